@@ -0,0 +1,56 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use rusmpp_core::{
+    CommandStatus,
+    command::owned::Command,
+    decode::Decoder,
+    pdus::owned::DeliverSm,
+    tlvs::{TlvTag, owned::MessageDeliveryRequestTlvValue},
+    types::owned::AnyOctetString,
+};
+
+fn bytes() -> Vec<u8> {
+    let deliver_sm = DeliverSm::builder()
+        .tlvs(
+            (0..8)
+                .map(|i| MessageDeliveryRequestTlvValue::Other {
+                    tag: TlvTag::Other(0x1400 + i),
+                    value: AnyOctetString::new([0x42; 64]),
+                })
+                .collect(),
+        )
+        .build();
+
+    let command = Command::new(CommandStatus::EsmeRok, 1, deliver_sm);
+
+    let mut bytes = Vec::new();
+
+    command.encode_to_writer(&mut bytes).unwrap();
+
+    bytes
+}
+
+/// Decodes a `deliver_sm` with unknown `TLV`s, statelessly and reusing buffers.
+fn decode(c: &mut Criterion) {
+    let bytes = bytes();
+
+    let mut group = c.benchmark_group("decode");
+
+    group.bench_function("stateless", |b| {
+        b.iter(|| std::hint::black_box(Command::try_from(bytes.as_slice()).unwrap()))
+    });
+
+    group.bench_function("reusing", |b| {
+        let mut decoder = Decoder::new();
+
+        b.iter(|| {
+            let command = decoder.decode_reusing(&bytes).unwrap();
+
+            decoder.recycle(std::hint::black_box(command));
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, decode);
+criterion_main!(benches);
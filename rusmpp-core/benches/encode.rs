@@ -0,0 +1,63 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use rusmpp_core::{
+    CommandStatus,
+    command::owned::Command,
+    encode::{Encode, Length},
+    pdus::owned::SubmitSm,
+    tlvs::owned::MessageSubmissionRequestTlvValue,
+    types::owned::{COctetString, OctetString},
+    values::{UserMessageReference, *},
+};
+
+fn command() -> Command {
+    let submit_sm = SubmitSm::builder()
+        .source_addr(COctetString::new(b"Source\0").unwrap())
+        .destination_addr(COctetString::new(b"Destination\0").unwrap())
+        .short_message(OctetString::new(b"Hello World").unwrap())
+        .tlvs(vec![
+            MessageSubmissionRequestTlvValue::UserMessageReference(UserMessageReference::new(1)),
+            MessageSubmissionRequestTlvValue::SourcePort(2),
+            MessageSubmissionRequestTlvValue::DestPort(3),
+            MessageSubmissionRequestTlvValue::SarMsgRefNum(4),
+            MessageSubmissionRequestTlvValue::SarTotalSegments(5),
+            MessageSubmissionRequestTlvValue::SarSegmentSeqnum(1),
+            MessageSubmissionRequestTlvValue::PayloadType(PayloadType::Default),
+            MessageSubmissionRequestTlvValue::MsValidity(MsValidity::new(
+                MsValidityBehavior::StoreIndefinitely,
+                None,
+            )),
+        ])
+        .build();
+
+    Command::new(CommandStatus::EsmeRok, 1, submit_sm)
+}
+
+/// Encodes a `TLV`-heavy `submit_sm` into a slice and into a writer.
+fn encode(c: &mut Criterion) {
+    let command = command();
+
+    let mut group = c.benchmark_group("encode");
+
+    group.bench_function("slice", |b| {
+        let mut buf = vec![0; command.length()];
+
+        b.iter(|| std::hint::black_box(command.encode(&mut buf)))
+    });
+
+    group.bench_function("writer", |b| {
+        let mut buf = Vec::with_capacity(4 + command.length());
+
+        b.iter(|| {
+            buf.clear();
+
+            command.encode_to_writer(&mut buf).unwrap();
+
+            std::hint::black_box(&buf);
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, encode);
+criterion_main!(benches);
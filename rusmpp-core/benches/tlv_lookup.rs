@@ -0,0 +1,53 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rusmpp_core::{
+    pdus::owned::{SubmitSm, TlvContainer},
+    tlvs::{
+        TlvTag,
+        owned::{MessageSubmissionRequestTlvValue, Tlv, get_tlv},
+    },
+    types::owned::AnyOctetString,
+};
+
+fn submit_sm(count: u16) -> SubmitSm {
+    SubmitSm::builder()
+        .tlvs(
+            (0..count)
+                .map(|i| MessageSubmissionRequestTlvValue::Other {
+                    tag: TlvTag::Other(0x1400 + i),
+                    value: AnyOctetString::new(i.to_be_bytes()),
+                })
+                .collect(),
+        )
+        .build()
+}
+
+/// Looks up every tag of a `submit_sm`, linearly and through [`TlvContainer::get_tlv`].
+fn lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tlv_lookup");
+
+    for count in [4, 16, 64, 256] {
+        let submit_sm = submit_sm(count);
+        let tags: Vec<_> = submit_sm.tlvs().iter().map(Tlv::tag).collect();
+
+        group.bench_with_input(BenchmarkId::new("linear", count), &submit_sm, |b, pdu| {
+            b.iter(|| {
+                for tag in &tags {
+                    std::hint::black_box(get_tlv(pdu.tlvs(), *tag));
+                }
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("indexed", count), &submit_sm, |b, pdu| {
+            b.iter(|| {
+                for tag in &tags {
+                    std::hint::black_box(pdu.get_tlv(*tag));
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, lookup);
+criterion_main!(benches);
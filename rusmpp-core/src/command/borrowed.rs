@@ -115,6 +115,29 @@ impl<'a, const N: usize> Command<'a, N> {
     pub fn builder() -> CommandStatusBuilder<'a, N> {
         Default::default()
     }
+
+    /// Encodes the command, including the `command_length`, into an array of `M` bytes without allocating.
+    ///
+    /// Returns the array and the number of bytes written, or an error if the command does not fit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{CommandStatus, command::borrowed::Command, pdus::borrowed::Pdu};
+    /// let command = Command::<'_, 0>::new(CommandStatus::EsmeRok, 1, Pdu::EnquireLink);
+    ///
+    /// let (buf, size) = command.encode_to_array::<16>().unwrap();
+    ///
+    /// assert_eq!(size, 16);
+    /// assert_eq!(&buf[..4], &[0x00, 0x00, 0x00, 0x10]);
+    ///
+    /// assert!(command.encode_to_array::<8>().is_err());
+    /// ```
+    pub fn encode_to_array<const M: usize>(
+        &self,
+    ) -> Result<([u8; M], usize), crate::encode::BufferTooSmall> {
+        crate::encode::encode_command_to_array(self)
+    }
 }
 
 #[derive(Debug, Default)]
@@ -166,4 +189,63 @@ mod tests {
     fn encode_decode() {
         crate::tests::borrowed::encode_decode_with_length_test_instances::<Command<'static, 16>>();
     }
+
+    #[test]
+    fn encode_to_array() {
+        use crate::encode::BufferTooSmall;
+
+        let command = Command::<'_, 16>::new(CommandStatus::EsmeRok, 7, Pdu::EnquireLink);
+
+        let (buf, size) = command.encode_to_array::<16>().unwrap();
+
+        assert_eq!(size, 16);
+        assert_eq!(
+            buf,
+            [
+                0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x07,
+            ]
+        );
+
+        let (decoded, _) =
+            <Command<'_, 16> as crate::decode::borrowed::DecodeWithLength>::decode(&buf[4..], 12)
+                .unwrap();
+
+        assert_eq!(decoded, command);
+
+        // Larger arrays leave the rest zeroed
+        let (buf, size) = command.encode_to_array::<32>().unwrap();
+
+        assert_eq!(size, 16);
+        assert!(buf[size..].iter().all(|byte| *byte == 0));
+
+        assert_eq!(
+            command.encode_to_array::<15>(),
+            Err(BufferTooSmall {
+                required: 16,
+                available: 15
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_command_round_trip() {
+        use crate::{decode::borrowed::DecodeWithLength, encode::Encode};
+
+        // command_id: 0x80000777, command_status: 0x12345678, sequence_number: 1
+        let bytes = [
+            0x80, 0x00, 0x07, 0x77, 0x12, 0x34, 0x56, 0x78, 0x00, 0x00, 0x00, 0x01, 0x01, 0x02,
+            0x03,
+        ];
+
+        let (command, size) = Command::<'_, 16>::decode(&bytes, bytes.len()).unwrap();
+
+        assert_eq!(size, bytes.len());
+        assert_eq!(command.id(), CommandId::Other(0x80000777));
+
+        let mut encoded = [0; 15];
+        let size = command.encode(&mut encoded);
+
+        assert_eq!(&encoded[..size], &bytes);
+    }
 }
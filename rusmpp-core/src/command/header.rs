@@ -0,0 +1,84 @@
+use crate::{CommandId, CommandStatus};
+
+/// The 16-octet `SMPP` command header.
+///
+/// See [`peek_header`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+pub struct CommandHeader {
+    /// The overall length of the command, including the header.
+    pub command_length: u32,
+    /// See [`CommandId`]
+    pub id: CommandId,
+    /// See [`CommandStatus`]
+    pub status: CommandStatus,
+    /// See [`Command::sequence_number`](crate::command::borrowed::Command::sequence_number)
+    pub sequence_number: u32,
+}
+
+impl CommandHeader {
+    /// The length of the header in octets.
+    pub const LENGTH: usize = 16;
+}
+
+/// Reads the [`CommandHeader`] from the first 16 bytes of `bytes` without decoding the body.
+///
+/// Returns [`None`] if fewer than 16 bytes are available.
+///
+/// Useful for dispatching on the [`CommandId`] before decoding the whole command.
+pub fn peek_header(bytes: &[u8]) -> Option<CommandHeader> {
+    if bytes.len() < CommandHeader::LENGTH {
+        return None;
+    }
+
+    let u32_at = |offset: usize| {
+        u32::from_be_bytes([
+            bytes[offset],
+            bytes[offset + 1],
+            bytes[offset + 2],
+            bytes[offset + 3],
+        ])
+    };
+
+    Some(CommandHeader {
+        command_length: u32_at(0),
+        id: CommandId::from(u32_at(4)),
+        status: CommandStatus::from(u32_at(8)),
+        sequence_number: u32_at(12),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peek() {
+        let bytes = [
+            0x00, 0x00, 0x00, 0x2F, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x01, 0x53, 0x4D, 0x50, 0x50,
+        ];
+
+        let header = peek_header(&bytes[..16]).expect("Failed to peek header");
+
+        assert_eq!(
+            header,
+            CommandHeader {
+                command_length: 47,
+                id: CommandId::BindTransmitter,
+                status: CommandStatus::EsmeRok,
+                sequence_number: 1,
+            }
+        );
+
+        assert_eq!(peek_header(&bytes), Some(header));
+    }
+
+    #[test]
+    fn peek_short() {
+        let bytes = [0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x15];
+
+        assert!(peek_header(&bytes).is_none());
+        assert!(peek_header(&[]).is_none());
+    }
+}
@@ -4,3 +4,11 @@ pub mod borrowed;
 #[cfg(any(test, feature = "alloc"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub mod owned;
+
+mod header;
+pub use header::{CommandHeader, peek_header};
+
+#[cfg(target_has_atomic = "32")]
+mod sequence;
+#[cfg(target_has_atomic = "32")]
+pub use sequence::SequenceNumbers;
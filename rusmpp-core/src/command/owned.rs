@@ -1,6 +1,16 @@
 use rusmpp_macros::Rusmpp;
 
-use crate::{CommandId, CommandStatus, pdus::owned::Pdu};
+use crate::{
+    CommandId, CommandStatus,
+    decode::{
+        DecodeContext, DecodeError, DecodeErrorExt,
+        owned::{Decode, DecodeExt, DecodeWithContext, DecodeWithLength},
+    },
+    fields::SmppField,
+    pdus::owned::Pdu,
+    tlvs::TlvTag,
+    values::InterfaceVersion,
+};
 
 /// `SMPP` command.
 ///
@@ -36,7 +46,7 @@ use crate::{CommandId, CommandStatus, pdus::owned::Pdu};
 /// | 01                            | addr_npi (0x01) |
 /// | 00                            | addr_range (NULL) |
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned)]
+#[rusmpp(decode = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
@@ -52,10 +62,55 @@ pub struct Command {
     /// See [`Pdu`]
     ///
     /// Optional because incoming commands may not have a PDU.
-    #[rusmpp(key = id, length = "unchecked")]
     pdu: Option<Pdu>,
 }
 
+impl DecodeWithLength for Command {
+    fn decode(src: &[u8], length: usize) -> Result<(Self, usize), DecodeError> {
+        Self::decode_with_context(src, length, &mut DecodeContext::default())
+    }
+}
+
+impl DecodeWithContext for Command {
+    /// Decodes a [`Command`] without its `command_length`.
+    fn decode_with_context(
+        src: &[u8],
+        length: usize,
+        context: &mut DecodeContext,
+    ) -> Result<(Self, usize), DecodeError> {
+        let size = 0;
+
+        let (id, size) = DecodeExt::decode_move(src, size).map_as_source(SmppField::id)?;
+
+        let (status, size) = DecodeExt::decode_move(src, size).map_as_source(SmppField::status)?;
+
+        let (sequence_number, size) =
+            DecodeExt::decode_move(src, size).map_as_source(SmppField::sequence_number)?;
+
+        let (pdu, size) =
+            Pdu::decode_with_context(id, &src[size..], length.saturating_sub(size), context)
+                .map_as_source(SmppField::pdu)?
+                .map(|(pdu, size_)| (Some(pdu), size + size_))
+                .unwrap_or((None, size));
+
+        // Error responses often have no body, only successful bind_resps get an empty one
+        let pdu = match pdu {
+            None if status == CommandStatus::EsmeRok => Pdu::header_only_bind_resp(id),
+            pdu => pdu,
+        };
+
+        Ok((
+            Self {
+                id,
+                status,
+                sequence_number,
+                pdu,
+            },
+            size,
+        ))
+    }
+}
+
 impl Default for Command {
     fn default() -> Self {
         Self {
@@ -83,6 +138,45 @@ impl Command {
         }
     }
 
+    /// Creates a new [`Command`] without a body, e.g. an error response.
+    ///
+    /// The [`pdu`](Command::pdu) is [`None`] and the command is encoded as its header only.
+    pub const fn without_body(id: CommandId, status: CommandStatus, sequence_number: u32) -> Self {
+        Self {
+            id,
+            status,
+            sequence_number,
+            pdu: None,
+        }
+    }
+
+    /// Creates a new request [`Command`] with the next `sequence_number` of `sequence_numbers`.
+    ///
+    /// The `command_status` is [`CommandStatus::EsmeRok`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     command::{SequenceNumbers, owned::Command},
+    /// #     pdus::owned::Pdu,
+    /// # };
+    /// let sequence_numbers = SequenceNumbers::new();
+    ///
+    /// let first = Command::with_next_sequence(Pdu::EnquireLink, &sequence_numbers);
+    /// let second = Command::with_next_sequence(Pdu::EnquireLink, &sequence_numbers);
+    ///
+    /// assert_eq!(first.sequence_number(), 1);
+    /// assert_eq!(second.sequence_number(), 2);
+    /// ```
+    #[cfg(target_has_atomic = "32")]
+    pub fn with_next_sequence(
+        pdu: impl Into<Pdu>,
+        sequence_numbers: &crate::command::SequenceNumbers,
+    ) -> Self {
+        Self::new(CommandStatus::EsmeRok, sequence_numbers.next(), pdu)
+    }
+
     #[inline]
     pub const fn id(&self) -> CommandId {
         self.id
@@ -103,6 +197,79 @@ impl Command {
         self.pdu.as_ref()
     }
 
+    /// Returns the [`CommandHeader`](crate::command::CommandHeader) of the encoded command.
+    pub fn header(&self) -> crate::command::CommandHeader {
+        crate::command::CommandHeader {
+            command_length: (4 + crate::encode::Length::length(self)) as u32,
+            id: self.id,
+            status: self.status,
+            sequence_number: self.sequence_number,
+        }
+    }
+
+    /// Returns the raw body of a command with an unknown `command_id`.
+    ///
+    /// Unknown commands are decoded opaquely as a [`Pdu::Other`], unless rejected with
+    /// [`DecodeOptions::reject_unknown_commands`](crate::decode::DecodeOptions::reject_unknown_commands).
+    /// Proxies can forward them untouched using the [`Command::header`] and this body.
+    ///
+    /// Returns [`None`] for known commands.
+    pub fn unknown_body(&self) -> Option<&[u8]> {
+        match &self.pdu {
+            Some(Pdu::Other { body, .. }) => Some(body.bytes()),
+            _ => None,
+        }
+    }
+
+    /// Returns the `message_id` of a `submit_sm_resp`, `submit_multi_resp`, `deliver_sm_resp` or `data_sm_resp`.
+    ///
+    /// `MC`s often omit the body of responses with an error `command_status`.
+    /// Such responses are decoded without a `PDU` and an empty `message_id` is returned.
+    ///
+    /// Returns [`None`] for other commands.
+    pub fn message_id(&self) -> Option<&str> {
+        match &self.pdu {
+            Some(Pdu::SubmitSmResp(pdu)) => Some(pdu.message_id().as_str()),
+            Some(Pdu::SubmitMultiResp(pdu)) => Some(pdu.message_id.as_str()),
+            Some(Pdu::DeliverSmResp(pdu)) => Some(pdu.message_id().as_str()),
+            Some(Pdu::DataSmResp(pdu)) => Some(pdu.message_id().as_str()),
+            Some(_) => None,
+            None => matches!(
+                self.id,
+                CommandId::SubmitSmResp
+                    | CommandId::SubmitMultiResp
+                    | CommandId::DeliverSmResp
+                    | CommandId::DataSmResp
+            )
+            .then_some(""),
+        }
+    }
+
+    /// Checks if this command is the response to `request`.
+    ///
+    /// The `command_id` must be the `command_id` of `request` with bit 31 set and the `sequence_number`s must match.
+    /// A `generic_nack` is never considered a response here, since it does not match the `command_id` of any request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     CommandStatus,
+    /// #     command::owned::Command,
+    /// #     pdus::owned::Pdu,
+    /// # };
+    /// let request = Command::new(CommandStatus::EsmeRok, 7, Pdu::EnquireLink);
+    /// let response = Command::new(CommandStatus::EsmeRok, 7, Pdu::EnquireLinkResp);
+    ///
+    /// assert!(response.is_response_to(&request));
+    /// assert!(!request.is_response_to(&response));
+    /// ```
+    pub fn is_response_to(&self, request: &Command) -> bool {
+        request.id.is_operation()
+            && u32::from(self.id) == crate::matching_response_id(request.id.into())
+            && self.sequence_number == request.sequence_number
+    }
+
     #[inline]
     pub fn set_pdu(&mut self, pdu: impl Into<Pdu>) {
         let pdu = pdu.into();
@@ -117,6 +284,346 @@ impl Command {
         Default::default()
     }
 
+    /// Returns the encoded bytes of the field with the given `name`.
+    ///
+    /// The command's own fields (`id`, `status`, `sequence_number`, `pdu`) are looked up first, then the fields of the [`Pdu`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{CommandStatus, command::owned::Command, pdus::owned::SubmitSm, types::owned::OctetString};
+    /// let submit_sm = SubmitSm::builder()
+    ///     .short_message(OctetString::new(b"Hi").unwrap())
+    ///     .build();
+    ///
+    /// let command = Command::new(CommandStatus::EsmeRok, 1, submit_sm);
+    ///
+    /// assert_eq!(command.field_bytes("short_message"), Some(b"Hi".to_vec()));
+    /// assert_eq!(command.field_bytes("sm_length"), Some(vec![2]));
+    /// assert_eq!(command.field_bytes("unknown"), None);
+    /// ```
+    pub fn field_bytes(&self, name: &str) -> Option<alloc::vec::Vec<u8>> {
+        use crate::encode::EncodeFields;
+
+        let field = EncodeFields::field(self, name)
+            .or_else(|| self.pdu.as_ref().and_then(|pdu| pdu.field(name)))?;
+
+        let mut bytes = alloc::vec![0; field.length()];
+
+        field.encode(&mut bytes);
+
+        Some(bytes)
+    }
+
+    /// Returns the names of the mandatory (non-`TLV`) fields of the [`Pdu`] in encoding order.
+    ///
+    /// Empty for commands without a body. The names can be passed to [`Command::field_bytes`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{CommandStatus, command::owned::Command, pdus::owned::{Pdu, SubmitSmResp}};
+    /// let command = Command::new(CommandStatus::EsmeRok, 1, SubmitSmResp::default());
+    ///
+    /// assert_eq!(command.mandatory_fields(), ["message_id"]);
+    ///
+    /// let command = Command::new(CommandStatus::EsmeRok, 1, Pdu::EnquireLink);
+    ///
+    /// assert!(command.mandatory_fields().is_empty());
+    /// ```
+    pub fn mandatory_fields(&self) -> &'static [&'static str] {
+        use crate::encode::EncodeFields;
+
+        self.pdu
+            .as_ref()
+            .map(EncodeFields::mandatory_fields)
+            .unwrap_or_default()
+    }
+
+    /// Returns the encoded optional parameters (`TLV`s) of the [`Pdu`].
+    ///
+    /// Empty if the `PDU` has no `TLV`s or does not support them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     CommandStatus,
+    /// #     command::owned::Command,
+    /// #     pdus::owned::{Pdu, SubmitSm},
+    /// #     tlvs::owned::MessageSubmissionRequestTlvValue,
+    /// # };
+    /// let submit_sm = SubmitSm::builder()
+    ///     .push_tlv(MessageSubmissionRequestTlvValue::SourcePort(1))
+    ///     .build();
+    ///
+    /// let command = Command::new(CommandStatus::EsmeRok, 1, submit_sm);
+    ///
+    /// assert_eq!(command.tlv_region_bytes(), [0x02, 0x0A, 0x00, 0x02, 0x00, 0x01]);
+    ///
+    /// let command = Command::new(CommandStatus::EsmeRok, 1, Pdu::EnquireLink);
+    ///
+    /// assert!(command.tlv_region_bytes().is_empty());
+    /// ```
+    pub fn tlv_region_bytes(&self) -> alloc::vec::Vec<u8> {
+        use crate::encode::{EncodeExt, Length};
+
+        let tlvs = self.pdu.as_ref().map(Pdu::tlvs).unwrap_or_default();
+
+        let mut bytes = alloc::vec![0; tlvs.iter().map(Length::length).sum()];
+
+        tlvs.iter()
+            .fold(0, |size, tlv| tlv.encode_move(&mut bytes, size));
+
+        bytes
+    }
+
+    /// Infers the `SMPP` version of the command, for peers that do not advertise their version.
+    ///
+    /// Bind PDUs return their advertised version. Otherwise broadcast PDUs and `TLV`s introduced in `SMPP` v5.0
+    /// imply [`InterfaceVersion::Smpp5_0`], PDUs introduced in v3.4 and all other `TLV`s imply [`InterfaceVersion::Smpp3_4`].
+    /// Commands without either are compatible with [`InterfaceVersion::Smpp3_3OrEarlier`].
+    ///
+    /// The result is a lower bound, a v5.0 peer may send commands without any v5.0 features.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     CommandStatus,
+    /// #     command::owned::Command,
+    /// #     pdus::owned::{BroadcastSm, Pdu, SubmitSm},
+    /// #     tlvs::owned::MessageSubmissionRequestTlvValue,
+    /// #     values::InterfaceVersion,
+    /// # };
+    /// let command = Command::new(CommandStatus::EsmeRok, 1, BroadcastSm::default());
+    ///
+    /// assert_eq!(command.probable_version(), InterfaceVersion::Smpp5_0);
+    ///
+    /// let submit_sm = SubmitSm::builder()
+    ///     .push_tlv(MessageSubmissionRequestTlvValue::DestPort(2948))
+    ///     .build();
+    ///
+    /// let command = Command::new(CommandStatus::EsmeRok, 1, submit_sm);
+    ///
+    /// assert_eq!(command.probable_version(), InterfaceVersion::Smpp3_4);
+    ///
+    /// let command = Command::new(CommandStatus::EsmeRok, 1, Pdu::EnquireLink);
+    ///
+    /// assert_eq!(command.probable_version(), InterfaceVersion::Smpp3_3OrEarlier(0x33));
+    /// ```
+    pub fn probable_version(&self) -> InterfaceVersion {
+        let Some(pdu) = self.pdu.as_ref() else {
+            return InterfaceVersion::Smpp3_3OrEarlier(0x33);
+        };
+
+        match pdu {
+            Pdu::BindTransmitter(body) => return body.interface_version,
+            Pdu::BindReceiver(body) => return body.interface_version,
+            Pdu::BindTransceiver(body) => return body.interface_version,
+            _ => {}
+        }
+
+        let advertised = match pdu {
+            Pdu::BindTransmitterResp(body) => body.sc_interface_version(),
+            Pdu::BindReceiverResp(body) => body.sc_interface_version(),
+            Pdu::BindTransceiverResp(body) => body.sc_interface_version(),
+            _ => None,
+        };
+
+        if let Some(version) = advertised {
+            return version;
+        }
+
+        if matches!(
+            self.id,
+            CommandId::BroadcastSm
+                | CommandId::BroadcastSmResp
+                | CommandId::QueryBroadcastSm
+                | CommandId::QueryBroadcastSmResp
+                | CommandId::CancelBroadcastSm
+                | CommandId::CancelBroadcastSmResp
+        ) || pdu.all_tlvs().any(|tlv| is_smpp5_0_tlv(tlv.tag()))
+        {
+            return InterfaceVersion::Smpp5_0;
+        }
+
+        if matches!(
+            self.id,
+            CommandId::BindTransceiverResp
+                | CommandId::Outbind
+                | CommandId::DataSm
+                | CommandId::DataSmResp
+                | CommandId::AlertNotification
+        ) || pdu.all_tlvs().next().is_some()
+        {
+            return InterfaceVersion::Smpp3_4;
+        }
+
+        InterfaceVersion::Smpp3_3OrEarlier(0x33)
+    }
+
+    /// Encodes the command, including the `command_length`, for a peer using the `SMPP` `version`.
+    ///
+    /// `TLV`s were introduced in `SMPP` v3.4. For [`InterfaceVersion::Smpp3_3OrEarlier`] the `TLV`s are omitted,
+    /// later versions encode the command unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     CommandStatus,
+    /// #     command::owned::Command,
+    /// #     pdus::owned::SubmitSm,
+    /// #     tlvs::owned::MessageSubmissionRequestTlvValue,
+    /// #     values::InterfaceVersion,
+    /// # };
+    /// let submit_sm = SubmitSm::builder()
+    ///     .push_tlv(MessageSubmissionRequestTlvValue::DestPort(2948))
+    ///     .build();
+    ///
+    /// let command = Command::new(CommandStatus::EsmeRok, 1, submit_sm);
+    ///
+    /// let v34 = command.encode_for_version(InterfaceVersion::Smpp3_4);
+    /// let v33 = command.encode_for_version(InterfaceVersion::Smpp3_3OrEarlier(0x33));
+    ///
+    /// assert_eq!(v34.len(), v33.len() + 6);
+    /// ```
+    pub fn encode_for_version(&self, version: InterfaceVersion) -> alloc::vec::Vec<u8> {
+        let mut buf = alloc::vec::Vec::new();
+
+        encode_many(core::slice::from_ref(self), &mut buf);
+
+        if matches!(version, InterfaceVersion::Smpp3_3OrEarlier(_)) {
+            let tlvs_length = self.pdu.as_ref().map(Pdu::tlvs_length).unwrap_or_default();
+
+            buf.truncate(buf.len() - tlvs_length);
+
+            let command_length = buf.len() as u32;
+
+            buf[..4].copy_from_slice(&command_length.to_be_bytes());
+        }
+
+        buf
+    }
+
+    /// Encodes the command, including the `command_length`, as a sequence of slices.
+    ///
+    /// The slices can be sent with a single vectored write, e.g. [`std::io::Write::write_vectored`], without concatenating them.
+    /// The header and the mandatory fields are encoded into small buffers, the `message_payload` bytes are borrowed from the command.
+    /// See [`VectoredCommand`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     CommandStatus,
+    /// #     command::owned::Command,
+    /// #     pdus::owned::SubmitSm,
+    /// #     tlvs::owned::MessageSubmissionRequestTlvValue,
+    /// #     types::owned::AnyOctetString,
+    /// #     values::owned::MessagePayload,
+    /// # };
+    /// let submit_sm = SubmitSm::builder()
+    ///     .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+    ///         MessagePayload::new(AnyOctetString::new(b"Hello")),
+    ///     ))
+    ///     .build();
+    ///
+    /// let command = Command::new(CommandStatus::EsmeRok, 1, submit_sm);
+    ///
+    /// let vectored = command.encode_vectored();
+    /// let slices = vectored.slices();
+    ///
+    /// assert_eq!(slices[0].len(), 16);
+    /// assert_eq!(slices.last(), Some(&&b"Hello"[..]));
+    /// ```
+    pub fn encode_vectored(&self) -> VectoredCommand<'_> {
+        use crate::encode::{EncodeExt, EncodeFields, Length};
+
+        let command_length = 4 + self.length();
+
+        let mut header = [0; 16];
+
+        let size = 0;
+        let size = (command_length as u32).encode_move(&mut header, size);
+        let size = self.id.encode_move(&mut header, size);
+        let size = self.status.encode_move(&mut header, size);
+        let _ = self.sequence_number.encode_move(&mut header, size);
+
+        let mut vectored = VectoredCommand {
+            header,
+            buf: alloc::vec::Vec::new(),
+            parts: alloc::vec::Vec::new(),
+        };
+
+        if let Some(pdu) = &self.pdu {
+            vectored.push_encoded(pdu.length() - pdu.tlvs_length(), |dst| {
+                pdu.encode_mandatory_fields(dst)
+            });
+
+            for tlv in pdu.all_tlvs() {
+                vectored.push_tlv(tlv);
+            }
+        }
+
+        vectored
+    }
+
+    /// Encodes the command, including the `command_length`, into an array of `M` bytes.
+    ///
+    /// See [`borrowed::Command::encode_to_array`](crate::command::borrowed::Command::encode_to_array).
+    pub fn encode_to_array<const M: usize>(
+        &self,
+    ) -> Result<([u8; M], usize), crate::encode::BufferTooSmall> {
+        crate::encode::encode_command_to_array(self)
+    }
+
+    /// Encodes the command, including the `command_length`, into [`Bytes`](bytes::Bytes).
+    #[cfg(feature = "bytes")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+    pub fn to_bytes(&self) -> bytes::Bytes {
+        use crate::encode::{Encode, Length};
+
+        let length = self.length();
+        let command_length = 4 + length;
+
+        let mut buf = bytes::BytesMut::zeroed(command_length);
+
+        buf[..4].copy_from_slice(&(command_length as u32).to_be_bytes());
+
+        let _ = self.encode(&mut buf[4..]);
+
+        buf.freeze()
+    }
+
+    /// Writes the encoded command, including the `command_length`, into `w`.
+    ///
+    /// The `command_length` is computed up front, the header is written first, followed by the body.
+    /// Big values like the `message_payload` are streamed without encoding the whole command into an intermediate buffer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn encode_to_writer(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        use crate::encode::{EncodeExt, Length};
+
+        let command_length = 4 + self.length();
+
+        let mut header = [0; 16];
+
+        let size = 0;
+        let size = (command_length as u32).encode_move(&mut header, size);
+        let size = self.id.encode_move(&mut header, size);
+        let size = self.status.encode_move(&mut header, size);
+        let size = self.sequence_number.encode_move(&mut header, size);
+
+        w.write_all(&header[..size])?;
+
+        match &self.pdu {
+            Some(pdu) => pdu.encode_to_writer(w),
+            None => Ok(()),
+        }
+    }
+
     /// Creates a new command from it's parts.
     ///
     /// # Note
@@ -133,6 +640,283 @@ impl Command {
     }
 }
 
+impl TryFrom<&[u8]> for Command {
+    type Error = DecodeError;
+
+    /// Decodes exactly one [`Command`] from `bytes`.
+    ///
+    /// `bytes` must start with the `command_length` and contain exactly `command_length` bytes.
+    /// Trailing bytes result in a [`DecodeErrorKind::TrailingBytes`](crate::decode::DecodeErrorKind::TrailingBytes) error.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let (command_length, size) = <u32 as Decode>::decode(bytes)?;
+
+        let command_length = command_length as usize;
+
+        if command_length < size || bytes.len() < command_length {
+            return Err(DecodeError::unexpected_eof());
+        }
+
+        if bytes.len() > command_length {
+            return Err(DecodeError::trailing_bytes(bytes.len() - command_length));
+        }
+
+        let length = command_length - size;
+
+        let (command, decoded) = Command::decode(&bytes[size..], length)?;
+
+        if decoded < length {
+            return Err(DecodeError::trailing_bytes(length - decoded));
+        }
+
+        Ok(command)
+    }
+}
+
+impl Command {
+    /// Decodes one [`Command`] from `bytes` with the given `options`.
+    ///
+    /// `bytes` must start with the `command_length`.
+    /// Unlike [`Command::try_from`], the strictness of decoding is tuned by `options`, see [`DecodeOptions`](crate::decode::DecodeOptions).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     command::owned::Command,
+    /// #     decode::{DecodeErrorKind, DecodeOptions},
+    /// # };
+    /// // enquire_link followed by two padding bytes
+    /// let bytes = [
+    ///     0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ///     0x00, 0x00,
+    /// ];
+    ///
+    /// let command = Command::decode_with(&bytes, &DecodeOptions::default()).unwrap();
+    ///
+    /// assert_eq!(command.sequence_number(), 1);
+    ///
+    /// let error = Command::decode_with(&bytes, &DecodeOptions::strict()).unwrap_err();
+    ///
+    /// assert!(matches!(error.kind(), DecodeErrorKind::TrailingBytes { count: 2 }));
+    /// ```
+    pub fn decode_with(
+        bytes: &[u8],
+        options: &crate::decode::DecodeOptions,
+    ) -> Result<Self, DecodeError> {
+        Self::decode_with_options(bytes, &mut DecodeContext::new(*options))
+    }
+
+    /// Decodes one [`Command`] from `bytes` with the options of the `context`, see [`Command::decode_with`].
+    pub(crate) fn decode_with_options(
+        bytes: &[u8],
+        context: &mut DecodeContext,
+    ) -> Result<Self, DecodeError> {
+        let options = *context.options();
+
+        let (command_length, size) = <u32 as Decode>::decode(bytes)?;
+
+        let command_length = command_length as usize;
+
+        if let Some(max) = options.max_pdu_size {
+            if command_length > max {
+                return Err(DecodeError::max_length(command_length, max));
+            }
+        }
+
+        if command_length < size || bytes.len() < command_length {
+            return Err(DecodeError::unexpected_eof());
+        }
+
+        if options.strict_length && bytes.len() > command_length {
+            return Err(DecodeError::trailing_bytes(bytes.len() - command_length));
+        }
+
+        let length = command_length - size;
+
+        let (command, decoded) =
+            Command::decode_with_context(&bytes[size..command_length], length, context)?;
+
+        if options.strict_length && decoded < length {
+            return Err(DecodeError::trailing_bytes(length - decoded));
+        }
+
+        if options.reject_unknown_commands && matches!(command.pdu(), Some(Pdu::Other { .. })) {
+            return Err(DecodeError::unsupported_key(command.id().into()));
+        }
+
+        Ok(command)
+    }
+}
+
+/// An encoded [`Command`] split into slices, created by [`Command::encode_vectored`].
+///
+/// The first slice is the 16-byte header, followed by the mandatory fields and the `TLV` region.
+/// The `message_payload` bytes are borrowed from the command, everything else is encoded into a small buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VectoredCommand<'a> {
+    header: [u8; 16],
+    buf: alloc::vec::Vec<u8>,
+    parts: alloc::vec::Vec<VectoredPart<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum VectoredPart<'a> {
+    /// A range of the buffer.
+    Encoded(core::ops::Range<usize>),
+    Borrowed(&'a [u8]),
+}
+
+impl<'a> VectoredCommand<'a> {
+    /// Encodes up to `length` bytes into the buffer, extending the last encoded part if possible.
+    fn push_encoded(&mut self, length: usize, encode: impl FnOnce(&mut [u8]) -> usize) {
+        if length == 0 {
+            return;
+        }
+
+        let start = self.buf.len();
+
+        self.buf.resize(start + length, 0);
+
+        let size = encode(&mut self.buf[start..]);
+
+        self.buf.truncate(start + size);
+
+        match self.parts.last_mut() {
+            Some(VectoredPart::Encoded(range)) if range.end == start => range.end = self.buf.len(),
+            _ => self
+                .parts
+                .push(VectoredPart::Encoded(start..self.buf.len())),
+        }
+    }
+
+    fn push_tlv(&mut self, tlv: &'a crate::tlvs::owned::Tlv) {
+        use crate::{
+            encode::{Encode, EncodeExt, Length},
+            tlvs::owned::TlvValue,
+        };
+
+        self.push_encoded(4, |dst| {
+            let size = tlv.tag().encode_move(dst, 0);
+
+            tlv.value_length().encode_move(dst, size)
+        });
+
+        match tlv.value() {
+            Some(TlvValue::MessagePayload(payload)) => self
+                .parts
+                .push(VectoredPart::Borrowed(payload.value.bytes())),
+            Some(value) => self.push_encoded(value.length(), |dst| value.encode(dst)),
+            None => {}
+        }
+    }
+
+    /// Returns the slices in encoding order, starting with the header.
+    pub fn slices(&self) -> alloc::vec::Vec<&[u8]> {
+        core::iter::once(&self.header[..])
+            .chain(self.parts.iter().map(|part| match part {
+                VectoredPart::Encoded(range) => &self.buf[range.clone()],
+                VectoredPart::Borrowed(bytes) => *bytes,
+            }))
+            .collect()
+    }
+
+    /// Returns the slices as [`IoSlice`](std::io::IoSlice)s for [`std::io::Write::write_vectored`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn io_slices(&self) -> alloc::vec::Vec<std::io::IoSlice<'_>> {
+        self.slices()
+            .into_iter()
+            .map(std::io::IoSlice::new)
+            .collect()
+    }
+
+    /// Returns the `command_length`, the total length of all slices.
+    pub fn command_length(&self) -> usize {
+        u32::from_be_bytes([
+            self.header[0],
+            self.header[1],
+            self.header[2],
+            self.header[3],
+        ]) as usize
+    }
+}
+
+/// Appends the encoded `commands`, each including its `command_length`, to `buf`.
+///
+/// Used for pipelining: a batch of commands can be sent with a single write.
+pub fn encode_many(commands: &[Command], buf: &mut alloc::vec::Vec<u8>) {
+    use crate::encode::{Encode, Length};
+
+    let total = commands.iter().map(|command| 4 + command.length()).sum();
+
+    buf.reserve(total);
+
+    for command in commands {
+        let start = buf.len();
+        let command_length = 4 + command.length();
+
+        buf.resize(start + command_length, 0);
+        buf[start..start + 4].copy_from_slice(&(command_length as u32).to_be_bytes());
+
+        let _ = command.encode(&mut buf[start + 4..]);
+    }
+}
+
+/// Returns `true` if the `TLV` was introduced in `SMPP` v5.0.
+const fn is_smpp5_0_tlv(tag: TlvTag) -> bool {
+    matches!(
+        tag,
+        TlvTag::CongestionState
+            | TlvTag::BroadcastChannelIndicator
+            | TlvTag::BroadcastContentType
+            | TlvTag::BroadcastContentTypeInfo
+            | TlvTag::BroadcastMessageClass
+            | TlvTag::BroadcastRepNum
+            | TlvTag::BroadcastFrequencyInterval
+            | TlvTag::BroadcastAreaIdentifier
+            | TlvTag::BroadcastErrorStatus
+            | TlvTag::BroadcastAreaSuccess
+            | TlvTag::BroadcastEndTime
+            | TlvTag::BroadcastServiceGroup
+            | TlvTag::BillingIdentification
+            | TlvTag::SourceNetworkId
+            | TlvTag::DestNetworkId
+            | TlvTag::SourceNodeId
+            | TlvTag::DestNodeId
+            | TlvTag::DestAddrNpResolution
+            | TlvTag::DestAddrNpInformation
+            | TlvTag::DestAddrNpCountry
+    )
+}
+
+/// Decodes all complete commands from `buf`.
+///
+/// Returns the decoded commands and the remaining bytes, starting at the first incomplete command.
+///
+/// Decoding stops at the first command that fails to decode. Its bytes are part of the remainder
+/// and can be inspected using [`Command::try_from`].
+pub fn decode_many(buf: &[u8]) -> (alloc::vec::Vec<Command>, &[u8]) {
+    let mut commands = alloc::vec::Vec::new();
+    let mut rest = buf;
+
+    while let Some(header) = super::peek_header(rest) {
+        let command_length = header.command_length as usize;
+
+        if rest.len() < command_length {
+            break;
+        }
+
+        let Ok(command) = Command::try_from(&rest[..command_length]) else {
+            break;
+        };
+
+        commands.push(command);
+        rest = &rest[command_length..];
+    }
+
+    (commands, rest)
+}
+
 #[derive(Debug, Default)]
 pub struct CommandStatusBuilder {
     inner: Command,
@@ -174,6 +958,25 @@ impl PduBuilder {
     }
 }
 
+impl crate::validate::Validate for Command {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        use crate::validate::ValidationError;
+
+        let Some(pdu) = &self.pdu else {
+            return Ok(());
+        };
+
+        if self.id != pdu.command_id() {
+            return Err(ValidationError::CommandIdMismatch {
+                id: self.id,
+                pdu: pdu.command_id(),
+            });
+        }
+
+        crate::validate::Validate::validate(pdu)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,4 +985,770 @@ mod tests {
     fn encode_decode() {
         crate::tests::owned::encode_decode_with_length_test_instances::<Command>();
     }
+
+    #[test]
+    fn try_from_bytes() {
+        use crate::{
+            decode::DecodeErrorKind,
+            encode::{Encode, Length},
+        };
+
+        let buf = &mut [0u8; 1024];
+
+        for command in crate::tests::owned::test_commands() {
+            let size = 4 + command.length();
+
+            buf[..4].copy_from_slice(&(size as u32).to_be_bytes());
+            command.encode(&mut buf[4..]);
+
+            let decoded = Command::try_from(&buf[..size]).expect("Failed to decode");
+
+            assert_eq!(command, decoded);
+
+            let error = Command::try_from(&buf[..size + 3]).unwrap_err();
+
+            assert!(matches!(
+                error.kind(),
+                DecodeErrorKind::TrailingBytes { count: 3 }
+            ));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn to_bytes() {
+        use crate::encode::{Encode, Length};
+
+        for command in crate::tests::owned::test_commands() {
+            let command_length = 4 + command.length();
+
+            let mut buf = alloc::vec![0; command_length];
+
+            buf[..4].copy_from_slice(&(command_length as u32).to_be_bytes());
+            command.encode(&mut buf[4..]);
+
+            assert_eq!(command.to_bytes(), buf);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn encode_to_writer() {
+        use crate::{
+            encode::{Encode, Length},
+            pdus::owned::SubmitSm,
+            tlvs::owned::MessageSubmissionRequestTlvValue,
+            types::owned::AnyOctetString,
+            values::owned::MessagePayload,
+        };
+
+        let big = Command::new(
+            CommandStatus::EsmeRok,
+            1,
+            SubmitSm::builder()
+                .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                    MessagePayload::new(AnyOctetString::new([0x53; 4096])),
+                ))
+                .build(),
+        );
+
+        // Bigger than the stack buffer of `write_encoded`
+        let big_other = Command::new(
+            CommandStatus::EsmeRok,
+            2,
+            SubmitSm::builder()
+                .push_tlv(MessageSubmissionRequestTlvValue::Other {
+                    tag: crate::tlvs::TlvTag::Other(0x1400),
+                    value: AnyOctetString::new([0x42; 1024]),
+                })
+                .push_tlv(MessageSubmissionRequestTlvValue::SourcePort(1))
+                .build(),
+        );
+
+        for command in crate::tests::owned::test_commands()
+            .into_iter()
+            .chain([big, big_other])
+        {
+            let command_length = 4 + command.length();
+
+            let mut expected = alloc::vec![0; command_length];
+
+            expected[..4].copy_from_slice(&(command_length as u32).to_be_bytes());
+            command.encode(&mut expected[4..]);
+
+            let mut written = alloc::vec::Vec::new();
+
+            command
+                .encode_to_writer(&mut written)
+                .expect("Failed to write");
+
+            assert_eq!(written, expected);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod decode_with {
+        use crate::{
+            CommandId,
+            decode::{DecodeErrorKind, DecodeOptions},
+            pdus::owned::{CancelSm, SubmitSm},
+            tlvs::owned::MessageSubmissionRequestTlvValue,
+            types::owned::{AnyOctetString, COctetString},
+            values::{UserMessageReference, owned::MessagePayload},
+        };
+
+        use super::*;
+
+        fn encode(pdu: impl Into<Pdu>) -> alloc::vec::Vec<u8> {
+            let mut buf = alloc::vec::Vec::new();
+
+            Command::new(CommandStatus::EsmeRok, 1, pdu)
+                .encode_to_writer(&mut buf)
+                .unwrap();
+
+            buf
+        }
+
+        /// Increments the `command_length` by `count` and appends `count` padding bytes.
+        fn pad(bytes: &mut alloc::vec::Vec<u8>, count: usize) {
+            let command_length = u32::from_be_bytes(bytes[..4].try_into().unwrap()) as usize;
+
+            bytes[..4].copy_from_slice(&((command_length + count) as u32).to_be_bytes());
+            bytes.resize(bytes.len() + count, 0);
+        }
+
+        #[test]
+        fn default_matches_try_from() {
+            for command in crate::tests::owned::test_commands() {
+                let bytes = encode(command.pdu().cloned().unwrap_or(Pdu::EnquireLink));
+
+                assert_eq!(
+                    Command::decode_with(&bytes, &DecodeOptions::default()).unwrap(),
+                    Command::try_from(&bytes[..]).unwrap()
+                );
+            }
+        }
+
+        #[test]
+        fn strict_length() {
+            let cancel_sm = CancelSm::builder()
+                .message_id(COctetString::new(b"id\0").unwrap())
+                .build();
+
+            // Unconsumed body bytes within the command_length
+            let mut bytes = encode(cancel_sm.clone());
+            pad(&mut bytes, 2);
+
+            let command = Command::decode_with(&bytes, &DecodeOptions::default()).unwrap();
+
+            assert_eq!(command.pdu(), Some(&Pdu::from(cancel_sm.clone())));
+
+            let options = DecodeOptions::default().with_strict_length(true);
+            let error = Command::decode_with(&bytes, &options).unwrap_err();
+
+            assert!(matches!(
+                error.kind(),
+                DecodeErrorKind::TrailingBytes { count: 2 }
+            ));
+
+            // Bytes after the command_length
+            let mut bytes = encode(cancel_sm);
+            bytes.extend_from_slice(&[0x00, 0x00, 0x00]);
+
+            assert!(Command::decode_with(&bytes, &DecodeOptions::default()).is_ok());
+
+            let error = Command::decode_with(&bytes, &options).unwrap_err();
+
+            assert!(matches!(
+                error.kind(),
+                DecodeErrorKind::TrailingBytes { count: 3 }
+            ));
+        }
+
+        #[test]
+        fn strict_tlv_bounds() {
+            let submit_sm = SubmitSm::builder()
+                .push_tlv(MessageSubmissionRequestTlvValue::UserMessageReference(
+                    UserMessageReference::new(16),
+                ))
+                .build();
+
+            let mut bytes = encode(submit_sm);
+
+            // value_length: 1 for the 2 bytes of the user_message_reference
+            let value_length = bytes.len() - 3;
+            bytes[value_length] = 0x01;
+
+            let command = Command::decode_with(&bytes, &DecodeOptions::default()).unwrap();
+            let Some(Pdu::SubmitSm(submit_sm)) = command.pdu() else {
+                unreachable!()
+            };
+
+            assert_eq!(submit_sm.tlvs()[0].value_length(), 1);
+
+            let options = DecodeOptions::default().with_strict_tlv_bounds(true);
+            let error = Command::decode_with(&bytes, &options).unwrap_err();
+
+            assert!(matches!(
+                error.kind(),
+                DecodeErrorKind::TlvLengthMismatch {
+                    tag: 0x0204,
+                    value_length: 1,
+                    actual: 2
+                }
+            ));
+
+            // Well-formed TLVs pass
+            bytes[value_length] = 0x02;
+
+            assert!(Command::decode_with(&bytes, &options).is_ok());
+        }
+
+        #[test]
+        fn reject_unknown_commands() {
+            let bytes = encode(Pdu::Other {
+                command_id: CommandId::Other(0x00010001),
+                body: AnyOctetString::new([0x01, 0x02]),
+            });
+
+            let command = Command::decode_with(&bytes, &DecodeOptions::default()).unwrap();
+
+            assert_eq!(command.id(), CommandId::Other(0x00010001));
+
+            let options = DecodeOptions::default().with_reject_unknown_commands(true);
+            let error = Command::decode_with(&bytes, &options).unwrap_err();
+
+            assert!(matches!(
+                error.kind(),
+                DecodeErrorKind::UnsupportedKey { key: 0x00010001 }
+            ));
+
+            assert!(Command::decode_with(&encode(Pdu::EnquireLink), &options).is_ok());
+        }
+
+        #[test]
+        fn unknown_command() {
+            // Neither a known nor a vendor-specific command_id
+            let mut bytes = alloc::vec![
+                0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x07, 0x77, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x2A, 0x01, 0x02, 0x03,
+            ];
+
+            let command = Command::decode_with(&bytes, &DecodeOptions::default()).unwrap();
+
+            assert_eq!(command.id(), CommandId::Other(0x00000777));
+            assert_eq!(command.unknown_body(), Some(&[0x01, 0x02, 0x03][..]));
+            assert_eq!(Some(command.header()), crate::command::peek_header(&bytes));
+
+            // Passed through untouched
+            let mut forwarded = alloc::vec::Vec::new();
+            command.encode_to_writer(&mut forwarded).unwrap();
+
+            assert_eq!(forwarded, bytes);
+
+            let options = DecodeOptions::default().with_reject_unknown_commands(true);
+            let error = Command::decode_with(&bytes, &options).unwrap_err();
+
+            assert!(matches!(
+                error.kind(),
+                DecodeErrorKind::UnsupportedKey { key: 0x00000777 }
+            ));
+
+            // Known commands have no opaque body
+            bytes = encode(Pdu::EnquireLink);
+
+            let command = Command::decode_with(&bytes, &options).unwrap();
+
+            assert!(command.unknown_body().is_none());
+            assert_eq!(command.header().command_length, 16);
+        }
+
+        #[test]
+        fn max_pdu_size() {
+            let bytes = encode(
+                SubmitSm::builder()
+                    .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                        MessagePayload::new(AnyOctetString::new([0x53; 16384])),
+                    ))
+                    .build(),
+            );
+
+            let error = Command::decode_with(&bytes, &DecodeOptions::default()).unwrap_err();
+
+            assert!(matches!(
+                error.kind(),
+                DecodeErrorKind::MaxLength {
+                    max: DecodeOptions::DEFAULT_MAX_PDU_SIZE,
+                    ..
+                }
+            ));
+
+            let options = DecodeOptions::default().with_max_pdu_size(None);
+
+            assert!(Command::decode_with(&bytes, &options).is_ok());
+
+            // Checked before the body is available
+            let options = DecodeOptions::default().with_max_pdu_size(Some(16));
+
+            assert!(Command::decode_with(&encode(Pdu::EnquireLink), &options).is_ok());
+
+            let error = Command::decode_with(&bytes[..8], &options).unwrap_err();
+
+            assert!(matches!(
+                error.kind(),
+                DecodeErrorKind::MaxLength { max: 16, .. }
+            ));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn unknown_command_round_trip() {
+        let ids = [0x00000777, 0x80000777, 0x00010001, 0x0000FFFF];
+        let statuses = [0x00000000, 0x00000045, 0x12345678];
+        let bodies = [&[][..], &[0x00][..], &[0xAB; 300][..]];
+
+        for id in ids {
+            for status in statuses {
+                for body in bodies {
+                    let command_length = 16 + body.len() as u32;
+
+                    let mut bytes = alloc::vec::Vec::new();
+
+                    for field in [command_length, id, status, 0x0000002A] {
+                        bytes.extend_from_slice(&field.to_be_bytes());
+                    }
+
+                    bytes.extend_from_slice(body);
+
+                    let command = Command::try_from(&bytes[..]).unwrap();
+
+                    assert_eq!(command.id(), CommandId::Other(id));
+
+                    let mut encoded = alloc::vec::Vec::new();
+                    command.encode_to_writer(&mut encoded).unwrap();
+
+                    assert_eq!(encoded, bytes, "id: {id:#010X}, status: {status:#010X}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn error_resp_without_body() {
+        use crate::encode::{Encode, Length};
+
+        // submit_sm_resp, command_status: ESME_RSUBMITFAIL, no body
+        let bytes = [
+            0x00, 0x00, 0x00, 0x10, 0x80, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x45, 0x00, 0x00,
+            0x00, 0x01,
+        ];
+
+        let command = Command::try_from(&bytes[..]).unwrap();
+
+        assert_eq!(command.status(), CommandStatus::EsmeRsubmitfail);
+        assert!(command.pdu().is_none());
+        assert_eq!(command.message_id(), Some(""));
+
+        // Re-encoded without a body
+        let mut buf = [0; 16];
+
+        buf[..4].copy_from_slice(&(4 + command.length() as u32).to_be_bytes());
+        command.encode(&mut buf[4..]);
+
+        assert_eq!(buf, bytes);
+
+        // Empty message_id
+        let bytes = [
+            0x00, 0x00, 0x00, 0x11, 0x80, 0x00, 0x01, 0x03, 0x00, 0x00, 0x00, 0x45, 0x00, 0x00,
+            0x00, 0x01, 0x00,
+        ];
+
+        let command = Command::try_from(&bytes[..]).unwrap();
+
+        assert_eq!(command.message_id(), Some(""));
+
+        assert_eq!(
+            Command::new(CommandStatus::EsmeRok, 1, Pdu::EnquireLink).message_id(),
+            None
+        );
+    }
+
+    #[test]
+    fn try_from_bytes_incomplete() {
+        use crate::decode::DecodeErrorKind;
+
+        let bytes = [0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x15];
+
+        let error = Command::try_from(&bytes[..]).unwrap_err();
+
+        assert!(matches!(error.kind(), DecodeErrorKind::UnexpectedEof));
+    }
+
+    #[test]
+    fn validate() {
+        use crate::{
+            fields::SmppField,
+            pdus::owned::SubmitSm,
+            validate::{Validate, ValidationError},
+        };
+
+        let command = Command::new(CommandStatus::EsmeRok, 1, Pdu::EnquireLink);
+
+        assert!(command.validate().is_ok());
+
+        let command = Command::new(CommandStatus::EsmeRok, 1, SubmitSm::default());
+
+        assert_eq!(
+            command.validate(),
+            Err(ValidationError::EmptyField {
+                field: SmppField::destination_addr
+            })
+        );
+    }
+
+    #[test]
+    fn decode_many() {
+        let commands = crate::tests::owned::test_commands();
+
+        let mut buf = alloc::vec::Vec::new();
+
+        super::encode_many(&commands[..3], &mut buf);
+
+        let (decoded, rest) = super::decode_many(&buf);
+
+        assert_eq!(decoded, &commands[..3]);
+        assert!(rest.is_empty());
+
+        let mut buf = alloc::vec::Vec::new();
+
+        super::encode_many(&commands[..3], &mut buf);
+
+        // Drop the last byte of the third command
+        let partial = buf.len() - 1;
+        let third = 4 + crate::encode::Length::length(&commands[2]);
+
+        let (decoded, rest) = super::decode_many(&buf[..partial]);
+
+        assert_eq!(decoded, &commands[..2]);
+        assert_eq!(rest, &buf[partial + 1 - third..partial]);
+
+        // Partial header
+        let (decoded, rest) = super::decode_many(&buf[..2]);
+
+        assert!(decoded.is_empty());
+        assert_eq!(rest, &buf[..2]);
+    }
+
+    #[test]
+    fn field_bytes() {
+        use crate::{
+            pdus::owned::SubmitSm, tlvs::owned::MessageSubmissionRequestTlvValue,
+            types::owned::OctetString,
+        };
+
+        let submit_sm = SubmitSm::builder()
+            .short_message(OctetString::new(b"Short Message").unwrap())
+            .push_tlv(MessageSubmissionRequestTlvValue::UserMessageReference(
+                crate::values::UserMessageReference::new(16),
+            ))
+            .build();
+
+        let command = Command::new(CommandStatus::EsmeRok, 7, submit_sm);
+
+        assert_eq!(
+            command.field_bytes("short_message").as_deref(),
+            Some(b"Short Message".as_slice())
+        );
+        assert_eq!(command.field_bytes("sm_length"), Some(alloc::vec![13]));
+        assert_eq!(
+            command.field_bytes("sequence_number"),
+            Some(alloc::vec![0, 0, 0, 7])
+        );
+        assert_eq!(
+            command.field_bytes("tlvs"),
+            Some(alloc::vec![0x02, 0x04, 0x00, 0x02, 0x00, 0x10])
+        );
+        assert_eq!(command.field_bytes("not_a_field"), None);
+
+        let command = Command::new(CommandStatus::EsmeRok, 7, Pdu::EnquireLink);
+
+        assert_eq!(command.field_bytes("short_message"), None);
+    }
+
+    #[test]
+    fn tlv_region_bytes() {
+        use crate::{
+            pdus::owned::SubmitSm, tlvs::owned::MessageSubmissionRequestTlvValue,
+            types::owned::OctetString, values::UserMessageReference,
+        };
+
+        let submit_sm = SubmitSm::builder()
+            .short_message(OctetString::new(b"Short Message").unwrap())
+            .push_tlv(MessageSubmissionRequestTlvValue::UserMessageReference(
+                UserMessageReference::new(16),
+            ))
+            .push_tlv(MessageSubmissionRequestTlvValue::DestPort(0x0B84))
+            .build();
+
+        let command = Command::new(CommandStatus::EsmeRok, 7, submit_sm);
+
+        assert_eq!(
+            command.tlv_region_bytes(),
+            [
+                0x02, 0x04, 0x00, 0x02, 0x00, 0x10, //
+                0x02, 0x0B, 0x00, 0x02, 0x0B, 0x84,
+            ]
+        );
+
+        let command = Command::new(CommandStatus::EsmeRok, 7, Pdu::EnquireLink);
+
+        assert!(command.tlv_region_bytes().is_empty());
+    }
+
+    #[test]
+    fn with_next_sequence() {
+        use crate::command::SequenceNumbers;
+
+        let sequence_numbers = SequenceNumbers::new();
+
+        let first = Command::with_next_sequence(Pdu::EnquireLink, &sequence_numbers);
+        let second = Command::with_next_sequence(Pdu::Unbind, &sequence_numbers);
+
+        assert_ne!(first.sequence_number(), second.sequence_number());
+        assert_eq!(first.status(), CommandStatus::EsmeRok);
+        assert_eq!(second.id(), CommandId::Unbind);
+    }
+
+    #[test]
+    fn is_response_to() {
+        use crate::{
+            pdus::owned::{SubmitSm, SubmitSmResp},
+            types::owned::COctetString,
+        };
+
+        let request = Command::new(CommandStatus::EsmeRok, 7, SubmitSm::default());
+
+        let response =
+            |sequence_number, pdu: Pdu| Command::new(CommandStatus::EsmeRok, sequence_number, pdu);
+
+        let submit_sm_resp = || {
+            Pdu::from(
+                SubmitSmResp::builder()
+                    .message_id(COctetString::new(b"id\0").unwrap())
+                    .build(),
+            )
+        };
+
+        assert!(response(7, submit_sm_resp()).is_response_to(&request));
+        assert!(!response(8, submit_sm_resp()).is_response_to(&request));
+        assert!(!response(7, Pdu::DeliverSmResp(Default::default())).is_response_to(&request));
+        assert!(!response(7, Pdu::GenericNack).is_response_to(&request));
+        assert!(!request.is_response_to(&request));
+
+        // Responses have no response
+        let response = response(7, submit_sm_resp());
+
+        assert!(
+            !Command::new(CommandStatus::EsmeRok, 7, Pdu::GenericNack).is_response_to(&response)
+        );
+    }
+
+    #[test]
+    fn mandatory_fields() {
+        use crate::pdus::owned::{
+            AlertNotification, BindTransmitter, BindTransmitterResp, SubmitSmResp,
+        };
+
+        let command = Command::new(CommandStatus::EsmeRok, 1, BindTransmitter::default());
+
+        assert_eq!(
+            command.mandatory_fields(),
+            [
+                "system_id",
+                "password",
+                "system_type",
+                "interface_version",
+                "addr_ton",
+                "addr_npi",
+                "address_range",
+            ]
+        );
+
+        for name in command.mandatory_fields() {
+            assert!(command.field_bytes(name).is_some(), "name: {name}");
+        }
+
+        // TLVs are optional
+        let command = Command::new(CommandStatus::EsmeRok, 1, SubmitSmResp::default());
+
+        assert_eq!(command.mandatory_fields(), ["message_id"]);
+
+        // Single optional TLVs too
+        let command = Command::new(CommandStatus::EsmeRok, 1, BindTransmitterResp::default());
+
+        assert_eq!(command.mandatory_fields(), ["system_id"]);
+
+        let command = Command::new(CommandStatus::EsmeRok, 1, AlertNotification::default());
+
+        assert_eq!(
+            command.mandatory_fields(),
+            [
+                "source_addr_ton",
+                "source_addr_npi",
+                "source_addr",
+                "esme_addr_ton",
+                "esme_addr_npi",
+                "esme_addr",
+            ]
+        );
+    }
+
+    #[test]
+    fn encode_for_version() {
+        use crate::{pdus::owned::BindTransceiverResp, types::owned::COctetString};
+
+        let bind_transceiver_resp = BindTransceiverResp::builder()
+            .system_id(COctetString::new(b"SMSC\0").unwrap())
+            .sc_interface_version(Some(InterfaceVersion::Smpp3_4))
+            .build();
+
+        let command = Command::new(CommandStatus::EsmeRok, 1, bind_transceiver_resp);
+
+        let v34 = command.encode_for_version(InterfaceVersion::Smpp3_4);
+        let v33 = command.encode_for_version(InterfaceVersion::Smpp3_3OrEarlier(0x33));
+
+        assert_eq!(v34, command.encode_for_version(InterfaceVersion::Smpp5_0));
+        assert_eq!(&v34[..4], &[0x00, 0x00, 0x00, 0x1A]);
+        assert_eq!(&v33[..4], &[0x00, 0x00, 0x00, 0x15]);
+
+        // The sc_interface_version TLV is omitted for v3.3
+        assert_eq!(&v34[4..21], &v33[4..]);
+        assert_eq!(&v34[21..], &[0x02, 0x10, 0x00, 0x01, 0x34]);
+
+        let decoded = Command::try_from(v33.as_slice()).unwrap();
+
+        assert_eq!(decoded.header().id, CommandId::BindTransceiverResp);
+
+        // Commands without TLVs are unchanged
+        let command = Command::new(CommandStatus::EsmeRok, 1, Pdu::EnquireLink);
+
+        assert_eq!(
+            command.encode_for_version(InterfaceVersion::Smpp3_3OrEarlier(0x33)),
+            command.encode_for_version(InterfaceVersion::Smpp3_4)
+        );
+    }
+
+    #[test]
+    fn encode_vectored() {
+        use crate::{
+            pdus::owned::{BindTransceiverResp, SubmitSm},
+            tlvs::owned::{MessageSubmissionRequestTlvValue, TlvValue},
+            types::owned::{AnyOctetString, COctetString, OctetString},
+            values::owned::MessagePayload,
+        };
+
+        let submit_sm = SubmitSm::builder()
+            .short_message(OctetString::new(b"Hello").unwrap())
+            .push_tlv(MessageSubmissionRequestTlvValue::SourcePort(1))
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new([0x53; 4096])),
+            ))
+            .push_tlv(MessageSubmissionRequestTlvValue::DestPort(2))
+            .build();
+
+        let bind_resp = BindTransceiverResp::builder()
+            .system_id(COctetString::new(b"SMSC\0").unwrap())
+            .sc_interface_version(Some(InterfaceVersion::Smpp5_0))
+            .build();
+
+        let commands = [
+            Command::new(CommandStatus::EsmeRok, 1, submit_sm),
+            Command::new(CommandStatus::EsmeRok, 2, bind_resp),
+            Command::new(CommandStatus::EsmeRok, 3, Pdu::EnquireLink),
+        ];
+
+        for command in commands.iter().chain(&crate::tests::owned::test_commands()) {
+            let vectored = command.encode_vectored();
+
+            let mut full = alloc::vec::Vec::new();
+
+            encode_many(core::slice::from_ref(command), &mut full);
+
+            assert_eq!(vectored.slices().concat(), full);
+            assert_eq!(vectored.command_length(), full.len());
+
+            #[cfg(feature = "bytes")]
+            assert_eq!(vectored.slices().concat(), command.to_bytes());
+
+            #[cfg(feature = "std")]
+            {
+                use std::io::Write;
+
+                let mut written = alloc::vec::Vec::new();
+                let size = written.write_vectored(&vectored.io_slices()).unwrap();
+
+                assert_eq!(size, full.len());
+                assert_eq!(written, full);
+            }
+        }
+
+        // The message_payload is borrowed from the command, between the encoded source_port and dest_port
+        let vectored = commands[0].encode_vectored();
+        let slices = vectored.slices();
+
+        let Some(Pdu::SubmitSm(submit_sm)) = commands[0].pdu() else {
+            unreachable!()
+        };
+
+        let Some(TlvValue::MessagePayload(payload)) = submit_sm.tlvs()[1].value() else {
+            unreachable!()
+        };
+
+        assert_eq!(slices.len(), 4);
+        assert_eq!(slices[2].as_ptr(), payload.value.bytes().as_ptr());
+        assert_eq!(slices[2].len(), 4096);
+        assert_eq!(slices[3], [0x02, 0x0B, 0x00, 0x02, 0x00, 0x02]);
+    }
+
+    #[test]
+    fn probable_version() {
+        use crate::{
+            pdus::owned::{BroadcastSm, DeliverSm, SubmitSm},
+            tlvs::owned::{BroadcastRequestTlvValue, MessageDeliveryRequestTlvValue},
+            types::owned::OctetString,
+            values::BroadcastRepNum,
+        };
+
+        let broadcast_sm = BroadcastSm::builder()
+            .push_tlv(BroadcastRequestTlvValue::BroadcastRepNum(
+                BroadcastRepNum::new(2),
+            ))
+            .build();
+
+        let command = Command::new(CommandStatus::EsmeRok, 1, broadcast_sm);
+
+        assert_eq!(command.probable_version(), InterfaceVersion::Smpp5_0);
+
+        let deliver_sm = DeliverSm::builder()
+            .push_tlv(MessageDeliveryRequestTlvValue::DestNodeId(
+                OctetString::new([0x01; 6]).unwrap(),
+            ))
+            .build();
+
+        let command = Command::new(CommandStatus::EsmeRok, 2, deliver_sm);
+
+        assert_eq!(command.probable_version(), InterfaceVersion::Smpp5_0);
+
+        let command = Command::new(CommandStatus::EsmeRok, 3, Pdu::DataSm(Default::default()));
+
+        assert_eq!(command.probable_version(), InterfaceVersion::Smpp3_4);
+
+        let command = Command::new(CommandStatus::EsmeRok, 4, SubmitSm::default());
+
+        assert_eq!(
+            command.probable_version(),
+            InterfaceVersion::Smpp3_3OrEarlier(0x33)
+        );
+    }
 }
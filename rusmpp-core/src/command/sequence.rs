@@ -0,0 +1,79 @@
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Generator of unique `sequence_number`s.
+///
+/// Hands out `1` to [`SequenceNumbers::MAX`] and wraps around to `1`, as allowed by the `SMPP` specification.
+/// The generator can be shared across tasks since [`SequenceNumbers::next`] takes `&self`.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::command::SequenceNumbers;
+/// let sequence_numbers = SequenceNumbers::new();
+///
+/// assert_eq!(sequence_numbers.next(), 1);
+/// assert_eq!(sequence_numbers.next(), 2);
+/// ```
+#[derive(Debug)]
+pub struct SequenceNumbers {
+    next: AtomicU32,
+}
+
+impl SequenceNumbers {
+    /// The largest `sequence_number`.
+    pub const MAX: u32 = 0x7FFF_FFFF;
+
+    /// Creates a new [`SequenceNumbers`] starting at `1`.
+    pub const fn new() -> Self {
+        Self::starting_at(1)
+    }
+
+    /// Creates a new [`SequenceNumbers`] starting at `start`.
+    ///
+    /// Out of range values start at `1`.
+    pub const fn starting_at(start: u32) -> Self {
+        let start = if start == 0 || start > Self::MAX {
+            1
+        } else {
+            start
+        };
+
+        Self {
+            next: AtomicU32::new(start),
+        }
+    }
+
+    /// Returns the next `sequence_number`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&self) -> u32 {
+        // The closure never fails
+        self.next
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                Some(if current >= Self::MAX { 1 } else { current + 1 })
+            })
+            .unwrap_or_else(|current| current)
+    }
+}
+
+impl Default for SequenceNumbers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_around() {
+        let sequence_numbers = SequenceNumbers::starting_at(SequenceNumbers::MAX - 1);
+
+        assert_eq!(sequence_numbers.next(), SequenceNumbers::MAX - 1);
+        assert_eq!(sequence_numbers.next(), SequenceNumbers::MAX);
+        assert_eq!(sequence_numbers.next(), 1);
+
+        assert_eq!(SequenceNumbers::starting_at(0).next(), 1);
+        assert_eq!(SequenceNumbers::starting_at(u32::MAX).next(), 1);
+    }
+}
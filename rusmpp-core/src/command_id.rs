@@ -76,8 +76,49 @@ impl CommandId {
     ///
     /// Note that this function should be used only on response Ids.
     pub fn matching_request(self) -> CommandId {
-        let id: u32 = self.into();
-        (id & 0x0FFFFFFF).into()
+        matching_request_id(self.into()).into()
+    }
+
+    /// Returns the canonical `SMPP` name of this [`CommandId`] e.g. `submit_sm`, `deliver_sm_resp`.
+    ///
+    /// Returns `unknown` for [`CommandId::Other`].
+    pub const fn name(self) -> &'static str {
+        match self {
+            CommandId::BindReceiver => "bind_receiver",
+            CommandId::BindTransmitter => "bind_transmitter",
+            CommandId::QuerySm => "query_sm",
+            CommandId::SubmitSm => "submit_sm",
+            CommandId::DeliverSm => "deliver_sm",
+            CommandId::Unbind => "unbind",
+            CommandId::ReplaceSm => "replace_sm",
+            CommandId::CancelSm => "cancel_sm",
+            CommandId::BindTransceiver => "bind_transceiver",
+            CommandId::Outbind => "outbind",
+            CommandId::EnquireLink => "enquire_link",
+            CommandId::SubmitMulti => "submit_multi",
+            CommandId::AlertNotification => "alert_notification",
+            CommandId::DataSm => "data_sm",
+            CommandId::BroadcastSm => "broadcast_sm",
+            CommandId::QueryBroadcastSm => "query_broadcast_sm",
+            CommandId::CancelBroadcastSm => "cancel_broadcast_sm",
+            CommandId::GenericNack => "generic_nack",
+            CommandId::BindReceiverResp => "bind_receiver_resp",
+            CommandId::BindTransmitterResp => "bind_transmitter_resp",
+            CommandId::QuerySmResp => "query_sm_resp",
+            CommandId::SubmitSmResp => "submit_sm_resp",
+            CommandId::DeliverSmResp => "deliver_sm_resp",
+            CommandId::UnbindResp => "unbind_resp",
+            CommandId::ReplaceSmResp => "replace_sm_resp",
+            CommandId::CancelSmResp => "cancel_sm_resp",
+            CommandId::BindTransceiverResp => "bind_transceiver_resp",
+            CommandId::EnquireLinkResp => "enquire_link_resp",
+            CommandId::SubmitMultiResp => "submit_multi_resp",
+            CommandId::DataSmResp => "data_sm_resp",
+            CommandId::BroadcastSmResp => "broadcast_sm_resp",
+            CommandId::QueryBroadcastSmResp => "query_broadcast_sm_resp",
+            CommandId::CancelBroadcastSmResp => "cancel_broadcast_sm_resp",
+            CommandId::Other(_) => "unknown",
+        }
     }
 
     /// Returns the matching response [`CommandId`]
@@ -85,11 +126,42 @@ impl CommandId {
     /// Note that this function should be used only on request Ids.
     /// If the command does not have a response, then it will return [`CommandId::Other`].
     pub fn matching_response(self) -> CommandId {
-        let id: u32 = self.into();
-        (id | 0x80000000).into()
+        matching_response_id(self.into()).into()
     }
 }
 
+/// Returns the canonical `SMPP` name of a numeric command id e.g. `submit_sm`, `deliver_sm_resp`.
+///
+/// Returns `unknown` for unrecognized ids.
+///
+/// Useful for logging at the framing layer before decoding the command.
+pub fn command_name(id: u32) -> &'static str {
+    CommandId::from(id).name()
+}
+
+/// Returns true if the numeric command id represents a response PDU, i.e. bit 31 is set.
+///
+/// See [`CommandId::is_response`].
+pub const fn is_response(id: u32) -> bool {
+    id & 0x80000000 == 0x80000000
+}
+
+/// Returns the matching request command id of a numeric response command id, i.e. clears bit 31.
+///
+/// Note that generic_nack (0x80000000) maps to 0x00000000, which is not a valid request.
+///
+/// See [`CommandId::matching_request`].
+pub const fn matching_request_id(resp_id: u32) -> u32 {
+    resp_id & !0x80000000
+}
+
+/// Returns the matching response command id of a numeric request command id, i.e. sets bit 31.
+///
+/// See [`CommandId::matching_response`].
+pub const fn matching_response_id(req_id: u32) -> u32 {
+    req_id | 0x80000000
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests::TestInstance;
@@ -139,6 +211,14 @@ mod tests {
         assert!(CommandId::SubmitSmResp.is_response());
     }
 
+    #[test]
+    fn is_response_id() {
+        assert!(!super::is_response(0x00000004));
+        assert!(super::is_response(0x80000004));
+        assert!(super::is_response(0x80000000));
+        assert!(!super::is_response(0x00000115));
+    }
+
     #[test]
     fn get_matching_request() {
         assert_eq!(
@@ -147,6 +227,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn matching_ids() {
+        assert_eq!(matching_request_id(0x80000004), 0x00000004);
+        assert_eq!(matching_request_id(0x00000004), 0x00000004);
+        assert_eq!(matching_request_id(0x80000000), 0x00000000);
+        assert_eq!(matching_response_id(0x00000004), 0x80000004);
+        assert_eq!(matching_response_id(0x80000004), 0x80000004);
+
+        assert_eq!(
+            CommandId::from(matching_request_id(CommandId::SubmitSmResp.into())),
+            CommandId::SubmitSm
+        );
+        assert_eq!(
+            CommandId::from(matching_response_id(CommandId::SubmitSm.into())),
+            CommandId::SubmitSmResp
+        );
+    }
+
     #[test]
     fn get_matching_response() {
         assert_eq!(
@@ -159,6 +257,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn name() {
+        assert_eq!(command_name(0x00000004), "submit_sm");
+        assert_eq!(command_name(0x80000005), "deliver_sm_resp");
+        assert_eq!(command_name(0x80000000), "generic_nack");
+        assert_eq!(command_name(0x00000103), "data_sm");
+        assert_eq!(command_name(0x00000115), "unknown");
+        assert_eq!(
+            CommandId::QueryBroadcastSmResp.name(),
+            "query_broadcast_sm_resp"
+        );
+    }
+
     #[test]
     fn encode_decode() {
         crate::tests::owned::encode_decode_test_instances::<CommandId>();
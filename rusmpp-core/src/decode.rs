@@ -5,3 +5,18 @@ pub mod owned;
 
 mod error;
 pub use error::*;
+
+mod options;
+pub use options::DecodeOptions;
+
+#[cfg(any(test, feature = "alloc"))]
+mod context;
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use context::DecodeContext;
+
+#[cfg(any(test, feature = "alloc"))]
+mod decoder;
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use decoder::Decoder;
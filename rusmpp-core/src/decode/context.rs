@@ -0,0 +1,408 @@
+//! Settings of one owned decoding.
+
+use alloc::vec::Vec;
+
+use crate::{
+    encode::Length,
+    tlvs::{
+        TlvTag,
+        owned::{Tlv, find_duplicate_tlv},
+    },
+};
+
+use super::{DecodeError, DecodeOptions};
+
+/// Settings of one owned decoding, threaded through the decoding of a `PDU` and its `TLV`s.
+///
+/// See [`DecodeWithContext`](crate::decode::owned::DecodeWithContext).
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{
+/// #     decode::{DecodeContext, DecodeOptions, owned::DecodeWithContext},
+/// #     tlvs::owned::{Tlv, TlvValue},
+/// # };
+/// // tag: 0x1400, value_length: 6
+/// let bytes = [0x14, 0x00, 0x00, 0x06, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+///
+/// let mut context = DecodeContext::new(DecodeOptions::new().with_other_tlv_value_limit(Some(2)));
+///
+/// let (tlv, size) = Tlv::decode_with_context(&bytes, bytes.len(), &mut context).unwrap();
+///
+/// assert_eq!(size, 10);
+///
+/// let Some(TlvValue::Other { value, .. }) = tlv.value() else {
+///     unreachable!()
+/// };
+///
+/// assert_eq!(value.bytes(), &[0x01, 0x02]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DecodeContext {
+    options: DecodeOptions,
+    /// Buffers reused for the values of unknown `TLV`s, see [`Decoder`](crate::decode::Decoder).
+    pub(super) buffers: Vec<Vec<u8>>,
+}
+
+impl DecodeContext {
+    /// Creates a new [`DecodeContext`] decoding with `options`.
+    pub const fn new(options: DecodeOptions) -> Self {
+        Self {
+            options,
+            buffers: Vec::new(),
+        }
+    }
+
+    /// Returns the [`DecodeOptions`] of this decoding.
+    pub const fn options(&self) -> &DecodeOptions {
+        &self.options
+    }
+
+    /// Checks the `TLV`s decoded into one field against the options of this decoding.
+    ///
+    /// Called by the derived [`DecodeWithContext`](crate::decode::owned::DecodeWithContext) of the `PDU`s,
+    /// see [`DecodeOptions::reject_duplicate_tlvs`] and [`DecodeOptions::strict_tlv_bounds`].
+    pub fn check_tlvs(&self, tlvs: &[Tlv]) -> Result<(), DecodeError> {
+        if self.options.reject_duplicate_tlvs {
+            if let Some(tag) = find_duplicate_tlv(tlvs) {
+                return Err(DecodeError::duplicate_tlv(tag.into()));
+            }
+        }
+
+        if self.options.strict_tlv_bounds {
+            check_tlv_bounds(tlvs)?;
+        }
+
+        Ok(())
+    }
+
+    /// Takes a pooled buffer, if any.
+    ///
+    /// Prefers a buffer with a capacity of at least `length` bytes.
+    pub(crate) fn take_buffer(&mut self, length: usize) -> Option<Vec<u8>> {
+        match self
+            .buffers
+            .iter()
+            .position(|buffer| buffer.capacity() >= length)
+        {
+            Some(position) => Some(self.buffers.swap_remove(position)),
+            None => self.buffers.pop(),
+        }
+    }
+}
+
+/// Checks that the `value_length` of every known `TLV` matches the length of its decoded value.
+///
+/// Unknown `TLV`s are skipped, their value is exactly `value_length` bytes or capped by
+/// [`DecodeOptions::other_tlv_value_limit`].
+fn check_tlv_bounds(tlvs: &[Tlv]) -> Result<(), DecodeError> {
+    for tlv in tlvs {
+        if matches!(tlv.tag(), TlvTag::Other(_)) {
+            continue;
+        }
+
+        let actual = tlv.value().map(Length::length).unwrap_or(0);
+
+        if actual != tlv.value_length() as usize {
+            return Err(DecodeError::tlv_length_mismatch(
+                tlv.tag().into(),
+                tlv.value_length(),
+                actual,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CommandStatus,
+        command::owned::{Command, encode_many},
+        decode::{DecodeError, DecodeErrorKind, owned::DecodeWithContext},
+        encode::{Encode, Length},
+        pdus::owned::{BindTransmitterResp, BroadcastSm, DataSm, SubmitSm, TlvContainer},
+        tlvs::{
+            TlvTag,
+            owned::{BroadcastRequestTlvValue, MessageSubmissionRequestTlvValue, Tlv, TlvValue},
+        },
+        types::owned::AnyOctetString,
+        values::{
+            BroadcastAreaFormat, InterfaceVersion, UserMessageReference,
+            owned::{BroadcastAreaIdentifier, MessagePayload},
+        },
+    };
+
+    use super::*;
+
+    fn decode<T: DecodeWithContext>(
+        bytes: &[u8],
+        options: DecodeOptions,
+    ) -> Result<T, DecodeError> {
+        T::decode_with_context(bytes, bytes.len(), &mut DecodeContext::new(options))
+            .map(|(value, _)| value)
+    }
+
+    fn submit_sm() -> SubmitSm {
+        SubmitSm::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::Other {
+                tag: TlvTag::Other(0x1400),
+                value: AnyOctetString::new([0xAB; 1024]),
+            })
+            .push_tlv(MessageSubmissionRequestTlvValue::UserMessageReference(
+                UserMessageReference::new(16),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn capped() {
+        let submit_sm = submit_sm();
+
+        let mut buf = alloc::vec![0; submit_sm.length()];
+        let size = submit_sm.encode(&mut buf);
+
+        let options = DecodeOptions::new().with_other_tlv_value_limit(Some(8));
+
+        let (decoded, decoded_size) =
+            SubmitSm::decode_with_context(&buf, size, &mut DecodeContext::new(options)).unwrap();
+
+        assert_eq!(decoded_size, size);
+
+        let other = &decoded.tlvs()[0];
+
+        assert_eq!(other.value_length(), 8);
+        assert_eq!(other.uncapped_value_length(), Some(1024));
+        assert_eq!(
+            other.value(),
+            Some(&TlvValue::Other {
+                tag: TlvTag::Other(0x1400),
+                value: AnyOctetString::new([0xAB; 8]),
+            })
+        );
+
+        // The following TLV is decoded as usual
+        assert_eq!(decoded.tlvs()[1], submit_sm.tlvs()[1]);
+        assert_eq!(decoded.tlvs()[1].uncapped_value_length(), None);
+    }
+
+    #[test]
+    fn capped_encode() {
+        let submit_sm = submit_sm();
+
+        let options = DecodeOptions::new().with_other_tlv_value_limit(Some(8));
+
+        let capped = Command::decode_with(&encode(submit_sm.clone()), &options).unwrap();
+
+        // The capped TLV is encoded with the length of the kept value
+        let mut bytes = alloc::vec::Vec::new();
+
+        encode_many(&[capped], &mut bytes);
+
+        assert_eq!(bytes.len(), encode(submit_sm.clone()).len() - 1016);
+
+        let command = Command::decode_with(&bytes, &DecodeOptions::strict()).unwrap();
+
+        let Some(crate::pdus::owned::Pdu::SubmitSm(decoded)) = command.pdu() else {
+            panic!("Expected a submit_sm");
+        };
+
+        assert_eq!(decoded.tlvs()[0].value_length(), 8);
+        assert_eq!(decoded.tlvs()[0].uncapped_value_length(), None);
+        assert_eq!(decoded.tlvs()[1], submit_sm.tlvs()[1]);
+    }
+
+    #[test]
+    fn uncapped() {
+        let submit_sm = submit_sm();
+
+        let mut buf = alloc::vec![0; submit_sm.length()];
+        submit_sm.encode(&mut buf);
+
+        // Values within the limit are copied completely
+        let options = DecodeOptions::new().with_other_tlv_value_limit(Some(1024));
+
+        assert_eq!(decode::<SubmitSm>(&buf, options).unwrap(), submit_sm);
+        assert_eq!(
+            decode::<SubmitSm>(&buf, DecodeOptions::new()).unwrap(),
+            submit_sm
+        );
+    }
+
+    #[test]
+    fn capped_incomplete() {
+        // tag: 0x1400, value_length: 6, only 3 value bytes
+        let bytes = [0x14, 0x00, 0x00, 0x06, 0x01, 0x02, 0x03];
+
+        let options = DecodeOptions::new().with_other_tlv_value_limit(Some(2));
+
+        assert!(decode::<Tlv>(&bytes, options).is_err());
+    }
+
+    fn encode(pdu: impl Into<crate::pdus::owned::Pdu>) -> alloc::vec::Vec<u8> {
+        let mut buf = alloc::vec::Vec::new();
+
+        encode_many(&[Command::new(CommandStatus::EsmeRok, 1, pdu)], &mut buf);
+
+        buf
+    }
+
+    #[test]
+    fn strict_duplicate() {
+        let data_sm = DataSm::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new(b"Hi")),
+            ))
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new(b"Ho")),
+            ))
+            .build();
+
+        let bytes = encode(data_sm.clone());
+
+        // Lenient by default, all TLVs are kept and the last one wins
+        let command = Command::decode_with(&bytes, &DecodeOptions::new()).unwrap();
+
+        assert_eq!(command.pdu(), Some(&data_sm.into()));
+
+        let Some(crate::pdus::owned::Pdu::DataSm(decoded)) = command.pdu() else {
+            panic!("Expected a data_sm");
+        };
+
+        assert_eq!(
+            decoded.get_tlv(TlvTag::MessagePayload).and_then(Tlv::value),
+            Some(&TlvValue::MessagePayload(MessagePayload::new(
+                AnyOctetString::new(b"Ho")
+            )))
+        );
+
+        let options = DecodeOptions::new().with_reject_duplicate_tlvs(true);
+
+        let error = Command::decode_with(&bytes, &options).unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            DecodeErrorKind::DuplicateTlv { tag: 0x0424 }
+        ));
+    }
+
+    #[test]
+    fn strict_duplicate_body() {
+        let submit_sm = SubmitSm::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new(b"Hi")),
+            ))
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new(b"Ho")),
+            ))
+            .build();
+
+        let mut buf = alloc::vec![0; submit_sm.length()];
+        submit_sm.encode(&mut buf);
+
+        assert_eq!(
+            decode::<SubmitSm>(&buf, DecodeOptions::new()).unwrap(),
+            submit_sm
+        );
+
+        let options = DecodeOptions::new().with_reject_duplicate_tlvs(true);
+
+        let error = decode::<SubmitSm>(&buf, options).unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            DecodeErrorKind::DuplicateTlv { tag: 0x0424 }
+        ));
+    }
+
+    #[test]
+    fn strict_tlv_bounds_body() {
+        let bind_resp = BindTransmitterResp::builder()
+            .sc_interface_version(Some(InterfaceVersion::Smpp5_0))
+            .build();
+
+        let mut buf = alloc::vec![0; bind_resp.length()];
+        let size = bind_resp.encode(&mut buf);
+
+        // value_length: 2 for the 1 byte of the sc_interface_version
+        buf[size - 2] = 0x02;
+
+        let decoded = decode::<BindTransmitterResp>(&buf, DecodeOptions::new()).unwrap();
+
+        assert_eq!(
+            decoded.sc_interface_version(),
+            Some(InterfaceVersion::Smpp5_0)
+        );
+
+        let options = DecodeOptions::new().with_strict_tlv_bounds(true);
+
+        let error = decode::<BindTransmitterResp>(&buf, options).unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            DecodeErrorKind::TlvLengthMismatch {
+                tag: 0x0210,
+                value_length: 2,
+                actual: 1
+            }
+        ));
+    }
+
+    #[test]
+    fn strict_repeatable() {
+        let area = || {
+            BroadcastRequestTlvValue::BroadcastAreaIdentifier(BroadcastAreaIdentifier::new(
+                BroadcastAreaFormat::AliasName,
+                AnyOctetString::new(b"area"),
+            ))
+        };
+
+        let broadcast_sm = BroadcastSm::builder()
+            .push_tlv(area())
+            .push_tlv(area())
+            .build();
+
+        let bytes = encode(broadcast_sm);
+
+        let options = DecodeOptions::new().with_reject_duplicate_tlvs(true);
+
+        assert!(Command::decode_with(&bytes, &options).is_ok());
+    }
+
+    #[test]
+    fn lenient() {
+        use crate::pdus::owned::{DeliverSm, PduBody};
+
+        let mut body = DeliverSm::default().encode_body();
+
+        // callback_num with a value of 2 bytes, followed by a source_port
+        body.extend_from_slice(&[0x03, 0x81, 0x00, 0x02, 0x01, 0x01]);
+        body.extend_from_slice(&[0x02, 0x0A, 0x00, 0x02, 0x00, 0x01]);
+
+        let error = DeliverSm::decode_body(&body).unwrap_err();
+
+        assert_eq!(error.tlv().map(|tlv| tlv.tag), Some(0x0381));
+
+        let options = DecodeOptions::new().with_lenient_tlvs(true);
+
+        let deliver_sm = decode::<DeliverSm>(&body, options).unwrap();
+
+        assert_eq!(
+            deliver_sm.tlvs()[0].value(),
+            Some(&TlvValue::Other {
+                tag: TlvTag::CallbackNum,
+                value: AnyOctetString::new([0x01, 0x01]),
+            })
+        );
+        assert_eq!(deliver_sm.tlvs()[0].value_length(), 2);
+        assert_eq!(deliver_sm.tlvs()[1].value(), Some(&TlvValue::SourcePort(1)));
+
+        // The raw bytes are re-encoded unchanged
+        assert_eq!(deliver_sm.encode_body(), body);
+
+        // The default options are not lenient
+        assert!(decode::<DeliverSm>(&body, DecodeOptions::new()).is_err());
+    }
+}
@@ -0,0 +1,238 @@
+//! Stateful decoder reusing allocations across calls.
+
+use crate::{
+    command::owned::Command,
+    decode::{DecodeContext, DecodeError, DecodeOptions},
+    tlvs::owned::TlvValue,
+};
+
+/// Decoder reusing the buffers of unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) `TLV`s across calls.
+///
+/// Owned decoding allocates a buffer for the value of every unknown `TLV`.
+/// Receivers decoding many `PDU`s can hand decoded commands back using [`Decoder::recycle`],
+/// the buffers of their unknown `TLV`s are then reused by the following calls of [`Decoder::decode_reusing`].
+///
+/// The pool is owned by the decoder and passed explicitly to the decoding of unknown `TLV`s.
+/// Decoded values are identical to the stateless [`Command::decode_with`] with the same [`DecodeOptions`].
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{
+/// #     CommandStatus,
+/// #     command::owned::{Command, encode_many},
+/// #     decode::Decoder,
+/// #     pdus::owned::SubmitSm,
+/// #     tlvs::{TlvTag, owned::MessageSubmissionRequestTlvValue},
+/// #     types::owned::AnyOctetString,
+/// # };
+/// let command = Command::new(
+///     CommandStatus::EsmeRok,
+///     1,
+///     SubmitSm::builder()
+///         .push_tlv(MessageSubmissionRequestTlvValue::Other {
+///             tag: TlvTag::Other(0x1400),
+///             value: AnyOctetString::new([0x01; 64]),
+///         })
+///         .build(),
+/// );
+///
+/// let mut bytes = Vec::new();
+/// encode_many(&[command.clone()], &mut bytes);
+///
+/// let mut decoder = Decoder::new();
+///
+/// for _ in 0..3 {
+///     let decoded = decoder.decode_reusing(&bytes).unwrap();
+///
+///     assert_eq!(decoded, command);
+///
+///     decoder.recycle(decoded);
+/// }
+///
+/// assert_eq!(decoder.pooled(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct Decoder {
+    context: DecodeContext,
+}
+
+impl Decoder {
+    /// Maximum number of buffers kept for reuse.
+    pub const MAX_POOLED: usize = 64;
+
+    /// Creates a new [`Decoder`] with the default [`DecodeOptions`] and without pooled buffers.
+    pub const fn new() -> Self {
+        Self::with_options(DecodeOptions::new())
+    }
+
+    /// Creates a new [`Decoder`] decoding with `options` and without pooled buffers.
+    pub const fn with_options(options: DecodeOptions) -> Self {
+        Self {
+            context: DecodeContext::new(options),
+        }
+    }
+
+    /// Returns the [`DecodeOptions`] of this decoder.
+    pub const fn options(&self) -> &DecodeOptions {
+        self.context.options()
+    }
+
+    /// Returns the number of buffers available for reuse.
+    pub fn pooled(&self) -> usize {
+        self.context.buffers.len()
+    }
+
+    /// Decodes one [`Command`] from `bytes`, reusing pooled buffers for unknown `TLV` values.
+    ///
+    /// See [`Command::decode_with`].
+    pub fn decode_reusing(&mut self, bytes: &[u8]) -> Result<Command, DecodeError> {
+        Command::decode_with_options(bytes, &mut self.context)
+    }
+
+    /// Takes the buffers of the unknown `TLV`s of `command` for reuse.
+    pub fn recycle(&mut self, command: Command) {
+        let Some(pdu) = command.into_parts().pdu else {
+            return;
+        };
+
+        let values = pdu
+            .into_tlvs()
+            .into_iter()
+            .filter_map(|tlv| tlv.into_parts().value);
+
+        for value in values {
+            if self.context.buffers.len() >= Self::MAX_POOLED {
+                return;
+            }
+
+            if let TlvValue::Other { value, .. } = value {
+                self.context.buffers.push(value.into_bytes());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::{
+        CommandStatus,
+        command::owned::encode_many,
+        pdus::owned::SubmitSm,
+        tests::owned::test_commands,
+        tlvs::{TlvTag, owned::MessageSubmissionRequestTlvValue},
+        types::owned::AnyOctetString,
+    };
+
+    use super::*;
+
+    fn encoded(command: &Command) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        encode_many(core::slice::from_ref(command), &mut bytes);
+
+        bytes
+    }
+
+    fn command_with_others() -> Command {
+        Command::new(
+            CommandStatus::EsmeRok,
+            1,
+            SubmitSm::builder()
+                .push_tlv(MessageSubmissionRequestTlvValue::Other {
+                    tag: TlvTag::Other(0x1400),
+                    value: AnyOctetString::new([0x01; 128]),
+                })
+                .push_tlv(MessageSubmissionRequestTlvValue::SourcePort(1))
+                .push_tlv(MessageSubmissionRequestTlvValue::Other {
+                    tag: TlvTag::Other(0x1401),
+                    value: AnyOctetString::new([0x02; 16]),
+                })
+                .build(),
+        )
+    }
+
+    #[test]
+    fn matches_stateless() {
+        let mut decoder = Decoder::new();
+
+        for command in test_commands().into_iter().chain([command_with_others()]) {
+            let bytes = encoded(&command);
+
+            let stateless = Command::decode_with(&bytes, &DecodeOptions::new()).unwrap();
+            let reusing = decoder.decode_reusing(&bytes).unwrap();
+
+            assert_eq!(reusing, stateless);
+
+            decoder.recycle(reusing);
+        }
+    }
+
+    #[test]
+    fn reuses_buffers() {
+        let command = command_with_others();
+        let bytes = encoded(&command);
+
+        let mut decoder = Decoder::new();
+
+        let decoded = decoder.decode_reusing(&bytes).unwrap();
+
+        decoder.recycle(decoded);
+
+        assert_eq!(decoder.pooled(), 2);
+
+        let pointers: Vec<_> = decoder
+            .context
+            .buffers
+            .iter()
+            .map(|buffer| buffer.as_ptr())
+            .collect();
+
+        let decoded = decoder.decode_reusing(&bytes).unwrap();
+
+        assert_eq!(decoded, command);
+        assert_eq!(decoder.pooled(), 0);
+
+        let Some(crate::pdus::owned::Pdu::SubmitSm(submit_sm)) = decoded.pdu() else {
+            panic!("Expected a submit_sm");
+        };
+
+        for tlv in submit_sm.tlvs() {
+            if let Some(TlvValue::Other { value, .. }) = tlv.value() {
+                assert!(pointers.contains(&value.bytes().as_ptr()));
+            }
+        }
+
+        // Stateless decoding does not use the pool
+        let decoded = Command::decode_with(&bytes, &DecodeOptions::new()).unwrap();
+
+        assert_eq!(decoded, command);
+    }
+
+    #[test]
+    fn with_options() {
+        let mut bytes = encoded(&command_with_others());
+
+        bytes.extend_from_slice(&[0x00, 0x00]);
+
+        assert!(Decoder::new().decode_reusing(&bytes).is_ok());
+        assert!(
+            Decoder::with_options(DecodeOptions::strict())
+                .decode_reusing(&bytes)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn max_pooled() {
+        let mut decoder = Decoder::new();
+
+        for _ in 0..Decoder::MAX_POOLED {
+            decoder.recycle(command_with_others());
+        }
+
+        assert_eq!(decoder.pooled(), Decoder::MAX_POOLED);
+    }
+}
@@ -4,6 +4,7 @@ use crate::fields::SmppField;
 #[derive(Debug)]
 pub struct DecodeError {
     kind: DecodeErrorKind,
+    tlv: Option<DecodeErrorTlv>,
     #[cfg(feature = "verbose")]
     source: Option<alloc::boxed::Box<DecodeErrorSource>>,
 }
@@ -12,10 +13,27 @@ impl DecodeError {
     #[inline]
     pub const fn new(kind: DecodeErrorKind) -> Self {
         #[cfg(feature = "verbose")]
-        return Self { kind, source: None };
+        return Self {
+            kind,
+            tlv: None,
+            source: None,
+        };
 
         #[cfg(not(feature = "verbose"))]
-        Self { kind }
+        Self { kind, tlv: None }
+    }
+
+    /// Attaches the `TLV` that failed to decode.
+    ///
+    /// Keeps an already attached `TLV`.
+    #[inline]
+    #[cold]
+    pub const fn with_tlv(mut self, tag: u16, remaining: usize) -> Self {
+        if self.tlv.is_none() {
+            self.tlv = Some(DecodeErrorTlv { tag, remaining });
+        }
+
+        self
     }
 
     #[inline]
@@ -32,7 +50,13 @@ impl DecodeError {
     #[cfg(feature = "verbose")]
     #[cfg_attr(docsrs, doc(cfg(feature = "verbose")))]
     pub fn as_source(self, field: SmppField) -> DecodeError {
-        DecodeError::new(self.kind).with_source(field, self)
+        let tlv = self.tlv;
+
+        DecodeError {
+            tlv,
+            ..DecodeError::new(self.kind)
+        }
+        .with_source(field, self)
     }
 
     #[inline]
@@ -47,6 +71,12 @@ impl DecodeError {
         self.kind
     }
 
+    /// Returns the `TLV` that failed to decode, if the error occurred while decoding a `TLV` value.
+    #[inline]
+    pub const fn tlv(&self) -> Option<DecodeErrorTlv> {
+        self.tlv
+    }
+
     #[inline]
     pub const fn unexpected_eof() -> Self {
         Self::new(DecodeErrorKind::UnexpectedEof)
@@ -72,6 +102,30 @@ impl DecodeError {
         Self::new(DecodeErrorKind::TooManyElements { max })
     }
 
+    #[inline]
+    pub const fn trailing_bytes(count: usize) -> Self {
+        Self::new(DecodeErrorKind::TrailingBytes { count })
+    }
+
+    #[inline]
+    pub const fn duplicate_tlv(tag: u16) -> Self {
+        Self::new(DecodeErrorKind::DuplicateTlv { tag })
+    }
+
+    #[inline]
+    pub const fn tlv_length_mismatch(tag: u16, value_length: u16, actual: usize) -> Self {
+        Self::new(DecodeErrorKind::TlvLengthMismatch {
+            tag,
+            value_length,
+            actual,
+        })
+    }
+
+    #[inline]
+    pub const fn max_length(actual: usize, max: usize) -> Self {
+        Self::new(DecodeErrorKind::MaxLength { actual, max })
+    }
+
     /// Checks recursively if the field exists in the sources tree.
     #[cfg(feature = "verbose")]
     #[cfg_attr(docsrs, doc(cfg(feature = "verbose")))]
@@ -108,6 +162,21 @@ impl DecodeErrorSource {
     }
 }
 
+/// The `TLV` that failed to decode, see [`DecodeError::tlv`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DecodeErrorTlv {
+    /// The tag of the `TLV`.
+    pub tag: u16,
+    /// The number of bytes remaining in the source when decoding the value of the `TLV` started.
+    pub remaining: usize,
+}
+
+impl core::fmt::Display for DecodeErrorTlv {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "tag: {:#06X}, remaining: {}", self.tag, self.remaining)
+    }
+}
+
 /// Kind of [`DecodeError`].
 #[derive(Debug, Copy, Clone)]
 #[non_exhaustive]
@@ -124,6 +193,33 @@ pub enum DecodeErrorKind {
     TooManyElements {
         max: usize,
     },
+    /// The source contained more bytes than the decoded value.
+    ///
+    /// E.g. decoding exactly one `Command` from a slice that contains trailing bytes.
+    TrailingBytes {
+        count: usize,
+    },
+    /// A single-occurrence `TLV` occurred more than once.
+    ///
+    /// Only reported with [`DecodeOptions::reject_duplicate_tlvs`](crate::decode::DecodeOptions::reject_duplicate_tlvs).
+    DuplicateTlv {
+        tag: u16,
+    },
+    /// The `value_length` of a `TLV` does not match the length of its decoded value.
+    ///
+    /// Only reported with [`DecodeOptions::strict_tlv_bounds`](crate::decode::DecodeOptions::strict_tlv_bounds).
+    TlvLengthMismatch {
+        tag: u16,
+        value_length: u16,
+        actual: usize,
+    },
+    /// The `command_length` exceeds the maximum allowed length.
+    ///
+    /// See [`DecodeOptions::max_pdu_size`](crate::decode::DecodeOptions::max_pdu_size).
+    MaxLength {
+        actual: usize,
+        max: usize,
+    },
 }
 
 /// An error that can occur when decoding a `COctetString`.
@@ -163,16 +259,18 @@ impl core::error::Error for DecodeErrorSource {
 
 impl core::fmt::Display for DecodeError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Decode error. kind: {}", self.kind)?;
+
+        if let Some(tlv) = &self.tlv {
+            write!(f, ", tlv: [{tlv}]")?;
+        }
+
         #[cfg(feature = "verbose")]
-        return match &self.source {
-            Some(source) => {
-                write!(f, "Decode error. kind: {}, source: [{source}]", self.kind,)
-            }
-            None => write!(f, "Decode error. kind: {}", self.kind),
-        };
+        if let Some(source) = &self.source {
+            write!(f, ", source: [{source}]")?;
+        }
 
-        #[cfg(not(feature = "verbose"))]
-        write!(f, "Decode error. kind: {}", self.kind)
+        Ok(())
     }
 }
 
@@ -203,6 +301,25 @@ impl core::fmt::Display for DecodeErrorKind {
             DecodeErrorKind::TooManyElements { max } => {
                 write!(f, "Too many elements. max: {max}")
             }
+            DecodeErrorKind::TrailingBytes { count } => {
+                write!(f, "Trailing bytes. count: {count}")
+            }
+            DecodeErrorKind::DuplicateTlv { tag } => {
+                write!(f, "Duplicate TLV. tag: {tag:#06X}")
+            }
+            DecodeErrorKind::TlvLengthMismatch {
+                tag,
+                value_length,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "TLV length mismatch. tag: {tag:#06X}, value_length: {value_length}, actual: {actual}"
+                )
+            }
+            DecodeErrorKind::MaxLength { actual, max } => {
+                write!(f, "Max length exceeded. actual: {actual}, max: {max}")
+            }
         }
     }
 }
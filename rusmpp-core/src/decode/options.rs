@@ -0,0 +1,136 @@
+//! Decoding strictness.
+
+/// Options tuning the leniency of owned decoding, see [`Command::decode_with`](crate::command::owned::Command::decode_with).
+///
+/// The options are threaded through the decoding of the `PDU` and its `TLV`s by a [`DecodeContext`](crate::decode::DecodeContext).
+///
+/// `SMPP` implementations differ in how closely they follow the specification.
+/// The [`Default`] options are lenient but safe: malformed but harmless input is tolerated, while oversized commands are rejected.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::decode::DecodeOptions;
+/// let options = DecodeOptions::new()
+///     .with_strict_length(true)
+///     .with_max_pdu_size(Some(1024));
+///
+/// assert!(options.strict_length);
+/// assert!(!options.strict_tlv_bounds);
+/// assert_eq!(options.max_pdu_size, Some(1024));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct DecodeOptions {
+    /// Reject commands whose body is not completely consumed by decoding,
+    /// and input containing bytes after the `command_length`.
+    ///
+    /// Lenient decoding ignores such bytes.
+    pub strict_length: bool,
+    /// Reject `TLV`s whose `value_length` does not match the length of the decoded value,
+    /// e.g. an `sms_signal` of 3 bytes.
+    ///
+    /// Lenient decoding keeps the decoded value.
+    pub strict_tlv_bounds: bool,
+    /// Keep `TLV`s that fail to decode as their typed value as [`TlvValue::Other`](crate::tlvs::owned::TlvValue::Other)
+    /// with their tag and raw value bytes, e.g. a `callback_num` of 2 bytes sent by a noncompliant `MC`.
+    ///
+    /// `TLV`s whose value exceeds the input still fail to decode.
+    /// Strict decoding fails the whole command.
+    pub lenient_tlvs: bool,
+    /// Reject `PDU`s carrying a single-occurrence `TLV` more than once, e.g. two `message_payload`s,
+    /// with a [`DecodeErrorKind::DuplicateTlv`](crate::decode::DecodeErrorKind::DuplicateTlv) error.
+    ///
+    /// See [`TlvTag::is_repeatable`](crate::tlvs::TlvTag::is_repeatable) for the `TLV`s that may occur more than once.
+    ///
+    /// Lenient decoding keeps all `TLV`s, lookups return the last occurrence, see [`get_tlv`](crate::tlvs::owned::get_tlv).
+    pub reject_duplicate_tlvs: bool,
+    /// Maximum number of value bytes to copy for unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) `TLV`s.
+    /// [`None`] copies the whole value.
+    ///
+    /// Memory-constrained receivers that only log such `TLV`s can cap the copy.
+    /// The whole value is still consumed from the input.
+    /// The `value_length` of a capped `TLV` is the length of the kept value, see [`Tlv::uncapped_value_length`](crate::tlvs::owned::Tlv::uncapped_value_length).
+    pub other_tlv_value_limit: Option<usize>,
+    /// Reject commands with an unknown `command_id`.
+    ///
+    /// Lenient decoding keeps their body as a [`Pdu::Other`](crate::pdus::owned::Pdu::Other).
+    pub reject_unknown_commands: bool,
+    /// Maximum `command_length` accepted. [`None`] accepts any length.
+    pub max_pdu_size: Option<usize>,
+}
+
+impl DecodeOptions {
+    /// Default maximum `command_length`.
+    pub const DEFAULT_MAX_PDU_SIZE: usize = 8192;
+
+    /// Creates the default, lenient [`DecodeOptions`].
+    pub const fn new() -> Self {
+        Self {
+            strict_length: false,
+            strict_tlv_bounds: false,
+            lenient_tlvs: false,
+            reject_duplicate_tlvs: false,
+            other_tlv_value_limit: None,
+            reject_unknown_commands: false,
+            max_pdu_size: Some(Self::DEFAULT_MAX_PDU_SIZE),
+        }
+    }
+
+    /// Creates [`DecodeOptions`] rejecting any deviation from the specification.
+    pub const fn strict() -> Self {
+        Self {
+            strict_length: true,
+            strict_tlv_bounds: true,
+            lenient_tlvs: false,
+            reject_duplicate_tlvs: true,
+            other_tlv_value_limit: None,
+            reject_unknown_commands: true,
+            max_pdu_size: Some(Self::DEFAULT_MAX_PDU_SIZE),
+        }
+    }
+
+    pub const fn with_strict_length(mut self, strict_length: bool) -> Self {
+        self.strict_length = strict_length;
+        self
+    }
+
+    pub const fn with_strict_tlv_bounds(mut self, strict_tlv_bounds: bool) -> Self {
+        self.strict_tlv_bounds = strict_tlv_bounds;
+        self
+    }
+
+    pub const fn with_lenient_tlvs(mut self, lenient_tlvs: bool) -> Self {
+        self.lenient_tlvs = lenient_tlvs;
+        self
+    }
+
+    pub const fn with_reject_duplicate_tlvs(mut self, reject_duplicate_tlvs: bool) -> Self {
+        self.reject_duplicate_tlvs = reject_duplicate_tlvs;
+        self
+    }
+
+    pub const fn with_other_tlv_value_limit(
+        mut self,
+        other_tlv_value_limit: Option<usize>,
+    ) -> Self {
+        self.other_tlv_value_limit = other_tlv_value_limit;
+        self
+    }
+
+    pub const fn with_reject_unknown_commands(mut self, reject_unknown_commands: bool) -> Self {
+        self.reject_unknown_commands = reject_unknown_commands;
+        self
+    }
+
+    pub const fn with_max_pdu_size(mut self, max_pdu_size: Option<usize>) -> Self {
+        self.max_pdu_size = max_pdu_size;
+        self
+    }
+}
+
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,6 +2,9 @@
 //!
 use crate::decode::DecodeError;
 
+#[cfg(any(test, feature = "alloc"))]
+use crate::decode::DecodeContext;
+
 /// Trait for decoding `SMPP` values from a slice.
 ///
 /// # Implementation
@@ -370,6 +373,44 @@ pub trait DecodeWithKeyOptional: Sized {
     ) -> Result<Option<(Self, usize)>, DecodeError>;
 }
 
+/// Trait for decoding `SMPP` values from a slice with a specified length and a [`DecodeContext`].
+///
+/// Implemented by values holding `TLV`s, whose decoding is tuned by the [`DecodeOptions`](crate::decode::DecodeOptions) of the context.
+/// [`Rusmpp`](crate::Rusmpp) implements it for structs with `Vec<Tlv>` or `Option<Tlv>` fields,
+/// their [`DecodeWithLength`] implementation decodes with the default context.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{
+/// #     decode::{DecodeContext, DecodeOptions, owned::DecodeWithContext},
+/// #     pdus::owned::{DeliverSm, PduBody},
+/// # };
+/// let mut body = DeliverSm::default().encode_body();
+///
+/// // callback_num with a value of 2 bytes, at least 4 are required
+/// body.extend_from_slice(&[0x03, 0x81, 0x00, 0x02, 0x01, 0x01]);
+///
+/// assert!(DeliverSm::decode_body(&body).is_err());
+///
+/// let mut context = DecodeContext::new(DecodeOptions::new().with_lenient_tlvs(true));
+///
+/// let (deliver_sm, size) = DeliverSm::decode_with_context(&body, body.len(), &mut context).unwrap();
+///
+/// assert_eq!(size, body.len());
+/// assert_eq!(deliver_sm.tlvs().len(), 1);
+/// ```
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub trait DecodeWithContext: Sized {
+    /// Decode a value from a slice, with a specified length and context.
+    fn decode_with_context(
+        src: &[u8],
+        length: usize,
+        context: &mut DecodeContext,
+    ) -> Result<(Self, usize), DecodeError>;
+}
+
 #[doc(hidden)]
 pub trait DecodeExt: Decode {
     fn decode_move(src: &[u8], size: usize) -> Result<(Self, usize), DecodeError> {
@@ -480,6 +521,38 @@ pub trait DecodeWithKeyOptionalExt: DecodeWithKeyOptional {
 
 impl<T: DecodeWithKeyOptional> DecodeWithKeyOptionalExt for T {}
 
+#[doc(hidden)]
+#[cfg(any(test, feature = "alloc"))]
+pub trait DecodeWithContextExt: DecodeWithContext {
+    fn decode_with_context_move(
+        src: &[u8],
+        length: usize,
+        size: usize,
+        context: &mut DecodeContext,
+    ) -> Result<(Self, usize), DecodeError> {
+        Self::decode_with_context(&src[size..], length, context)
+            .map(|(this, size_)| (this, size + size_))
+    }
+
+    /// Decode a value from a slice, with a specified length and context.
+    ///
+    /// If the length is 0, return `None`.
+    fn length_checked_decode_with_context_move(
+        src: &[u8],
+        length: usize,
+        size: usize,
+        context: &mut DecodeContext,
+    ) -> Result<Option<(Self, usize)>, DecodeError> {
+        (length > 0)
+            .then_some(())
+            .map(|_| Self::decode_with_context_move(src, length, size, context))
+            .transpose()
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl<T: DecodeWithContext> DecodeWithContextExt for T {}
+
 #[cfg(any(test, feature = "alloc"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl<T: Decode> DecodeWithLength for alloc::vec::Vec<T> {
@@ -508,6 +581,38 @@ impl<T: Decode> DecodeWithLength for alloc::vec::Vec<T> {
     }
 }
 
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T: DecodeWithContext> DecodeWithContext for alloc::vec::Vec<T> {
+    fn decode_with_context(
+        src: &[u8],
+        length: usize,
+        context: &mut DecodeContext,
+    ) -> Result<(Self, usize), DecodeError> {
+        if length == 0 {
+            return Ok((alloc::vec::Vec::new(), 0));
+        }
+
+        if length > src.len() {
+            return Err(DecodeError::unexpected_eof());
+        }
+
+        let mut size = 0;
+
+        let mut vec = alloc::vec::Vec::new();
+
+        while size < length {
+            let (item, size_) = T::decode_with_context(&src[size..length], length - size, context)?;
+
+            size += size_;
+
+            vec.push(item);
+        }
+
+        Ok((vec, size))
+    }
+}
+
 // TODO: fuzz the decode functions
 #[cfg(test)]
 mod tests {
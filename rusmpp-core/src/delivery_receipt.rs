@@ -0,0 +1,379 @@
+//! `SMPP` delivery receipt.
+//!
+//! The format of the delivery receipt is `MC` specific. The typical format, as suggested by the `SMPP` v3.4 specification, is:
+//!
+//! `id:IIIIIIIIII sub:SSS dlvrd:DDD submit date:YYMMDDhhmm done date:YYMMDDhhmm stat:DDDDDDD err:E text: . . . . . . . . .`
+
+use alloc::string::{String, ToString};
+
+/// Final state of a message, as reported in the `stat` field of a [`DeliveryReceipt`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DeliveryReceiptStat {
+    /// `ENROUTE`
+    Enroute,
+    /// `DELIVRD`
+    Delivered,
+    /// `EXPIRED`
+    Expired,
+    /// `DELETED`
+    Deleted,
+    /// `UNDELIV`
+    Undeliverable,
+    /// `ACCEPTD`
+    Accepted,
+    /// `UNKNOWN`
+    Unknown,
+    /// `REJECTD`
+    Rejected,
+    /// `MC` specific state.
+    Other(String),
+}
+
+impl DeliveryReceiptStat {
+    /// Returns the receipt text of the state, e.g. `DELIVRD`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DeliveryReceiptStat::Enroute => "ENROUTE",
+            DeliveryReceiptStat::Delivered => "DELIVRD",
+            DeliveryReceiptStat::Expired => "EXPIRED",
+            DeliveryReceiptStat::Deleted => "DELETED",
+            DeliveryReceiptStat::Undeliverable => "UNDELIV",
+            DeliveryReceiptStat::Accepted => "ACCEPTD",
+            DeliveryReceiptStat::Unknown => "UNKNOWN",
+            DeliveryReceiptStat::Rejected => "REJECTD",
+            DeliveryReceiptStat::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for DeliveryReceiptStat {
+    /// Matches the known states case-insensitively.
+    fn from(value: &str) -> Self {
+        const KNOWN: [DeliveryReceiptStat; 8] = [
+            DeliveryReceiptStat::Enroute,
+            DeliveryReceiptStat::Delivered,
+            DeliveryReceiptStat::Expired,
+            DeliveryReceiptStat::Deleted,
+            DeliveryReceiptStat::Undeliverable,
+            DeliveryReceiptStat::Accepted,
+            DeliveryReceiptStat::Unknown,
+            DeliveryReceiptStat::Rejected,
+        ];
+
+        KNOWN
+            .into_iter()
+            .find(|stat| stat.as_str().eq_ignore_ascii_case(value))
+            .unwrap_or_else(|| DeliveryReceiptStat::Other(value.to_string()))
+    }
+}
+
+impl core::fmt::Display for DeliveryReceiptStat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A delivery receipt, usually carried in the `short_message` of a `deliver_sm`.
+///
+/// Only the `id` field is mandatory. The remaining fields are kept as they appear in the receipt.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DeliveryReceipt {
+    /// The message ID allocated to the message by the `MC` when originally submitted.
+    pub id: String,
+    /// Number of short messages originally submitted.
+    pub sub: Option<String>,
+    /// Number of short messages delivered.
+    pub dlvrd: Option<String>,
+    /// The time and date at which the short message was submitted. Format: `YYMMDDhhmm`.
+    pub submit_date: Option<String>,
+    /// The time and date at which the short message reached its final state. Format: `YYMMDDhhmm`.
+    pub done_date: Option<String>,
+    /// The final state of the message.
+    pub stat: Option<DeliveryReceiptStat>,
+    /// Network specific error code or an `SMSC` error code for the attempted delivery of the message.
+    pub err: Option<String>,
+    /// The first characters of the short message.
+    pub text: Option<String>,
+}
+
+/// Keys of a delivery receipt in their canonical order.
+const KEYS: [&str; 8] = [
+    "id:",
+    "sub:",
+    "dlvrd:",
+    "submit date:",
+    "done date:",
+    "stat:",
+    "err:",
+    "text:",
+];
+
+impl DeliveryReceipt {
+    /// Parses a delivery receipt from its text representation.
+    ///
+    /// Fields must appear in the canonical order. Missing optional fields are skipped.
+    ///
+    /// Keys are matched case-insensitively and irregular whitespace is tolerated.
+    pub fn parse(receipt: &str) -> Result<Self, Error> {
+        let mut found: [Option<(usize, usize)>; KEYS.len()] = [None; KEYS.len()];
+        let mut cursor = 0;
+
+        for (index, key) in KEYS.iter().enumerate() {
+            if let Some((start, end)) = find_key(receipt, cursor, key) {
+                found[index] = Some((start, end));
+                cursor = end;
+            }
+        }
+
+        let value = |index: usize| {
+            let (_, end) = found[index]?;
+
+            let next = found[index + 1..]
+                .iter()
+                .flatten()
+                .map(|(start, _)| *start)
+                .next()
+                .unwrap_or(receipt.len());
+
+            Some(receipt[end..next].trim().to_string())
+        };
+
+        let id = value(0).ok_or(Error::MissingId)?;
+
+        Ok(Self {
+            id,
+            sub: value(1),
+            dlvrd: value(2),
+            submit_date: value(3),
+            done_date: value(4),
+            stat: value(5).map(|stat| DeliveryReceiptStat::from(stat.as_str())),
+            err: value(6),
+            text: value(7),
+        })
+    }
+
+    /// Formats the delivery receipt in its canonical text representation.
+    ///
+    /// e.g. `id:0123456789 sub:001 dlvrd:001 submit date:2301011200 done date:2301011201 stat:DELIVRD err:000 text:Hello`.
+    pub fn to_receipt_string(&self) -> String {
+        let values = [
+            Some(self.id.as_str()),
+            self.sub.as_deref(),
+            self.dlvrd.as_deref(),
+            self.submit_date.as_deref(),
+            self.done_date.as_deref(),
+            self.stat.as_ref().map(DeliveryReceiptStat::as_str),
+            self.err.as_deref(),
+            self.text.as_deref(),
+        ];
+
+        let mut receipt = String::new();
+
+        for (key, value) in KEYS.iter().zip(values) {
+            let Some(value) = value else {
+                continue;
+            };
+
+            if !receipt.is_empty() {
+                receipt.push(' ');
+            }
+
+            receipt.push_str(key);
+            receipt.push_str(value);
+        }
+
+        receipt
+    }
+}
+
+/// Finds `key` in `receipt` starting at `from`.
+///
+/// The key must be at the start of the receipt or preceded by whitespace.
+/// Keys are matched case-insensitively and tolerate irregular whitespace, e.g. `SUBMIT  DATE :`.
+///
+/// Returns the start and end indices of the key.
+fn find_key(receipt: &str, from: usize, key: &str) -> Option<(usize, usize)> {
+    let mut previous = receipt[..from].chars().next_back();
+
+    for (position, char) in receipt[from..].char_indices() {
+        let start = from + position;
+
+        if previous.is_none_or(char::is_whitespace) {
+            if let Some(end) = match_key(receipt.as_bytes(), start, key.as_bytes()) {
+                return Some((start, end));
+            }
+        }
+
+        previous = Some(char);
+    }
+
+    None
+}
+
+/// Matches `key` in `receipt` at `start`.
+///
+/// A space in `key` matches one or more whitespace characters, a colon may be preceded by whitespace.
+///
+/// Returns the end index of the match.
+fn match_key(receipt: &[u8], start: usize, key: &[u8]) -> Option<usize> {
+    let skip_whitespace = |mut index: usize| {
+        while receipt.get(index).is_some_and(u8::is_ascii_whitespace) {
+            index += 1;
+        }
+
+        index
+    };
+
+    let mut index = start;
+
+    for &expected in key {
+        match expected {
+            b' ' => {
+                let next = skip_whitespace(index);
+
+                if next == index {
+                    return None;
+                }
+
+                index = next;
+            }
+            b':' => {
+                index = skip_whitespace(index);
+
+                if receipt.get(index) != Some(&b':') {
+                    return None;
+                }
+
+                index += 1;
+            }
+            _ => {
+                if !receipt.get(index)?.eq_ignore_ascii_case(&expected) {
+                    return None;
+                }
+
+                index += 1;
+            }
+        }
+    }
+
+    Some(index)
+}
+
+impl core::str::FromStr for DeliveryReceipt {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl core::fmt::Display for DeliveryReceipt {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_receipt_string())
+    }
+}
+
+/// An error that can occur when parsing a [`DeliveryReceipt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The mandatory `id` field is missing.
+    MissingId,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::MissingId => write!(f, "Missing delivery receipt id"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CANONICAL: &str = "id:0123456789 sub:001 dlvrd:001 submit date:2301011200 done date:2301011201 stat:DELIVRD err:000 text:Hello World";
+
+    #[test]
+    fn parse() {
+        let receipt = DeliveryReceipt::parse(CANONICAL).unwrap();
+
+        assert_eq!(
+            receipt,
+            DeliveryReceipt {
+                id: "0123456789".to_string(),
+                sub: Some("001".to_string()),
+                dlvrd: Some("001".to_string()),
+                submit_date: Some("2301011200".to_string()),
+                done_date: Some("2301011201".to_string()),
+                stat: Some(DeliveryReceiptStat::Delivered),
+                err: Some("000".to_string()),
+                text: Some("Hello World".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_missing_fields() {
+        let receipt = DeliveryReceipt::parse("id:42 stat:UNDELIV err:001").unwrap();
+
+        assert_eq!(receipt.id, "42");
+        assert_eq!(receipt.sub, None);
+        assert_eq!(receipt.stat, Some(DeliveryReceiptStat::Undeliverable));
+        assert_eq!(receipt.err, Some("001".to_string()));
+        assert_eq!(receipt.text, None);
+
+        assert_eq!(
+            DeliveryReceipt::parse("sub:001 stat:DELIVRD"),
+            Err(Error::MissingId)
+        );
+    }
+
+    #[test]
+    fn parse_format_round_trip() {
+        for receipt in [
+            CANONICAL,
+            "id:42 stat:UNDELIV err:001",
+            "id:abc sub:001 dlvrd:000 submit date:2301011200 done date:2301011300 stat:EXPIRED err:000 text:",
+            "id:1 stat:CUSTOM",
+        ] {
+            assert_eq!(
+                DeliveryReceipt::parse(receipt).unwrap().to_receipt_string(),
+                receipt
+            );
+        }
+    }
+
+    #[test]
+    fn parse_case_insensitive() {
+        let receipt = DeliveryReceipt::parse(
+            "ID:0123456789 SUB:001 DLVRD:001 SUBMIT DATE:2301011200 DONE DATE:2301011201 STAT:delivrd ERR:000 TEXT:Hello World",
+        )
+        .unwrap();
+
+        assert_eq!(receipt, DeliveryReceipt::parse(CANONICAL).unwrap());
+        assert_eq!(receipt.to_receipt_string(), CANONICAL);
+    }
+
+    #[test]
+    fn parse_irregular_whitespace() {
+        let receipt = DeliveryReceipt::parse(
+            "  id: 0123456789   sub:001  dlvrd:001 Submit  Date:2301011200   done\tdate :2301011201 stat:  DELIVRD   err:000 text:Hello World  ",
+        )
+        .unwrap();
+
+        assert_eq!(receipt, DeliveryReceipt::parse(CANONICAL).unwrap());
+    }
+
+    #[test]
+    fn parse_key_inside_value() {
+        // `unsub:` must not be mistaken for `sub:`
+        let receipt = DeliveryReceipt::parse("id:unsub:1 sub:002").unwrap();
+
+        assert_eq!(receipt.id, "unsub:1");
+        assert_eq!(receipt.sub, Some("002".to_string()));
+    }
+}
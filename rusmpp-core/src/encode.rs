@@ -87,6 +87,29 @@ pub trait Encode: Length {
     fn encode(&self, dst: &mut [u8]) -> usize;
 }
 
+/// Access to the encodable fields of a value by name.
+///
+/// Implemented by the `Rusmpp` derive for structs with named fields. Field names are the `snake_case` names of the struct fields.
+pub trait EncodeFields {
+    /// Returns the field with the given `name`, if any.
+    fn field(&self, name: &str) -> Option<&dyn Encode>;
+
+    /// Returns the names of the mandatory fields in encoding order.
+    ///
+    /// Fields holding `TLV`s, e.g. the `tlvs` list or a single `Option<Tlv>`, are optional and not included.
+    fn mandatory_fields(&self) -> &'static [&'static str];
+
+    /// Encodes the [`mandatory_fields`](EncodeFields::mandatory_fields) into `dst`, in encoding order.
+    ///
+    /// The `TLV`s of a `PDU` can then be encoded separately, e.g. streamed into a writer.
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        self.mandatory_fields()
+            .iter()
+            .filter_map(|name| self.field(name))
+            .fold(0, |size, field| size + field.encode(&mut dst[size..]))
+    }
+}
+
 #[doc(hidden)]
 pub trait EncodeExt: Encode {
     fn encode_move(&self, dst: &mut [u8], size: usize) -> usize {
@@ -149,6 +172,79 @@ impl<T: Encode, const N: usize> Encode for heapless::vec::Vec<T, N> {
     }
 }
 
+/// The buffer is too small to hold the encoded value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    /// The length of the encoded value.
+    pub required: usize,
+    /// The length of the buffer.
+    pub available: usize,
+}
+
+impl core::fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Buffer too small. required: {}, available: {}",
+            self.required, self.available
+        )
+    }
+}
+
+impl core::error::Error for BufferTooSmall {}
+
+/// Encodes a command, prefixed by its `command_length`, into a new array.
+pub(crate) fn encode_command_to_array<const M: usize>(
+    command: &impl Encode,
+) -> Result<([u8; M], usize), BufferTooSmall> {
+    let command_length = 4 + command.length();
+
+    if command_length > M {
+        return Err(BufferTooSmall {
+            required: command_length,
+            available: M,
+        });
+    }
+
+    let mut buf = [0; M];
+
+    buf[..4].copy_from_slice(&(command_length as u32).to_be_bytes());
+
+    let size = 4 + command.encode(&mut buf[4..command_length]);
+
+    Ok((buf, size))
+}
+
+/// Length of the stack buffer used by [`write_encoded`].
+#[cfg(feature = "std")]
+const WRITE_ENCODED_STACK_LENGTH: usize = 256;
+
+/// Encodes a value into an intermediate buffer and writes it to `w`.
+///
+/// Used for small values while streaming bigger ones directly into `w`.
+/// Values up to [`WRITE_ENCODED_STACK_LENGTH`] bytes are encoded on the stack without allocating.
+#[cfg(feature = "std")]
+pub(crate) fn write_encoded<T: Encode, W: std::io::Write>(
+    value: &T,
+    w: &mut W,
+) -> std::io::Result<()> {
+    let length = value.length();
+
+    if length <= WRITE_ENCODED_STACK_LENGTH {
+        let mut buf = [0; WRITE_ENCODED_STACK_LENGTH];
+
+        let size = value.encode(&mut buf[..length]);
+
+        return w.write_all(&buf[..size]);
+    }
+
+    let mut buf = alloc::vec![0; length];
+
+    let size = value.encode(&mut buf);
+
+    w.write_all(&buf[..size])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,4 +110,8 @@ pub enum SmppField {
     validity_period,
     value,
     value_length,
+    /// A field of a custom `PDU` defined outside of this library.
+    ///
+    /// See [`Rusmpp`](crate::Rusmpp).
+    Other(&'static str),
 }
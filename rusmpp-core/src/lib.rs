@@ -6,6 +6,7 @@
 //! ## Features
 //!
 //! - `alloc`:  Enables the `alloc` crate.
+//! - `std`: Enables the `std` crate and encoding into [`Write`](https://doc.rust-lang.org/std/io/trait.Write.html)rs. Enables the `alloc` feature.
 //! - `verbose`: Enables verbose error reports. Enables the `alloc` feature.
 //! - `arbitrary`: Implements [`Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html) trait for all SMPP types.
 //! - `serde`: Implements [`Serialize`](https://docs.rs/serde/latest/serde/trait.Serialize.html) trait for all SMPP types.
@@ -13,13 +14,15 @@
 //! - `tokio-codec`: Implements [`tokio-util`](https://docs.rs/tokio-util/latest/tokio_util/index.html) [`Encoder`](https://docs.rs/tokio-util/latest/tokio_util/codec/trait.Encoder.html) and [`Decoder`](https://docs.rs/tokio-util/latest/tokio_util/codec/trait.Decoder.html) traits.
 //! - `framez`: Implements [`framez`](https://docs.rs/framez/latest/framez/index.html) [`Encoder`](https://docs.rs/framez/latest/framez/encode/trait.Encoder.html) and [`Decoder`](https://docs.rs/framez/latest/framez/decode/trait.Decoder.html) traits.
 //! - `tracing`: Enables logging using [`tracing`](https://docs.rs/tracing/latest/tracing/).
+//! - `bytes`: Enables encoding commands into [`Bytes`](https://docs.rs/bytes/latest/bytes/struct.Bytes.html). Enables the `alloc` feature.
+//! - `test-server`: Enables the in-memory [`MockSmsc`](crate::mock::MockSmsc) for testing ESMEs. Enables the `alloc` feature.
 //! - `pretty-hex-fmt`: Logs byte slices like `[0x00, 0x00, 0x00, 0x6F]` instead of `[00, 00, 00, 6F]`, if `tracing` feature is enabled.
 //! - `char-fmt`: Logs byte slices as characters, if `tracing` feature is enabled.
 
 #[cfg(any(test, feature = "alloc"))]
 extern crate alloc;
 
-#[cfg(any(test, feature = "arbitrary", feature = "tokio-codec"))]
+#[cfg(any(test, feature = "std", feature = "arbitrary", feature = "tokio-codec"))]
 extern crate std;
 
 pub mod pdus;
@@ -27,7 +30,9 @@ pub mod pdus;
 pub mod values;
 
 mod command_id;
-pub use command_id::CommandId;
+pub use command_id::{
+    CommandId, command_name, is_response, matching_request_id, matching_response_id,
+};
 
 mod command_status;
 pub use command_status::CommandStatus;
@@ -38,14 +43,50 @@ pub mod session;
 
 pub mod fields;
 
+pub use rusmpp_macros::Rusmpp;
+
 pub mod decode;
 
 pub mod encode;
 
+pub mod validate;
+
 pub mod types;
 
 pub mod tlvs;
 
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod delivery_receipt;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod message_id;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod reassembly;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod stats;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod storage;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod text;
+
+#[cfg(any(test, feature = "pcap"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "pcap")))]
+pub mod pcap;
+
+#[cfg(any(test, feature = "test-server"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-server")))]
+pub mod mock;
+
 #[cfg(test)]
 pub(crate) mod tests;
 
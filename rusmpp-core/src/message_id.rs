@@ -0,0 +1,177 @@
+//! `SMPP` message ID normalization.
+//!
+//! The `message_id` returned in a `submit_sm_resp` must be matched against the `id` of the delivery receipts.
+//! `MC`s are inconsistent about its format: the same message may be reported as `1234` in one `PDU` and as `4D2` or `0x4D2` in another.
+//!
+//! Normalizing both sides to a canonical form makes them comparable.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+/// Base of a numeric message ID.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MessageIdBase {
+    /// e.g. `1234`.
+    #[default]
+    Decimal,
+    /// e.g. `4D2`.
+    Hexadecimal,
+}
+
+/// Normalizes message IDs from their `expected` base to a `canonical` base.
+///
+/// - IDs prefixed with `0x` are always read as hexadecimal.
+/// - Unprefixed IDs are read in the `expected` base.
+/// - Leading zeros are dropped, hexadecimal IDs are formatted in uppercase without a prefix.
+/// - Other IDs, e.g. `UUID`s or unprefixed hexadecimal IDs when expecting [`MessageIdBase::Decimal`], are only trimmed.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::message_id::{MessageIdBase, MessageIdFormat};
+/// let format = MessageIdFormat::new()
+///     .with_expected(MessageIdBase::Hexadecimal)
+///     .with_canonical(MessageIdBase::Hexadecimal);
+///
+/// assert_eq!(format.normalize("04d2"), "4D2");
+/// assert_eq!(format.normalize("0x4D2"), "4D2");
+/// assert_eq!(format.normalize("1234"), "1234");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MessageIdFormat {
+    /// Base of unprefixed IDs.
+    pub expected: MessageIdBase,
+    /// Base of the normalized IDs.
+    pub canonical: MessageIdBase,
+}
+
+impl MessageIdFormat {
+    /// Creates a new [`MessageIdFormat`] reading and writing decimal IDs.
+    pub const fn new() -> Self {
+        Self {
+            expected: MessageIdBase::Decimal,
+            canonical: MessageIdBase::Decimal,
+        }
+    }
+
+    pub const fn with_expected(mut self, expected: MessageIdBase) -> Self {
+        self.expected = expected;
+        self
+    }
+
+    pub const fn with_canonical(mut self, canonical: MessageIdBase) -> Self {
+        self.canonical = canonical;
+        self
+    }
+
+    /// Normalizes `id` to the canonical base.
+    pub fn normalize(&self, id: &str) -> String {
+        let id = id.trim();
+
+        match self.parse(id) {
+            Some(value) => match self.canonical {
+                MessageIdBase::Decimal => value.to_string(),
+                MessageIdBase::Hexadecimal => format!("{value:X}"),
+            },
+            None => id.to_string(),
+        }
+    }
+
+    /// Parses the numeric value of `id`, if any.
+    fn parse(&self, id: &str) -> Option<u128> {
+        if let Some(hex) = id.strip_prefix("0x").or_else(|| id.strip_prefix("0X")) {
+            return parse_digits(hex, 16);
+        }
+
+        match self.expected {
+            MessageIdBase::Decimal => parse_digits(id, 10),
+            MessageIdBase::Hexadecimal => parse_digits(id, 16),
+        }
+    }
+}
+
+/// Parses `digits` in `radix`, rejecting signs and empty input which [`u128::from_str_radix`] would accept.
+fn parse_digits(digits: &str, radix: u32) -> Option<u128> {
+    if digits.is_empty() || !digits.chars().all(|char| char.is_digit(radix)) {
+        return None;
+    }
+
+    u128::from_str_radix(digits, radix).ok()
+}
+
+/// Normalizes `id` to its canonical decimal form.
+///
+/// See [`MessageIdFormat`] for other bases.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::message_id::normalize_message_id;
+/// assert_eq!(normalize_message_id("1234"), "1234");
+/// assert_eq!(normalize_message_id("0x4D2"), "1234");
+/// assert_eq!(normalize_message_id("0001234"), "1234");
+/// ```
+pub fn normalize_message_id(id: &str) -> String {
+    MessageIdFormat::new().normalize(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_and_hex_agree() {
+        assert_eq!(normalize_message_id("1234"), normalize_message_id("0x4D2"));
+        assert_eq!(normalize_message_id("0x4d2"), "1234");
+        assert_eq!(normalize_message_id(" 1234 "), "1234");
+
+        // Hexadecimal digits without prefix are not numbers
+        assert_eq!(normalize_message_id("4D2"), "4D2");
+    }
+
+    #[test]
+    fn decimal_and_alphanumeric_differ() {
+        // 0xABC is 2748
+        assert_ne!(normalize_message_id("ABC"), normalize_message_id("2748"));
+        assert_eq!(normalize_message_id("ABC"), "ABC");
+        assert_eq!(normalize_message_id("0xABC"), "2748");
+    }
+
+    #[test]
+    fn hexadecimal() {
+        let format = MessageIdFormat::new()
+            .with_expected(MessageIdBase::Hexadecimal)
+            .with_canonical(MessageIdBase::Hexadecimal);
+
+        assert_eq!(format.normalize("4d2"), "4D2");
+        assert_eq!(format.normalize("0x0004D2"), "4D2");
+
+        // Unprefixed IDs are read in the expected base
+        assert_eq!(format.normalize("1234"), "1234");
+
+        let format = MessageIdFormat::new().with_expected(MessageIdBase::Hexadecimal);
+
+        assert_eq!(format.normalize("1234"), "4660");
+    }
+
+    #[test]
+    fn non_numeric() {
+        for id in [
+            "",
+            "0x",
+            "+1234",
+            "id-42",
+            "0xZZ",
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+        ] {
+            assert_eq!(normalize_message_id(id), id);
+        }
+
+        // Too big for a number
+        let id = "9".repeat(64);
+
+        assert_eq!(normalize_message_id(&id), id);
+    }
+}
@@ -0,0 +1,1079 @@
+//! In-memory `MC` for testing `ESME`s.
+//!
+//! [`MockSmsc`] decodes incoming commands and answers them like a real `MC` would,
+//! without any networking. Downstream crates can wire it to their transport or feed it bytes directly.
+
+use core::time::Duration;
+
+use alloc::{
+    boxed::Box,
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    CommandId, CommandStatus,
+    command::owned::{Command, decode_many, encode_many},
+    delivery_receipt::{DeliveryReceipt, DeliveryReceiptStat},
+    pdus::owned::{
+        BindReceiverResp, BindTransceiverResp, BindTransmitterResp, DeliverSm, Pdu, SubmitSm,
+        SubmitSmResp,
+    },
+    session::SessionState,
+    tlvs::owned::MessageDeliveryRequestTlvValue,
+    types::owned::{COctetString, OctetString},
+    values::{EsmClass, InterfaceVersion, MCDeliveryReceipt, MessageState, MessageType},
+};
+
+/// Generates the `message_id`s of the `submit_sm_resp`s sent by a [`MockSmsc`].
+///
+/// Implemented for closures returning a [`String`].
+pub trait MessageIdGenerator: Send {
+    /// Returns the next `message_id`.
+    ///
+    /// The `message_id` must not exceed 64 characters,
+    /// the [`MockSmsc`] answers the `submit_sm` with `ESME_RSYSERR` and no body otherwise.
+    fn next_message_id(&mut self) -> String;
+}
+
+impl<F> MessageIdGenerator for F
+where
+    F: FnMut() -> String + Send,
+{
+    fn next_message_id(&mut self) -> String {
+        self()
+    }
+}
+
+/// Decimal `message_id`s counting up from a start value: `1`, `2`, ...
+#[derive(Debug, Clone)]
+pub struct SequentialMessageId {
+    next: u64,
+}
+
+impl SequentialMessageId {
+    pub const fn new(start: u64) -> Self {
+        Self { next: start }
+    }
+}
+
+impl Default for SequentialMessageId {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl MessageIdGenerator for SequentialMessageId {
+    fn next_message_id(&mut self) -> String {
+        let id = self.next.to_string();
+
+        self.next = self.next.wrapping_add(1);
+
+        id
+    }
+}
+
+/// Uppercase hexadecimal `message_id`s counting up from a start value, e.g. `9`, `A`, `B`, ...
+#[derive(Debug, Clone)]
+pub struct HexMessageId {
+    next: u64,
+}
+
+impl HexMessageId {
+    pub const fn new(start: u64) -> Self {
+        Self { next: start }
+    }
+}
+
+impl Default for HexMessageId {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl MessageIdGenerator for HexMessageId {
+    fn next_message_id(&mut self) -> String {
+        let id = format!("{:X}", self.next);
+
+        self.next = self.next.wrapping_add(1);
+
+        id
+    }
+}
+
+/// Random-looking `UUID` v4 `message_id`s, e.g. `0b7e9d4c-3f1a-4c2e-9a5d-6e8f7a1b2c3d`.
+///
+/// The ids are pseudo-random: the same `seed` always yields the same sequence.
+#[derive(Debug, Clone)]
+pub struct UuidMessageId {
+    state: u64,
+}
+
+impl UuidMessageId {
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// `SplitMix64`.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+}
+
+impl Default for UuidMessageId {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl MessageIdGenerator for UuidMessageId {
+    fn next_message_id(&mut self) -> String {
+        let high = self.next_u64();
+        let low = self.next_u64();
+
+        // Version 4, variant 1
+        let high = (high & !0xF000) | 0x4000;
+        let low = (low & !(0b11 << 62)) | (0b10 << 62);
+
+        format!(
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            high >> 32,
+            (high >> 16) & 0xFFFF,
+            high & 0xFFFF,
+            low >> 48,
+            low & 0xFFFF_FFFF_FFFF
+        )
+    }
+}
+
+/// In-memory `MC` answering binds, `submit_sm`s, `enquire_link`s and `unbind`s.
+///
+/// - `bind_*` are accepted with any credentials and answered with the matching `bind_*_resp`.
+/// - `submit_sm`s are answered with a `submit_sm_resp` carrying a `message_id` from the [`MessageIdGenerator`],
+///   sequential by default: `1`, `2`, ...
+/// - `enquire_link` and `unbind` are answered with their responses.
+/// - Optionally, `submit_sm`s exceeding a rate are answered with `ESME_RTHROTTLED`, see [`MockSmsc::with_throttling`].
+/// - Optionally, `submit_sm`s requesting a delivery receipt are followed by a `deliver_sm` delivery receipt,
+///   see [`MockSmsc::with_delivery_receipts`].
+/// - Commands that are not allowed in the [`SessionState`] are answered with `ESME_RINVBNDSTS`, or `ESME_RALYBND` for binds.
+/// - Other requests are answered with a `generic_nack` with `ESME_RINVCMDID`. Responses are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{
+/// #     CommandStatus,
+/// #     command::owned::Command,
+/// #     mock::MockSmsc,
+/// #     pdus::owned::{BindTransmitter, Pdu, SubmitSm},
+/// # };
+/// let mut smsc = MockSmsc::new();
+///
+/// let response = smsc
+///     .handle(&Command::new(CommandStatus::EsmeRok, 1, BindTransmitter::default()))
+///     .unwrap();
+///
+/// assert_eq!(response.status(), CommandStatus::EsmeRok);
+///
+/// let response = smsc
+///     .handle(&Command::new(CommandStatus::EsmeRok, 2, SubmitSm::default()))
+///     .unwrap();
+///
+/// assert_eq!(response.sequence_number(), 2);
+/// assert_eq!(response.message_id(), Some("1"));
+/// ```
+pub struct MockSmsc {
+    system_id: COctetString<1, 16>,
+    state: SessionState,
+    message_ids: Box<dyn MessageIdGenerator>,
+    /// Final state and delay of the delivery receipts, if enabled.
+    receipts: Option<(DeliveryReceiptStat, Duration)>,
+    /// Maximum number of `submit_sm`s per window, if enabled.
+    throttling: Option<(usize, Duration)>,
+    /// Times of the accepted `submit_sm`s within the current window.
+    submits: VecDeque<Duration>,
+    /// Time elapsed since the creation, see [`MockSmsc::advance`].
+    clock: Duration,
+    /// Scheduled commands with their due time, in scheduling order.
+    scheduled: Vec<(Duration, Command)>,
+    next_sequence_number: u32,
+    /// Received bytes not forming a complete command yet.
+    buffer: Vec<u8>,
+}
+
+impl MockSmsc {
+    /// Creates a new [`MockSmsc`] with an open session and the `system_id` `MockSmsc`.
+    pub fn new() -> Self {
+        Self {
+            system_id: "MockSmsc".parse().expect("Must be a valid system_id"),
+            state: SessionState::Open,
+            message_ids: Box::new(SequentialMessageId::default()),
+            receipts: None,
+            throttling: None,
+            submits: VecDeque::new(),
+            clock: Duration::ZERO,
+            scheduled: Vec::new(),
+            next_sequence_number: 1,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Sets the generator of the `message_id`s sent in the `submit_sm_resp`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     CommandStatus,
+    /// #     command::owned::Command,
+    /// #     mock::{HexMessageId, MockSmsc},
+    /// #     pdus::owned::{BindTransmitter, SubmitSm},
+    /// # };
+    /// let mut smsc = MockSmsc::new().with_message_id_generator(HexMessageId::new(0xFF));
+    ///
+    /// smsc.handle(&Command::new(CommandStatus::EsmeRok, 1, BindTransmitter::default()));
+    ///
+    /// let response = smsc
+    ///     .handle(&Command::new(CommandStatus::EsmeRok, 2, SubmitSm::default()))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(response.message_id(), Some("FF"));
+    /// ```
+    pub fn with_message_id_generator(
+        mut self,
+        generator: impl MessageIdGenerator + 'static,
+    ) -> Self {
+        self.message_ids = Box::new(generator);
+        self
+    }
+
+    /// Sets the `system_id` sent in the `bind_*_resp`s.
+    pub fn with_system_id(mut self, system_id: COctetString<1, 16>) -> Self {
+        self.system_id = system_id;
+        self
+    }
+
+    /// Enables delivery receipts with the final state `stat`, sent `delay` after the `submit_sm`.
+    ///
+    /// Receipts are only sent for `submit_sm`s whose `registered_delivery` requests them for `stat`,
+    /// and only while the session is bound as a receiver or transceiver. A [`Duration::ZERO`] delay sends them
+    /// right after the `submit_sm_resp`, otherwise they are due once the clock is [`advance`](MockSmsc::advance)d.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use core::time::Duration;
+    /// # use rusmpp_core::{
+    /// #     CommandId, CommandStatus,
+    /// #     command::owned::Command,
+    /// #     delivery_receipt::DeliveryReceiptStat,
+    /// #     mock::MockSmsc,
+    /// #     pdus::owned::{BindTransceiver, SubmitSm},
+    /// #     values::RegisteredDelivery,
+    /// # };
+    /// let mut smsc =
+    ///     MockSmsc::new().with_delivery_receipts(DeliveryReceiptStat::Delivered, Duration::from_secs(5));
+    ///
+    /// smsc.handle(&Command::new(CommandStatus::EsmeRok, 1, BindTransceiver::default()));
+    ///
+    /// let submit_sm = SubmitSm::builder()
+    ///     .registered_delivery(RegisteredDelivery::request_all())
+    ///     .build();
+    ///
+    /// smsc.handle(&Command::new(CommandStatus::EsmeRok, 2, submit_sm));
+    ///
+    /// assert!(smsc.outgoing().is_empty());
+    ///
+    /// smsc.advance(Duration::from_secs(5));
+    ///
+    /// assert_eq!(smsc.outgoing()[0].id(), CommandId::DeliverSm);
+    /// ```
+    pub fn with_delivery_receipts(mut self, stat: DeliveryReceiptStat, delay: Duration) -> Self {
+        self.receipts = Some((stat, delay));
+        self
+    }
+
+    /// Answers `submit_sm`s with `ESME_RTHROTTLED` once `max_submits` were accepted within the last `window`.
+    ///
+    /// Throttled `submit_sm`s do not count towards the limit. The window slides with the clock, see [`MockSmsc::advance`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use core::time::Duration;
+    /// # use rusmpp_core::{
+    /// #     CommandStatus,
+    /// #     command::owned::Command,
+    /// #     mock::MockSmsc,
+    /// #     pdus::owned::{BindTransmitter, SubmitSm},
+    /// # };
+    /// let mut smsc = MockSmsc::new().with_throttling(1, Duration::from_secs(1));
+    ///
+    /// smsc.handle(&Command::new(CommandStatus::EsmeRok, 1, BindTransmitter::default()));
+    ///
+    /// let mut submit = |sequence_number| {
+    ///     smsc.handle(&Command::new(CommandStatus::EsmeRok, sequence_number, SubmitSm::default()))
+    ///         .unwrap()
+    ///         .status()
+    /// };
+    ///
+    /// assert_eq!(submit(2), CommandStatus::EsmeRok);
+    /// assert_eq!(submit(3), CommandStatus::EsmeRthrottled);
+    /// ```
+    pub fn with_throttling(mut self, max_submits: usize, window: Duration) -> Self {
+        self.throttling = Some((max_submits, window));
+        self
+    }
+
+    /// Advances the clock of the scheduled commands and the throttling window by `elapsed`.
+    pub fn advance(&mut self, elapsed: Duration) {
+        self.clock += elapsed;
+    }
+
+    /// Takes the commands initiated by the `MC` which are due, e.g. delivery receipts.
+    ///
+    /// Commands that can not be sent in the current [`SessionState`] are kept until the session allows them.
+    pub fn outgoing(&mut self) -> Vec<Command> {
+        let (due, scheduled) =
+            core::mem::take(&mut self.scheduled)
+                .into_iter()
+                .partition(|(due, command)| {
+                    *due <= self.clock && self.state.can_send_as_mc(command.id())
+                });
+
+        self.scheduled = scheduled;
+
+        due.into_iter().map(|(_, command)| command).collect()
+    }
+
+    /// Returns the state of the session with the `ESME`.
+    pub const fn state(&self) -> SessionState {
+        self.state
+    }
+
+    /// Handles a command received from the `ESME`.
+    ///
+    /// Returns the response to send back, if any.
+    pub fn handle(&mut self, command: &Command) -> Option<Command> {
+        let id = command.id();
+        let sequence_number = command.sequence_number();
+
+        if id.is_response() {
+            return None;
+        }
+
+        let status = if self.state.can_receive_as_mc(id) {
+            CommandStatus::EsmeRok
+        } else if self.state.is_bound() && is_bind(id) {
+            CommandStatus::EsmeRalybnd
+        } else {
+            CommandStatus::EsmeRinvbndsts
+        };
+
+        let status =
+            if status == CommandStatus::EsmeRok && id == CommandId::SubmitSm && self.throttle() {
+                CommandStatus::EsmeRthrottled
+            } else {
+                status
+            };
+
+        let pdu: Pdu = match id {
+            CommandId::BindTransmitter => {
+                BindTransmitterResp::new(self.system_id.clone(), Some(InterfaceVersion::Smpp5_0))
+                    .into()
+            }
+            CommandId::BindReceiver => {
+                BindReceiverResp::new(self.system_id.clone(), Some(InterfaceVersion::Smpp5_0))
+                    .into()
+            }
+            CommandId::BindTransceiver => {
+                BindTransceiverResp::new(self.system_id.clone(), Some(InterfaceVersion::Smpp5_0))
+                    .into()
+            }
+            CommandId::SubmitSm => {
+                let mut submit_sm_resp = SubmitSmResp::default();
+
+                if status == CommandStatus::EsmeRok {
+                    let message_id = self.message_ids.next_message_id();
+
+                    if submit_sm_resp.set_message_id(&message_id).is_err() {
+                        return Some(Command::without_body(
+                            CommandId::SubmitSmResp,
+                            CommandStatus::EsmeRsyserr,
+                            sequence_number,
+                        ));
+                    }
+
+                    if let Some(Pdu::SubmitSm(submit_sm)) = command.pdu() {
+                        self.schedule_receipt(submit_sm, submit_sm_resp.message_id().clone());
+                    }
+                }
+
+                submit_sm_resp.into()
+            }
+            CommandId::EnquireLink => Pdu::EnquireLinkResp,
+            CommandId::Unbind => Pdu::UnbindResp,
+            _ => {
+                return Some(Command::new(
+                    CommandStatus::EsmeRinvcmdid,
+                    sequence_number,
+                    Pdu::GenericNack,
+                ));
+            }
+        };
+
+        if status == CommandStatus::EsmeRok {
+            self.transition(id);
+        }
+
+        Some(Command::new(status, sequence_number, pdu))
+    }
+
+    /// Handles the bytes received from the `ESME`.
+    ///
+    /// `bytes` may contain any number of commands, including a partial command which is completed by the next call.
+    /// Returns the encoded responses, followed by the due [`outgoing`](MockSmsc::outgoing) commands.
+    ///
+    /// A complete command that fails to decode is dropped and answered with a `generic_nack` with `ESME_RINVCMDLEN`.
+    pub fn receive(&mut self, bytes: &[u8]) -> Vec<u8> {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut responses = Vec::new();
+
+        loop {
+            let (commands, rest) = decode_many(&self.buffer);
+
+            let consumed = self.buffer.len() - rest.len();
+
+            self.buffer.drain(..consumed);
+
+            responses.extend(commands.iter().filter_map(|command| self.handle(command)));
+
+            // `decode_many` stops at incomplete and malformed commands
+            let Some(header) = crate::command::peek_header(&self.buffer) else {
+                break;
+            };
+
+            let command_length = (header.command_length as usize).max(16);
+
+            if self.buffer.len() < command_length {
+                break;
+            }
+
+            self.buffer.drain(..command_length);
+
+            responses.push(Command::new(
+                CommandStatus::EsmeRinvcmdlen,
+                header.sequence_number,
+                Pdu::GenericNack,
+            ));
+        }
+
+        responses.extend(self.outgoing());
+
+        let mut buf = Vec::new();
+
+        encode_many(&responses, &mut buf);
+
+        buf
+    }
+
+    /// Checks if a `submit_sm` exceeds the throttling limit, counting it otherwise.
+    fn throttle(&mut self) -> bool {
+        let Some((max_submits, window)) = self.throttling else {
+            return false;
+        };
+
+        while let Some(submitted) = self.submits.front() {
+            if *submitted + window > self.clock {
+                break;
+            }
+
+            self.submits.pop_front();
+        }
+
+        if self.submits.len() >= max_submits {
+            return true;
+        }
+
+        self.submits.push_back(self.clock);
+
+        false
+    }
+
+    fn schedule_receipt(&mut self, submit_sm: &SubmitSm, message_id: COctetString<1, 65>) {
+        let Some((stat, delay)) = &self.receipts else {
+            return;
+        };
+
+        let delivered = *stat == DeliveryReceiptStat::Delivered;
+
+        let requested = match submit_sm.registered_delivery.mc_delivery_receipt() {
+            MCDeliveryReceipt::NoMcDeliveryReceiptRequested => false,
+            MCDeliveryReceipt::McDeliveryReceiptRequestedWhereFinalDeliveryOutcomeIsFailure => {
+                !delivered
+            }
+            MCDeliveryReceipt::McDeliveryReceiptRequestedWhereFinalDeliveryOutcomeIsSuccess => {
+                delivered
+            }
+            _ => true,
+        };
+
+        if !requested {
+            return;
+        }
+
+        let short_message = submit_sm.short_message().bytes();
+
+        let receipt = DeliveryReceipt {
+            id: message_id.to_string(),
+            sub: Some("001".into()),
+            dlvrd: Some(if delivered { "001" } else { "000" }.into()),
+            stat: Some(stat.clone()),
+            err: Some("000".into()),
+            text: Some(
+                String::from_utf8_lossy(&short_message[..short_message.len().min(20)]).into(),
+            ),
+            ..Default::default()
+        };
+
+        let message_state = match stat {
+            DeliveryReceiptStat::Enroute => MessageState::Enroute,
+            DeliveryReceiptStat::Delivered => MessageState::Delivered,
+            DeliveryReceiptStat::Expired => MessageState::Expired,
+            DeliveryReceiptStat::Deleted => MessageState::Deleted,
+            DeliveryReceiptStat::Undeliverable => MessageState::Undeliverable,
+            DeliveryReceiptStat::Accepted => MessageState::Accepted,
+            DeliveryReceiptStat::Rejected => MessageState::Rejected,
+            DeliveryReceiptStat::Unknown | DeliveryReceiptStat::Other(_) => MessageState::Unknown,
+        };
+
+        let deliver_sm = DeliverSm::builder()
+            .source_addr_ton(submit_sm.dest_addr_ton)
+            .source_addr_npi(submit_sm.dest_addr_npi)
+            .source_addr(submit_sm.destination_addr.clone())
+            .dest_addr_ton(submit_sm.source_addr_ton)
+            .dest_addr_npi(submit_sm.source_addr_npi)
+            .destination_addr(submit_sm.source_addr.clone())
+            .esm_class(EsmClass {
+                message_type: MessageType::ShortMessageContainsMCDeliveryReceipt,
+                ..Default::default()
+            })
+            .short_message(
+                OctetString::new(receipt.to_receipt_string().into_bytes())
+                    .expect("Receipt must not exceed 255 bytes"),
+            )
+            .push_tlv(MessageDeliveryRequestTlvValue::ReceiptedMessageId(
+                message_id,
+            ))
+            .push_tlv(MessageDeliveryRequestTlvValue::MessageState(message_state))
+            .build();
+
+        let sequence_number = self.next_sequence_number;
+
+        self.next_sequence_number = self.next_sequence_number.wrapping_add(1).max(1);
+
+        self.scheduled.push((
+            self.clock + *delay,
+            Command::new(CommandStatus::EsmeRok, sequence_number, deliver_sm),
+        ));
+    }
+
+    fn transition(&mut self, id: CommandId) {
+        self.state = match id {
+            CommandId::BindTransmitter => SessionState::BoundTx,
+            CommandId::BindReceiver => SessionState::BoundRx,
+            CommandId::BindTransceiver => SessionState::BoundTrx,
+            CommandId::Unbind => SessionState::Unbound,
+            _ => self.state,
+        };
+    }
+}
+
+impl core::fmt::Debug for MockSmsc {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MockSmsc")
+            .field("system_id", &self.system_id)
+            .field("state", &self.state)
+            .field("clock", &self.clock)
+            .field("scheduled", &self.scheduled)
+            .field("buffer", &self.buffer)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for MockSmsc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const fn is_bind(id: CommandId) -> bool {
+    matches!(
+        id,
+        CommandId::BindTransmitter | CommandId::BindReceiver | CommandId::BindTransceiver
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        command::owned::decode_many,
+        pdus::owned::{BindTransceiver, BindTransmitter, TlvContainer},
+        values::RegisteredDelivery,
+    };
+
+    use super::*;
+
+    fn encode(commands: &[Command]) -> Vec<u8> {
+        let mut buf = Vec::new();
+
+        encode_many(commands, &mut buf);
+
+        buf
+    }
+
+    #[test]
+    fn bind_and_submit() {
+        let mut smsc = MockSmsc::new();
+
+        let submit_sm = || {
+            SubmitSm::builder()
+                .destination_addr("491701234567".parse().unwrap())
+                .short_message(OctetString::new(b"Hi").unwrap())
+                .build()
+        };
+
+        // Not bound yet
+        let response = smsc
+            .handle(&Command::new(CommandStatus::EsmeRok, 1, submit_sm()))
+            .unwrap();
+
+        assert_eq!(response.status(), CommandStatus::EsmeRinvbndsts);
+
+        let bytes = encode(&[
+            Command::new(
+                CommandStatus::EsmeRok,
+                2,
+                BindTransceiver::builder()
+                    .system_id("esme".parse().unwrap())
+                    .password("secret".parse().unwrap())
+                    .build(),
+            ),
+            Command::new(CommandStatus::EsmeRok, 3, submit_sm()),
+            Command::new(CommandStatus::EsmeRok, 4, submit_sm()),
+            Command::new(CommandStatus::EsmeRok, 5, Pdu::EnquireLink),
+        ]);
+
+        // Split in the middle of a command
+        let mut responses = smsc.receive(&bytes[..20]);
+
+        responses.extend(smsc.receive(&bytes[20..]));
+
+        let (responses, rest) = decode_many(&responses);
+
+        assert!(rest.is_empty());
+        assert_eq!(smsc.state(), SessionState::BoundTrx);
+
+        let ids: Vec<_> = responses.iter().map(Command::id).collect();
+
+        assert_eq!(
+            ids,
+            [
+                CommandId::BindTransceiverResp,
+                CommandId::SubmitSmResp,
+                CommandId::SubmitSmResp,
+                CommandId::EnquireLinkResp,
+            ]
+        );
+
+        assert!(
+            responses
+                .iter()
+                .all(|response| response.status() == CommandStatus::EsmeRok)
+        );
+
+        let Some(Pdu::BindTransceiverResp(bind_resp)) = responses[0].pdu() else {
+            unreachable!()
+        };
+
+        assert_eq!(bind_resp.system_id.as_str(), "MockSmsc");
+        assert_eq!(responses[1].message_id(), Some("1"));
+        assert_eq!(responses[1].sequence_number(), 3);
+        assert_eq!(responses[2].message_id(), Some("2"));
+
+        // Already bound
+        let response = smsc
+            .handle(&Command::new(
+                CommandStatus::EsmeRok,
+                6,
+                BindTransceiver::default(),
+            ))
+            .unwrap();
+
+        assert_eq!(response.status(), CommandStatus::EsmeRalybnd);
+
+        let response = smsc
+            .handle(&Command::new(CommandStatus::EsmeRok, 7, Pdu::Unbind))
+            .unwrap();
+
+        assert_eq!(response.id(), CommandId::UnbindResp);
+        assert_eq!(smsc.state(), SessionState::Unbound);
+    }
+
+    fn message_ids(mut smsc: MockSmsc) -> Vec<String> {
+        smsc.handle(&Command::new(
+            CommandStatus::EsmeRok,
+            1,
+            BindTransceiver::default(),
+        ));
+
+        (2..5)
+            .map(|sequence_number| {
+                let response = smsc
+                    .handle(&Command::new(
+                        CommandStatus::EsmeRok,
+                        sequence_number,
+                        SubmitSm::default(),
+                    ))
+                    .unwrap();
+
+                response.message_id().unwrap().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn message_id_generators() {
+        assert_eq!(
+            message_ids(MockSmsc::new().with_message_id_generator(SequentialMessageId::new(41))),
+            ["41", "42", "43"]
+        );
+
+        assert_eq!(
+            message_ids(MockSmsc::new().with_message_id_generator(HexMessageId::new(9))),
+            ["9", "A", "B"]
+        );
+
+        let mut counter = 0;
+
+        assert_eq!(
+            message_ids(MockSmsc::new().with_message_id_generator(move || {
+                counter += 1;
+
+                format!("msg-{counter}")
+            })),
+            ["msg-1", "msg-2", "msg-3"]
+        );
+
+        // Deterministic for a seed
+        let uuids = message_ids(MockSmsc::new().with_message_id_generator(UuidMessageId::new(7)));
+
+        let mut generator = UuidMessageId::new(7);
+
+        assert_eq!(
+            uuids,
+            (0..3)
+                .map(|_| generator.next_message_id())
+                .collect::<Vec<_>>()
+        );
+
+        for uuid in &uuids {
+            let groups: Vec<_> = uuid.split('-').map(str::len).collect();
+
+            assert_eq!(groups, [8, 4, 4, 4, 12]);
+            assert_eq!(&uuid[14..15], "4");
+            assert!(matches!(&uuid[19..20], "8" | "9" | "a" | "b"));
+        }
+
+        assert_ne!(uuids[0], uuids[1]);
+    }
+
+    #[test]
+    fn message_id_too_long() {
+        let mut smsc = MockSmsc::new()
+            .with_message_id_generator(|| "1".repeat(65))
+            .with_delivery_receipts(DeliveryReceiptStat::Delivered, Duration::ZERO);
+
+        smsc.handle(&Command::new(
+            CommandStatus::EsmeRok,
+            1,
+            BindTransceiver::default(),
+        ));
+
+        let response = smsc
+            .handle(&Command::new(
+                CommandStatus::EsmeRok,
+                2,
+                SubmitSm::default(),
+            ))
+            .unwrap();
+
+        assert_eq!(response.id(), CommandId::SubmitSmResp);
+        assert_eq!(response.status(), CommandStatus::EsmeRsyserr);
+        assert_eq!(response.sequence_number(), 2);
+        assert!(response.pdu().is_none());
+
+        // No receipt for the failed submit_sm
+        assert!(smsc.outgoing().is_empty());
+    }
+
+    #[test]
+    fn delivery_receipt() {
+        let mut smsc = MockSmsc::new()
+            .with_message_id_generator(HexMessageId::new(0xABC))
+            .with_delivery_receipts(DeliveryReceiptStat::Delivered, Duration::ZERO);
+
+        let submit_sm = SubmitSm::builder()
+            .source_addr("Rusmpp".parse().unwrap())
+            .destination_addr("491701234567".parse().unwrap())
+            .registered_delivery(RegisteredDelivery::request_all())
+            .short_message(OctetString::new(b"Hello from Rusmpp!").unwrap())
+            .build();
+
+        let bytes = encode(&[
+            Command::new(CommandStatus::EsmeRok, 1, BindTransceiver::default()),
+            Command::new(CommandStatus::EsmeRok, 2, submit_sm),
+        ]);
+
+        let (commands, _) = decode_many(&smsc.receive(&bytes));
+
+        let ids: Vec<_> = commands.iter().map(Command::id).collect();
+
+        assert_eq!(
+            ids,
+            [
+                CommandId::BindTransceiverResp,
+                CommandId::SubmitSmResp,
+                CommandId::DeliverSm,
+            ]
+        );
+
+        let Some(Pdu::DeliverSm(deliver_sm)) = commands[2].pdu() else {
+            unreachable!()
+        };
+
+        assert_eq!(
+            deliver_sm.esm_class.message_type,
+            MessageType::ShortMessageContainsMCDeliveryReceipt
+        );
+        assert_eq!(deliver_sm.source_addr.as_str(), "491701234567");
+        assert_eq!(deliver_sm.destination_addr.as_str(), "Rusmpp");
+
+        let receipt = DeliveryReceipt::parse(
+            core::str::from_utf8(deliver_sm.short_message().bytes()).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(receipt.id, "ABC");
+        assert_eq!(receipt.id, commands[1].message_id().unwrap());
+        assert_eq!(receipt.stat, Some(DeliveryReceiptStat::Delivered));
+        assert_eq!(receipt.text.as_deref(), Some("Hello from Rusmpp!"));
+
+        // The ESME acknowledges the receipt
+        let deliver_sm_resp = Command::new(
+            CommandStatus::EsmeRok,
+            commands[2].sequence_number(),
+            crate::pdus::owned::DeliverSmResp::default(),
+        );
+
+        assert!(smsc.receive(&encode(&[deliver_sm_resp])).is_empty());
+    }
+
+    #[test]
+    fn delayed_delivery_receipt() {
+        let mut smsc = MockSmsc::new()
+            .with_delivery_receipts(DeliveryReceiptStat::Undeliverable, Duration::from_secs(10));
+
+        smsc.handle(&Command::new(
+            CommandStatus::EsmeRok,
+            1,
+            BindTransmitter::default(),
+        ));
+
+        let submit_sm = |mc_delivery_receipt| {
+            SubmitSm::builder()
+                .registered_delivery(RegisteredDelivery::new(
+                    mc_delivery_receipt,
+                    Default::default(),
+                    Default::default(),
+                    0,
+                ))
+                .build()
+        };
+
+        for mc_delivery_receipt in [
+            MCDeliveryReceipt::NoMcDeliveryReceiptRequested,
+            MCDeliveryReceipt::McDeliveryReceiptRequestedWhereFinalDeliveryOutcomeIsSuccess,
+            MCDeliveryReceipt::McDeliveryReceiptRequestedWhereFinalDeliveryOutcomeIsFailure,
+        ] {
+            smsc.handle(&Command::new(
+                CommandStatus::EsmeRok,
+                2,
+                submit_sm(mc_delivery_receipt),
+            ));
+        }
+
+        smsc.advance(Duration::from_secs(9));
+
+        assert!(smsc.outgoing().is_empty());
+
+        smsc.advance(Duration::from_secs(1));
+
+        // Not deliverable to a transmitter
+        assert!(smsc.outgoing().is_empty());
+
+        let mut smsc_trx = MockSmsc::new()
+            .with_delivery_receipts(DeliveryReceiptStat::Undeliverable, Duration::from_secs(10));
+
+        smsc_trx.handle(&Command::new(
+            CommandStatus::EsmeRok,
+            1,
+            BindTransceiver::default(),
+        ));
+
+        for mc_delivery_receipt in [
+            MCDeliveryReceipt::NoMcDeliveryReceiptRequested,
+            MCDeliveryReceipt::McDeliveryReceiptRequestedWhereFinalDeliveryOutcomeIsSuccess,
+            MCDeliveryReceipt::McDeliveryReceiptRequestedWhereFinalDeliveryOutcomeIsFailure,
+        ] {
+            smsc_trx.handle(&Command::new(
+                CommandStatus::EsmeRok,
+                2,
+                submit_sm(mc_delivery_receipt),
+            ));
+        }
+
+        smsc_trx.advance(Duration::from_secs(10));
+
+        // Only the failure receipt was requested
+        let outgoing = smsc_trx.outgoing();
+
+        assert_eq!(outgoing.len(), 1);
+
+        let Some(Pdu::DeliverSm(deliver_sm)) = outgoing[0].pdu() else {
+            unreachable!()
+        };
+
+        assert_eq!(
+            deliver_sm.get_tlv(crate::tlvs::TlvTag::ReceiptedMessageId),
+            Some(&MessageDeliveryRequestTlvValue::ReceiptedMessageId("3".parse().unwrap()).into())
+        );
+
+        assert!(smsc_trx.outgoing().is_empty());
+    }
+
+    #[test]
+    fn throttling() {
+        let mut smsc = MockSmsc::new().with_throttling(3, Duration::from_secs(1));
+
+        smsc.handle(&Command::new(
+            CommandStatus::EsmeRok,
+            1,
+            BindTransmitter::default(),
+        ));
+
+        let mut submit = |sequence_number| {
+            smsc.handle(&Command::new(
+                CommandStatus::EsmeRok,
+                sequence_number,
+                SubmitSm::default(),
+            ))
+            .unwrap()
+        };
+
+        for sequence_number in 2..5 {
+            assert_eq!(submit(sequence_number).status(), CommandStatus::EsmeRok);
+        }
+
+        let throttled = submit(5);
+
+        assert_eq!(throttled.status(), CommandStatus::EsmeRthrottled);
+        assert_eq!(throttled.id(), CommandId::SubmitSmResp);
+        assert_eq!(throttled.message_id(), Some(""));
+
+        // Other commands are not throttled
+        let response = smsc
+            .handle(&Command::new(CommandStatus::EsmeRok, 6, Pdu::EnquireLink))
+            .unwrap();
+
+        assert_eq!(response.status(), CommandStatus::EsmeRok);
+
+        smsc.advance(Duration::from_millis(999));
+
+        assert_eq!(
+            smsc.handle(&Command::new(
+                CommandStatus::EsmeRok,
+                7,
+                SubmitSm::default()
+            ))
+            .unwrap()
+            .status(),
+            CommandStatus::EsmeRthrottled
+        );
+
+        // The window slides
+        smsc.advance(Duration::from_millis(1));
+
+        assert_eq!(
+            smsc.handle(&Command::new(
+                CommandStatus::EsmeRok,
+                8,
+                SubmitSm::default()
+            ))
+            .unwrap()
+            .message_id(),
+            Some("4")
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        let mut smsc = MockSmsc::new();
+
+        let response = smsc
+            .handle(&Command::new(
+                CommandStatus::EsmeRok,
+                1,
+                Pdu::Outbind(Default::default()),
+            ))
+            .unwrap();
+
+        assert_eq!(response.id(), CommandId::GenericNack);
+        assert_eq!(response.status(), CommandStatus::EsmeRinvcmdid);
+
+        // Responses are not answered
+        assert!(
+            smsc.handle(&Command::new(
+                CommandStatus::EsmeRok,
+                2,
+                Pdu::EnquireLinkResp
+            ))
+            .is_none()
+        );
+
+        // command_length: 17, bind_transmitter with a truncated body
+        let mut bytes = [0; 17];
+
+        bytes[3] = 17;
+        bytes[7] = 0x02;
+        bytes[15] = 3;
+        bytes[16] = b'a';
+
+        let (responses, _) = decode_many(&smsc.receive(&bytes));
+
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].id(), CommandId::GenericNack);
+        assert_eq!(responses[0].status(), CommandStatus::EsmeRinvcmdlen);
+        assert_eq!(responses[0].sequence_number(), 3);
+    }
+}
@@ -0,0 +1,197 @@
+//! Decoding `SMPP` commands from captured traffic.
+//!
+//! Captures, e.g. `pcap` files, contain `TCP` segments. A command may span several segments and
+//! a segment may contain several commands. The caller reassembles the `TCP` stream of one direction,
+//! e.g. using Wireshark's "Follow TCP Stream", and passes the payload to [`parse_payload`].
+
+use crate::{
+    command::{CommandHeader, owned::Command, peek_header},
+    decode::DecodeError,
+};
+
+/// Returns an iterator over all `SMPP` commands of a reassembled `TCP` payload.
+///
+/// Commands are framed by their `command_length`. A command that fails to decode is yielded with its error
+/// and decoding continues with the next command. The iteration stops at the first incomplete command,
+/// see [`CapturedCommands::remainder`].
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{CommandId, pcap::parse_payload};
+/// let payload = [
+///     // enquire_link
+///     0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+///     // enquire_link_resp
+///     0x00, 0x00, 0x00, 0x10, 0x80, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+/// ];
+///
+/// let ids = parse_payload(&payload)
+///     .map(|captured| captured.command.unwrap().id())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(ids, [CommandId::EnquireLink, CommandId::EnquireLinkResp]);
+/// ```
+pub fn parse_payload(payload: &[u8]) -> CapturedCommands<'_> {
+    CapturedCommands {
+        rest: payload,
+        offset: 0,
+    }
+}
+
+/// A command of a captured payload.
+///
+/// See [`parse_payload`].
+#[derive(Debug)]
+pub struct CapturedCommand<'a> {
+    /// The offset of the command in the payload.
+    pub offset: usize,
+    /// The bytes of the command, including the `command_length`.
+    pub bytes: &'a [u8],
+    /// The decoded command.
+    pub command: Result<Command, DecodeError>,
+}
+
+/// Iterator over the commands of a captured payload.
+///
+/// Created by [`parse_payload`].
+#[derive(Debug, Clone)]
+pub struct CapturedCommands<'a> {
+    rest: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> CapturedCommands<'a> {
+    /// Returns the bytes that were not yielded as commands.
+    ///
+    /// After the iteration, these are the bytes of an incomplete command at the end of the payload,
+    /// or the bytes following a `command_length` smaller than the header.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.rest
+    }
+
+    /// Returns the offset of the [`remainder`](CapturedCommands::remainder) in the payload.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<'a> Iterator for CapturedCommands<'a> {
+    type Item = CapturedCommand<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header = peek_header(self.rest)?;
+        let command_length = header.command_length as usize;
+
+        // Not framed, the following bytes can not be trusted
+        if command_length < CommandHeader::LENGTH || self.rest.len() < command_length {
+            return None;
+        }
+
+        let (bytes, rest) = self.rest.split_at(command_length);
+
+        let captured = CapturedCommand {
+            offset: self.offset,
+            bytes,
+            command: Command::try_from(bytes),
+        };
+
+        self.rest = rest;
+        self.offset += command_length;
+
+        Some(captured)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::{
+        CommandId, CommandStatus,
+        command::owned::encode_many,
+        pdus::owned::{Pdu, SubmitSm, SubmitSmResp},
+        types::owned::{COctetString, OctetString},
+    };
+
+    use super::*;
+
+    #[test]
+    fn multiple_commands() {
+        let commands = [
+            Command::new(
+                CommandStatus::EsmeRok,
+                1,
+                SubmitSm::builder()
+                    .short_message(OctetString::new(b"Hello").unwrap())
+                    .build(),
+            ),
+            Command::new(
+                CommandStatus::EsmeRok,
+                1,
+                SubmitSmResp::builder()
+                    .message_id(COctetString::new(b"0001\0").unwrap())
+                    .build(),
+            ),
+            Command::new(CommandStatus::EsmeRok, 2, Pdu::EnquireLink),
+        ];
+
+        let mut payload = Vec::new();
+
+        encode_many(&commands, &mut payload);
+
+        let second = 4 + crate::encode::Length::length(&commands[0]);
+
+        // A malformed submit_sm_resp with a missing message_id terminator
+        payload.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x12, 0x80, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x03, 0x30, 0x31,
+        ]);
+
+        let malformed = payload.len() - 18;
+
+        // The first bytes of a truncated enquire_link
+        payload.extend_from_slice(&[0x00, 0x00, 0x00, 0x10, 0x00, 0x00]);
+
+        let mut captured = parse_payload(&payload);
+
+        let decoded = captured.by_ref().collect::<Vec<_>>();
+
+        assert_eq!(decoded.len(), 4);
+
+        for (captured, command) in decoded.iter().zip(&commands) {
+            assert_eq!(captured.command.as_ref().ok(), Some(command));
+        }
+
+        assert_eq!(decoded[1].offset, second);
+        assert_eq!(decoded[3].offset, malformed);
+        assert_eq!(decoded[3].bytes.len(), 18);
+        assert!(decoded[3].command.is_err());
+
+        assert_eq!(captured.offset(), payload.len() - 6);
+        assert_eq!(captured.remainder(), [0x00, 0x00, 0x00, 0x10, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn unframed() {
+        // command_length smaller than the header
+        let payload = [
+            0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x01,
+        ];
+
+        let mut captured = parse_payload(&payload);
+
+        assert!(captured.next().is_none());
+        assert_eq!(captured.remainder(), payload);
+
+        let payload = [
+            0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x01,
+        ];
+
+        let captured = parse_payload(&payload).next().unwrap();
+
+        assert_eq!(captured.command.unwrap().id(), CommandId::EnquireLink);
+    }
+}
@@ -41,6 +41,9 @@ pub struct AlertNotification<'a> {
 }
 
 impl<'a> AlertNotification<'a> {
+    /// The [`CommandId`](crate::CommandId) of the `alert_notification` PDU.
+    pub const COMMAND_ID: u32 = 0x00000102;
+
     pub fn new(
         source_addr_ton: Ton,
         source_addr_npi: Npi,
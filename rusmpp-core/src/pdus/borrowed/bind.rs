@@ -7,7 +7,7 @@ use crate::{
 };
 
 macro_rules! bind {
-    ($name:ident) => {
+    ($name:ident, $command_id:literal) => {
         #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
         #[rusmpp(decode = borrowed, test = skip)]
         #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
@@ -45,6 +45,9 @@ macro_rules! bind {
         }
 
         impl<'a> $name<'a> {
+            /// The [`CommandId`](crate::CommandId) of the PDU.
+            pub const COMMAND_ID: u32 = $command_id;
+
             pub const fn new(
                 system_id: COctetString<'a, 1, 16>,
                 password: COctetString<'a, 1, 9>,
@@ -126,9 +129,9 @@ macro_rules! bind {
     };
 }
 
-bind!(BindTransmitter);
-bind!(BindReceiver);
-bind!(BindTransceiver);
+bind!(BindTransmitter, 0x00000002);
+bind!(BindReceiver, 0x00000001);
+bind!(BindTransceiver, 0x00000009);
 
 impl<'a, const N: usize> From<BindTransmitter<'a>> for Pdu<'a, N> {
     fn from(value: BindTransmitter<'a>) -> Self {
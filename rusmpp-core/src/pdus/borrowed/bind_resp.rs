@@ -8,7 +8,7 @@ use crate::{
 };
 
 macro_rules! bind_resp {
-    ($name:ident) => {
+    ($name:ident, $command_id:literal) => {
         #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
         #[rusmpp(decode = borrowed, test = skip)]
         #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
@@ -25,6 +25,9 @@ macro_rules! bind_resp {
         }
 
         impl<'a> $name<'a> {
+            /// The [`CommandId`](crate::CommandId) of the PDU.
+            pub const COMMAND_ID: u32 = $command_id;
+
             pub fn new(
                 system_id: COctetString<'a, 1, 16>,
                 sc_interface_version: Option<InterfaceVersion>,
@@ -98,9 +101,9 @@ macro_rules! bind_resp {
     };
 }
 
-bind_resp!(BindTransmitterResp);
-bind_resp!(BindReceiverResp);
-bind_resp!(BindTransceiverResp);
+bind_resp!(BindTransmitterResp, 0x80000002);
+bind_resp!(BindReceiverResp, 0x80000001);
+bind_resp!(BindTransceiverResp, 0x80000009);
 
 impl<'a, const N: usize> From<BindTransmitterResp<'a>> for Pdu<'a, N> {
     fn from(value: BindTransmitterResp<'a>) -> Self {
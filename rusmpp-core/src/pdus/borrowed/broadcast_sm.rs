@@ -87,6 +87,9 @@ pub struct BroadcastSm<'a, const N: usize> {
 }
 
 impl<'a, const N: usize> BroadcastSm<'a, N> {
+    /// The [`CommandId`](crate::CommandId) of the `broadcast_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000111;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         service_type: ServiceType<'a>,
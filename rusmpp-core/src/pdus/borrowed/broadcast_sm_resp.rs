@@ -22,6 +22,9 @@ pub struct BroadcastSmResp<'a, const N: usize> {
 }
 
 impl<'a, const N: usize> BroadcastSmResp<'a, N> {
+    /// The [`CommandId`](crate::CommandId) of the `broadcast_sm_resp` PDU.
+    pub const COMMAND_ID: u32 = 0x80000111;
+
     pub fn new(
         message_id: COctetString<'a, 1, 65>,
         tlvs: heapless::vec::Vec<impl Into<BroadcastResponseTlvValue<'a>>, N>,
@@ -67,6 +67,9 @@ pub struct CancelBroadcastSm<'a, const N: usize> {
 }
 
 impl<'a, const N: usize> CancelBroadcastSm<'a, N> {
+    /// The [`CommandId`](crate::CommandId) of the `cancel_broadcast_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000113;
+
     pub fn new(
         service_type: ServiceType<'a>,
         message_id: COctetString<'a, 1, 65>,
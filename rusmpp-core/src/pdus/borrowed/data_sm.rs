@@ -68,6 +68,9 @@ pub struct DataSm<'a, const N: usize> {
 }
 
 impl<'a, const N: usize> DataSm<'a, N> {
+    /// The [`CommandId`](crate::CommandId) of the `data_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000103;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         service_type: ServiceType<'a>,
@@ -92,6 +92,9 @@ pub struct DeliverSm<'a, const N: usize> {
 }
 
 impl<'a, const N: usize> DeliverSm<'a, N> {
+    /// The [`CommandId`](crate::CommandId) of the `deliver_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000005;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         service_type: ServiceType<'a>,
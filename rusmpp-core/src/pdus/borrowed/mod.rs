@@ -1,4 +1,6 @@
 //! Borrowed `SMPP` PDUs.
+//!
+//! The `TLV`s of a PDU are stored in a fixed-capacity [`heapless::Vec`] of `N` elements, no global allocator is required.
 
 mod pdu;
 pub use pdu::Pdu;
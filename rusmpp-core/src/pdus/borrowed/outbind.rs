@@ -25,6 +25,9 @@ pub struct Outbind<'a> {
 }
 
 impl<'a> Outbind<'a> {
+    /// The [`CommandId`](crate::CommandId) of the `outbind` PDU.
+    pub const COMMAND_ID: u32 = 0x0000000B;
+
     pub fn new(system_id: COctetString<'a, 1, 16>, password: COctetString<'a, 1, 9>) -> Self {
         Self {
             system_id,
@@ -176,6 +176,9 @@ pub enum Pdu<'a, const N: usize> {
     /// attempt and for successful attempts will also include the
     /// current state of the message.
     CancelBroadcastSmResp,
+    /// Body of a command with an unknown `command_id`, kept as opaque bytes.
+    ///
+    /// Re-encodes byte-for-byte, so proxies forward unknown commands transparently.
     Other {
         command_id: CommandId,
         body: AnyOctetString<'a>,
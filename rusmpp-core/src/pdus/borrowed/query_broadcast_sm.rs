@@ -51,6 +51,9 @@ pub struct QueryBroadcastSm<'a> {
 }
 
 impl<'a> QueryBroadcastSm<'a> {
+    /// The [`CommandId`](crate::CommandId) of the `query_broadcast_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000112;
+
     pub fn new(
         message_id: COctetString<'a, 1, 65>,
         source_addr_ton: Ton,
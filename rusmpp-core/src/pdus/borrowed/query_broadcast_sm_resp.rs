@@ -23,6 +23,9 @@ pub struct QueryBroadcastSmResp<'a, const N: usize> {
 }
 
 impl<'a, const N: usize> QueryBroadcastSmResp<'a, N> {
+    /// The [`CommandId`](crate::CommandId) of the `query_broadcast_sm_resp` PDU.
+    pub const COMMAND_ID: u32 = 0x80000112;
+
     pub fn new(
         message_id: COctetString<'a, 1, 65>,
         tlvs: heapless::vec::Vec<impl Into<QueryBroadcastResponseTlvValue<'a>>, N>,
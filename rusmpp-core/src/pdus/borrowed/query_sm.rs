@@ -46,6 +46,9 @@ pub struct QuerySm<'a> {
 }
 
 impl<'a> QuerySm<'a> {
+    /// The [`CommandId`](crate::CommandId) of the `query_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000003;
+
     pub fn new(
         message_id: COctetString<'a, 1, 65>,
         source_addr_ton: Ton,
@@ -34,6 +34,9 @@ pub struct QuerySmResp<'a> {
 }
 
 impl<'a> QuerySmResp<'a> {
+    /// The [`CommandId`](crate::CommandId) of the `query_sm_resp` PDU.
+    pub const COMMAND_ID: u32 = 0x80000003;
+
     pub fn new(
         message_id: COctetString<'a, 1, 65>,
         final_date: EmptyOrFullCOctetString<'a, 17>,
@@ -79,6 +79,9 @@ pub struct ReplaceSm<'a> {
 }
 
 impl<'a> ReplaceSm<'a> {
+    /// The [`CommandId`](crate::CommandId) of the `replace_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000007;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         message_id: COctetString<'a, 1, 65>,
@@ -7,7 +7,7 @@ use crate::{
 };
 
 macro_rules! sm_resp {
-    ($name:ident) => {
+    ($name:ident, $command_id:literal) => {
         #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
         #[rusmpp(decode = borrowed, test = skip)]
         #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
@@ -25,6 +25,9 @@ macro_rules! sm_resp {
         }
 
         impl<'a, const N: usize> $name<'a, N> {
+            /// The [`CommandId`](crate::CommandId) of the PDU.
+            pub const COMMAND_ID: u32 = $command_id;
+
             pub fn new(
                 message_id: COctetString<'a, 1, 65>,
                 tlvs: heapless::vec::Vec<impl Into<MessageDeliveryResponseTlvValue<'a>>, N>,
@@ -98,8 +101,8 @@ macro_rules! sm_resp {
     };
 }
 
-sm_resp!(DeliverSmResp);
-sm_resp!(DataSmResp);
+sm_resp!(DeliverSmResp, 0x80000005);
+sm_resp!(DataSmResp, 0x80000103);
 
 impl<'a, const N: usize> From<DeliverSmResp<'a, N>> for Pdu<'a, N> {
     fn from(value: DeliverSmResp<'a, N>) -> Self {
@@ -102,6 +102,9 @@ pub struct SubmitMulti<'a, const N: usize> {
 }
 
 impl<'a, const N: usize> SubmitMulti<'a, N> {
+    /// The [`CommandId`](crate::CommandId) of the `submit_multi` PDU.
+    pub const COMMAND_ID: u32 = 0x00000021;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         service_type: ServiceType<'a>,
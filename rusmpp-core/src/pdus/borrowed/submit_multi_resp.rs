@@ -34,6 +34,9 @@ pub struct SubmitMultiResp<'a, const N: usize> {
 }
 
 impl<'a, const N: usize> SubmitMultiResp<'a, N> {
+    /// The [`CommandId`](crate::CommandId) of the `submit_multi_resp` PDU.
+    pub const COMMAND_ID: u32 = 0x80000021;
+
     pub fn new(
         message_id: COctetString<'a, 1, 65>,
         unsuccess_sme: heapless::vec::Vec<UnsuccessSme<'a>, N>,
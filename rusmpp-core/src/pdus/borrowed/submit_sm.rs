@@ -90,6 +90,9 @@ pub struct SubmitSm<'a, const N: usize> {
 }
 
 impl<'a, const N: usize> SubmitSm<'a, N> {
+    /// The [`CommandId`](crate::CommandId) of the `submit_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000004;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         service_type: ServiceType<'a>,
@@ -347,7 +350,10 @@ impl<'a, const N: usize> SubmitSmBuilder<'a, N> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{tests::TestInstance, types::borrowed::AnyOctetString};
+    use crate::{
+        CommandStatus, command::borrowed::Command, decode::borrowed::DecodeWithLength,
+        tests::TestInstance, types::borrowed::AnyOctetString,
+    };
 
     use super::*;
 
@@ -546,4 +552,38 @@ mod tests {
         assert_eq!(submit_sm.short_message(), &short_message);
         assert_eq!(submit_sm.sm_length(), short_message.length() as u8);
     }
+
+    #[test]
+    fn fixed_capacity_tlvs() {
+        let submit_sm = SubmitSm::<'static, 2>::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::UserMessageReference(
+                UserMessageReference::new(1),
+            ))
+            .expect("Failed to push TLV")
+            .push_tlv(MessageSubmissionRequestTlvValue::SmsSignal(2))
+            .expect("Failed to push TLV")
+            .build();
+
+        assert_eq!(submit_sm.tlvs().len(), 2);
+
+        // The TLV store is full, the rejected TLV is handed back
+        let mut full = submit_sm.clone();
+
+        let rejected = full
+            .push_tlv(MessageSubmissionRequestTlvValue::MoreMessagesToSend(
+                MoreMessagesToSend::MoreMessagesToFollow,
+            ))
+            .unwrap_err();
+
+        assert_eq!(rejected.tag(), TlvTag::MoreMessagesToSend);
+
+        // Encoding and decoding do not allocate
+        let command = Command::new(CommandStatus::EsmeRok, 1, submit_sm);
+        let (buf, size) = command.encode_to_array::<64>().unwrap();
+
+        // The command_length is not part of the decoded command
+        let (decoded, _) = Command::<'_, 2>::decode(&buf[4..size], size - 4).unwrap();
+
+        assert_eq!(decoded, command);
+    }
 }
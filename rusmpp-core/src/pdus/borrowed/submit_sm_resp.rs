@@ -22,6 +22,9 @@ pub struct SubmitSmResp<'a, const N: usize> {
 }
 
 impl<'a, const N: usize> SubmitSmResp<'a, N> {
+    /// The [`CommandId`](crate::CommandId) of the `submit_sm_resp` PDU.
+    pub const COMMAND_ID: u32 = 0x80000004;
+
     pub fn new(
         message_id: COctetString<'a, 1, 65>,
         tlvs: heapless::vec::Vec<impl Into<MessageSubmissionResponseTlvValue<'a>>, N>,
@@ -0,0 +1,145 @@
+use crate::{fields::SmppField, validate::ValidationError, values::owned::Address};
+
+use super::*;
+
+macro_rules! impl_address {
+    ($address:ident, $set_address:ident, $ton:ident, $npi:ident, $addr:ident; $($pdu:ident),* $(,)?) => {
+        $(
+            impl $pdu {
+                #[doc = concat!("Returns the `", stringify!($ton), "`, `", stringify!($npi), "` and `", stringify!($addr), "` as an [`Address`].")]
+                pub fn $address(&self) -> Address {
+                    Address::new(self.$ton, self.$npi, self.$addr.clone())
+                }
+
+                #[doc = concat!("Sets the `", stringify!($ton), "`, `", stringify!($npi), "` and `", stringify!($addr), "` from an [`Address`].")]
+                pub fn $set_address(&mut self, address: Address) {
+                    self.$ton = address.ton;
+                    self.$npi = address.npi;
+                    self.$addr = address.addr;
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_try_set_source_address {
+    ($($pdu:ident),* $(,)?) => {
+        $(
+            impl $pdu {
+                /// Sets the `source_addr_ton`, `source_addr_npi` and `source_addr` from a validated [`Address`].
+                ///
+                /// Fails without modifying the `PDU` if the address is not ASCII or an invalid alphanumeric sender ID,
+                /// see [`Address::validate`].
+                pub fn try_set_source_address(&mut self, address: Address) -> Result<(), ValidationError> {
+                    address.validate(SmppField::source_addr)?;
+
+                    self.set_source_address(address);
+
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_address!(
+    source_address, set_source_address, source_addr_ton, source_addr_npi, source_addr;
+    BroadcastSm,
+    CancelBroadcastSm,
+    CancelSm,
+    DataSm,
+    DeliverSm,
+    QueryBroadcastSm,
+    QuerySm,
+    ReplaceSm,
+    SubmitMulti,
+    SubmitSm,
+);
+
+impl_try_set_source_address!(
+    BroadcastSm,
+    CancelBroadcastSm,
+    CancelSm,
+    DataSm,
+    DeliverSm,
+    QueryBroadcastSm,
+    QuerySm,
+    ReplaceSm,
+    SubmitMulti,
+    SubmitSm,
+);
+
+impl_address!(
+    destination_address, set_destination_address, dest_addr_ton, dest_addr_npi, destination_addr;
+    CancelSm,
+    DataSm,
+    DeliverSm,
+    SubmitSm,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::owned::COctetString,
+        validate::Validate,
+        values::{Npi, Ton},
+    };
+
+    use super::*;
+
+    fn address() -> Address {
+        Address::new(
+            Ton::International,
+            Npi::Isdn,
+            COctetString::new(b"491701234567\0").unwrap(),
+        )
+    }
+
+    #[test]
+    fn submit_sm() {
+        let mut submit_sm = SubmitSm::default();
+
+        submit_sm.set_source_address(address());
+
+        assert_eq!(submit_sm.source_addr_ton, Ton::International);
+        assert_eq!(submit_sm.source_addr_npi, Npi::Isdn);
+        assert_eq!(submit_sm.source_addr.as_str(), "491701234567");
+        assert_eq!(submit_sm.source_address(), address());
+
+        // The destination is untouched
+        assert_eq!(submit_sm.destination_address(), Address::default());
+    }
+
+    #[test]
+    fn alphanumeric_sender() {
+        let sender =
+            |addr: &str| Address::new(Ton::Alphanumeric, Npi::Unknown, addr.parse().unwrap());
+
+        let mut submit_sm = SubmitSm::builder()
+            .destination_addr(COctetString::new(b"491701234567\0").unwrap())
+            .build();
+
+        // 12 characters
+        assert_eq!(
+            submit_sm.try_set_source_address(sender("RusmppSender")),
+            Err(ValidationError::InvalidAlphanumericAddress {
+                field: crate::fields::SmppField::source_addr
+            })
+        );
+        assert_eq!(submit_sm.source_address(), Address::default());
+
+        // 11 characters
+        assert!(
+            submit_sm
+                .try_set_source_address(sender("RusmppSend1"))
+                .is_ok()
+        );
+        assert_eq!(submit_sm.source_address(), sender("RusmppSend1"));
+        assert!(submit_sm.validate().is_ok());
+
+        // Bypassing the setter is caught by validating the PDU
+        submit_sm.set_source_address(sender("RusmppSender"));
+
+        assert!(submit_sm.validate().is_err());
+    }
+}
@@ -42,6 +42,9 @@ pub struct AlertNotification {
 }
 
 impl AlertNotification {
+    /// The [`CommandId`](crate::CommandId) of the `alert_notification` PDU.
+    pub const COMMAND_ID: u32 = 0x00000102;
+
     pub fn new(
         source_addr_ton: Ton,
         source_addr_npi: Npi,
@@ -64,6 +67,18 @@ impl AlertNotification {
         }
     }
 
+    /// Returns the `esme_addr_ton`, `esme_addr_npi` and `esme_addr` of the ESME which requested the alert.
+    pub const fn esme_address(&self) -> (Ton, Npi, &COctetString<1, 65>) {
+        (self.esme_addr_ton, self.esme_addr_npi, &self.esme_addr)
+    }
+
+    /// Sets the `esme_addr_ton`, `esme_addr_npi` and `esme_addr` of the ESME which requested the alert.
+    pub fn set_esme_address(&mut self, ton: Ton, npi: Npi, addr: COctetString<1, 65>) {
+        self.esme_addr_ton = ton;
+        self.esme_addr_npi = npi;
+        self.esme_addr = addr;
+    }
+
     pub const fn ms_availability_status_tlv(&self) -> Option<&Tlv> {
         self.ms_availability_status.as_ref()
     }
@@ -137,6 +152,11 @@ impl AlertNotificationBuilder {
         self
     }
 
+    pub fn esme_address(mut self, ton: Ton, npi: Npi, addr: COctetString<1, 65>) -> Self {
+        self.inner.set_esme_address(ton, npi, addr);
+        self
+    }
+
     pub fn ms_availability_status(
         mut self,
         ms_availability_status: Option<MsAvailabilityStatus>,
@@ -151,6 +171,17 @@ impl AlertNotificationBuilder {
     }
 }
 
+impl crate::validate::Validate for AlertNotification {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        use crate::{fields::SmppField, validate::ValidationError};
+
+        ValidationError::non_empty(SmppField::source_addr, self.source_addr.is_empty())?;
+        ValidationError::non_empty(SmppField::esme_addr, self.esme_addr.is_empty())?;
+
+        self.ms_availability_status.validate()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -192,4 +223,47 @@ mod tests {
     fn encode_decode() {
         crate::tests::owned::encode_decode_with_length_test_instances::<AlertNotification>();
     }
+
+    #[test]
+    fn esme_address() {
+        use crate::encode::{Encode, Length};
+
+        let alert_notification = AlertNotification::builder()
+            .source_addr(COctetString::from_str("1").unwrap())
+            .esme_address(
+                Ton::International,
+                Npi::Isdn,
+                COctetString::from_str("4915112345678").unwrap(),
+            )
+            .build();
+
+        let (ton, npi, addr) = alert_notification.esme_address();
+
+        assert_eq!(ton, Ton::International);
+        assert_eq!(npi, Npi::Isdn);
+        assert_eq!(addr.as_str(), "4915112345678");
+
+        let mut buf = alloc::vec![0; alert_notification.length()];
+        alert_notification.encode(&mut buf);
+
+        // source_addr_ton, source_addr_npi, source_addr "1\0", then esme_addr_ton, esme_addr_npi, esme_addr
+        assert_eq!(&buf[4..7], &[0x01, 0x01, b'4']);
+
+        let mut alert_notification = alert_notification;
+
+        alert_notification.set_esme_address(
+            Ton::National,
+            Npi::National,
+            COctetString::from_str("0151").unwrap(),
+        );
+
+        assert_eq!(
+            alert_notification.esme_address(),
+            (
+                Ton::National,
+                Npi::National,
+                &COctetString::from_str("0151").unwrap()
+            )
+        );
+    }
 }
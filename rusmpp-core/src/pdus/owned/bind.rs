@@ -1,9 +1,13 @@
 use rusmpp_macros::Rusmpp;
 
-use crate::{pdus::owned::Pdu, types::owned::COctetString, values::*};
+use crate::{
+    pdus::owned::Pdu,
+    types::{COctetStringError, owned::COctetString},
+    values::*,
+};
 
 macro_rules! bind {
-    ($name:ident) => {
+    ($name:ident $(, $command_id:literal)?) => {
         #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
         #[rusmpp(decode = owned, test = skip)]
         #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
@@ -40,7 +44,21 @@ macro_rules! bind {
             pub address_range: COctetString<1, 41>,
         }
 
+        impl crate::validate::Validate for $name {
+            fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+                crate::validate::ValidationError::non_empty(
+                    crate::fields::SmppField::system_id,
+                    self.system_id.is_empty(),
+                )
+            }
+        }
+
         impl $name {
+            $(
+                /// The [`CommandId`](crate::CommandId) of the PDU.
+                pub const COMMAND_ID: u32 = $command_id;
+            )?
+
             pub const fn new(
                 system_id: COctetString<1, 16>,
                 password: COctetString<1, 9>,
@@ -61,6 +79,24 @@ macro_rules! bind {
                 }
             }
 
+            /// Sets the `system_id` from a [`str`].
+            ///
+            /// Fails if the `system_id` exceeds the maximum of 15 characters.
+            pub fn set_system_id(&mut self, system_id: &str) -> Result<(), COctetStringError> {
+                self.system_id = system_id.parse()?;
+
+                Ok(())
+            }
+
+            /// Sets the `password` from a [`str`].
+            ///
+            /// Fails if the `password` exceeds the maximum of 8 characters.
+            pub fn set_password(&mut self, password: &str) -> Result<(), COctetStringError> {
+                self.password = password.parse()?;
+
+                Ok(())
+            }
+
             ::pastey::paste! {
                 pub fn builder() -> [<$name Builder>] {
                     [<$name Builder>]::new()
@@ -122,9 +158,10 @@ macro_rules! bind {
     };
 }
 
-bind!(BindTransmitter);
-bind!(BindReceiver);
-bind!(BindTransceiver);
+bind!(BindTransmitter, 0x00000002);
+bind!(BindReceiver, 0x00000001);
+bind!(BindTransceiver, 0x00000009);
+// Not a PDU on its own, binds as any of the above.
 bind!(BindAny);
 
 impl From<BindTransmitter> for Pdu {
@@ -246,6 +283,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn set_system_id() {
+        let mut bind = BindTransmitter::default();
+
+        bind.set_system_id("system_id").unwrap();
+        assert_eq!(bind.system_id.as_str(), "system_id");
+
+        let error = bind.set_system_id("system_id_too_long").unwrap_err();
+        assert!(matches!(
+            error,
+            COctetStringError::TooManyBytes {
+                actual: 19,
+                max: 16
+            }
+        ));
+
+        // The previous value is kept
+        assert_eq!(bind.system_id.as_str(), "system_id");
+    }
+
+    #[test]
+    fn set_password() {
+        let mut bind = BindTransceiver::default();
+
+        bind.set_password("password").unwrap();
+        assert_eq!(bind.password.as_str(), "password");
+
+        let error = bind.set_password("password1").unwrap_err();
+        assert!(matches!(
+            error,
+            COctetStringError::TooManyBytes { actual: 10, max: 9 }
+        ));
+    }
+
     #[test]
     fn encode_decode() {
         crate::tests::owned::encode_decode_test_instances::<BindTransmitter>();
@@ -3,12 +3,12 @@ use rusmpp_macros::Rusmpp;
 use crate::{
     pdus::owned::Pdu,
     tlvs::owned::{Tlv, TlvValue},
-    types::owned::COctetString,
+    types::{COctetStringError, owned::COctetString},
     values::*,
 };
 
 macro_rules! bind_resp {
-    ($name:ident) => {
+    ($name:ident, $command_id:literal) => {
         #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
         #[rusmpp(decode = owned, test = skip)]
         #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
@@ -22,9 +22,26 @@ macro_rules! bind_resp {
             /// `SMPP` version supported by MC. [`ScInterfaceVersion`].
             #[rusmpp(length = "checked")]
             sc_interface_version: Option<Tlv>,
+            /// The MC omitted the body, not encoded.
+            #[rusmpp(skip)]
+            #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+            #[cfg_attr(
+                any(feature = "serde", feature = "serde-deserialize-unchecked"),
+                serde(skip)
+            )]
+            header_only: bool,
+        }
+
+        impl crate::validate::Validate for $name {
+            fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+                crate::validate::Validate::validate(&self.sc_interface_version)
+            }
         }
 
         impl $name {
+            /// The [`CommandId`](crate::CommandId) of the PDU.
+            pub const COMMAND_ID: u32 = $command_id;
+
             pub fn new(
                 system_id: COctetString<1, 16>,
                 sc_interface_version: Option<InterfaceVersion>,
@@ -34,9 +51,36 @@ macro_rules! bind_resp {
                     sc_interface_version: sc_interface_version
                         .map(TlvValue::ScInterfaceVersion)
                         .map(From::from),
+                    header_only: false,
                 }
             }
 
+            /// Creates a bind_resp for a successful response without a body.
+            ///
+            /// Some v3.3 MCs omit the `system_id` entirely.
+            pub(crate) fn header_only() -> Self {
+                Self {
+                    header_only: true,
+                    ..Default::default()
+                }
+            }
+
+            /// Returns `true` if the MC omitted the body of the bind_resp.
+            ///
+            /// The `system_id` is empty and the [`Pdu`] is encoded without a body.
+            pub const fn is_header_only(&self) -> bool {
+                self.header_only
+            }
+
+            /// Sets the `system_id` from a [`str`].
+            ///
+            /// Fails if the `system_id` exceeds the maximum of 15 characters.
+            pub fn set_system_id(&mut self, system_id: &str) -> Result<(), COctetStringError> {
+                self.system_id = system_id.parse()?;
+
+                Ok(())
+            }
+
             pub const fn sc_interface_version_tlv(&self) -> Option<&Tlv> {
                 self.sc_interface_version.as_ref()
             }
@@ -98,9 +142,9 @@ macro_rules! bind_resp {
     };
 }
 
-bind_resp!(BindTransmitterResp);
-bind_resp!(BindReceiverResp);
-bind_resp!(BindTransceiverResp);
+bind_resp!(BindTransmitterResp, 0x80000002);
+bind_resp!(BindReceiverResp, 0x80000001);
+bind_resp!(BindTransceiverResp, 0x80000009);
 
 impl From<BindTransmitterResp> for Pdu {
     fn from(value: BindTransmitterResp) -> Self {
@@ -164,6 +208,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn set_system_id() {
+        let mut bind_resp = BindReceiverResp::default();
+
+        bind_resp.set_system_id("SMSC").unwrap();
+        assert_eq!(bind_resp.system_id.as_str(), "SMSC");
+
+        let error = bind_resp.set_system_id("system_id_too_long").unwrap_err();
+        assert!(matches!(error, COctetStringError::TooManyBytes { .. }));
+    }
+
     #[test]
     fn encode_decode() {
         crate::tests::owned::encode_decode_with_length_test_instances::<BindTransmitterResp>();
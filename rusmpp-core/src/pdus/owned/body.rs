@@ -0,0 +1,270 @@
+use crate::{
+    CommandId,
+    decode::{DecodeError, owned::DecodeWithLength},
+    encode::Encode,
+    types::owned::AnyOctetString,
+};
+
+use super::*;
+
+/// Encoding and decoding of a `PDU` body, without the 16-byte command header.
+///
+/// Useful when the `command_id` is already known, e.g. for testing individual `PDU`s.
+///
+/// Custom (vendor-specific) `PDU`s derived with [`Rusmpp`](crate::Rusmpp) implement [`PduBody`] to be sent as [`Pdu::Other`],
+/// see [`Pdu::vendor`].
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{
+/// #     pdus::owned::{PduBody, SubmitSm},
+/// #     types::owned::COctetString,
+/// # };
+/// let submit_sm = SubmitSm::builder()
+///     .destination_addr(COctetString::new(b"123456\0").unwrap())
+///     .build();
+///
+/// let body = submit_sm.encode_body();
+///
+/// assert_eq!(SubmitSm::decode_body(&body).unwrap(), submit_sm);
+/// ```
+pub trait PduBody: DecodeWithLength + Encode {
+    /// Decodes the `PDU` from its body `bytes`.
+    ///
+    /// `bytes` must contain exactly one body.
+    /// Trailing bytes result in a [`DecodeErrorKind::TrailingBytes`](crate::decode::DecodeErrorKind::TrailingBytes) error.
+    fn decode_body(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let (pdu, size) = Self::decode(bytes, bytes.len())?;
+
+        if size < bytes.len() {
+            return Err(DecodeError::trailing_bytes(bytes.len() - size));
+        }
+
+        Ok(pdu)
+    }
+
+    /// Encodes the body of the `PDU`.
+    fn encode_body(&self) -> alloc::vec::Vec<u8> {
+        let mut body = alloc::vec![0; self.length()];
+
+        let size = self.encode(&mut body);
+
+        body.truncate(size);
+
+        body
+    }
+}
+
+impl Pdu {
+    /// Creates a [`Pdu::Other`] carrying the custom (vendor-specific) `pdu`.
+    ///
+    /// Custom `PDU`s have no variant of their own, e.g. a `Pdu::Vendor`.
+    /// [`Pdu::Other`] already carries any [`CommandId`] with a raw body, so it is sent, received and re-encoded unchanged.
+    /// A dedicated variant would have to hold a type-erased body that can not be cloned, compared, serialized or decoded
+    /// without knowing the custom type, and would break exhaustive matches on [`Pdu`] downstream.
+    /// The body is decoded on demand with [`Pdu::decode_vendor`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     CommandId, CommandStatus, Rusmpp,
+    /// #     command::owned::Command,
+    /// #     pdus::owned::{Pdu, PduBody},
+    /// #     types::owned::COctetString,
+    /// # };
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq, Rusmpp)]
+    /// #[rusmpp(crate = "::rusmpp_core", decode = owned)]
+    /// pub struct QueryBalance {
+    ///     pub account: COctetString<1, 17>,
+    ///     pub currency: u16,
+    /// }
+    ///
+    /// impl PduBody for QueryBalance {}
+    ///
+    /// let query_balance = QueryBalance {
+    ///     account: COctetString::new(b"account\0").unwrap(),
+    ///     currency: 978,
+    /// };
+    ///
+    /// let command = Command::builder()
+    ///     .status(CommandStatus::EsmeRok)
+    ///     .sequence_number(1)
+    ///     .pdu(Pdu::vendor(CommandId::Other(0x00010001), &query_balance));
+    ///
+    /// assert_eq!(command.id(), CommandId::Other(0x00010001));
+    ///
+    /// let decoded = command.pdu().unwrap().decode_vendor::<QueryBalance>();
+    ///
+    /// assert_eq!(decoded.unwrap().unwrap(), query_balance);
+    /// ```
+    pub fn vendor(command_id: CommandId, pdu: &impl PduBody) -> Self {
+        Pdu::Other {
+            command_id,
+            body: AnyOctetString::from(pdu.encode_body()),
+        }
+    }
+
+    /// Decodes the body of a [`Pdu::Other`] as the custom (vendor-specific) `PDU` `T`.
+    ///
+    /// Returns [`None`] if the `PDU` is not a [`Pdu::Other`].
+    pub fn decode_vendor<T: PduBody>(&self) -> Option<Result<T, DecodeError>> {
+        match self {
+            Pdu::Other { body, .. } => Some(T::decode_body(body.bytes())),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_pdu_body {
+    ($($pdu:ident),* $(,)?) => {
+        $(impl PduBody for $pdu {})*
+    };
+}
+
+impl_pdu_body!(
+    BindTransmitter,
+    BindTransmitterResp,
+    BindReceiver,
+    BindReceiverResp,
+    BindTransceiver,
+    BindTransceiverResp,
+    Outbind,
+    AlertNotification,
+    SubmitSm,
+    SubmitSmResp,
+    QuerySm,
+    QuerySmResp,
+    DeliverSm,
+    DeliverSmResp,
+    DataSm,
+    DataSmResp,
+    CancelSm,
+    ReplaceSm,
+    SubmitMulti,
+    SubmitMultiResp,
+    BroadcastSm,
+    BroadcastSmResp,
+    QueryBroadcastSm,
+    QueryBroadcastSmResp,
+    CancelBroadcastSm,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decode::DecodeErrorKind,
+        encode::Length,
+        tests::TestInstance,
+        tlvs::owned::{MessageSubmissionRequestTlvValue, Tlv},
+        types::owned::{COctetString, OctetString},
+        values::UserMessageReference,
+    };
+
+    use super::*;
+
+    #[test]
+    fn submit_sm_round_trip() {
+        let submit_sm = SubmitSm::builder()
+            .source_addr(COctetString::new(b"Rusmpp\0").unwrap())
+            .destination_addr(COctetString::new(b"123456\0").unwrap())
+            .short_message(OctetString::new(b"Hello").unwrap())
+            .push_tlv(MessageSubmissionRequestTlvValue::UserMessageReference(
+                UserMessageReference::new(16),
+            ))
+            .build();
+
+        let body = submit_sm.encode_body();
+
+        assert_eq!(body.len(), submit_sm.length());
+        assert_eq!(SubmitSm::decode_body(&body).unwrap(), submit_sm);
+    }
+
+    #[test]
+    fn round_trip_test_instances() {
+        for submit_sm in SubmitSm::instances() {
+            let body = submit_sm.encode_body();
+
+            assert_eq!(SubmitSm::decode_body(&body).unwrap(), submit_sm);
+        }
+
+        for query_sm in QuerySm::instances() {
+            let body = query_sm.encode_body();
+
+            assert_eq!(QuerySm::decode_body(&body).unwrap(), query_sm);
+        }
+    }
+
+    #[test]
+    fn trailing_bytes() {
+        let mut body = QuerySm::default().encode_body();
+
+        body.extend_from_slice(&[0, 0]);
+
+        let error = QuerySm::decode_body(&body).unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            DecodeErrorKind::TrailingBytes { count: 2 }
+        ));
+    }
+
+    /// A custom `PDU` as defined outside of this crate.
+    #[derive(Debug, Default, Clone, PartialEq, Eq, rusmpp_macros::Rusmpp)]
+    #[rusmpp(crate = "crate", decode = owned)]
+    struct VendorSubmit {
+        service_type: COctetString<1, 6>,
+        priority: u8,
+        sm_length: u8,
+        #[rusmpp(length = sm_length)]
+        short_message: OctetString<0, 255>,
+        #[rusmpp(length = "unchecked")]
+        tlvs: alloc::vec::Vec<Tlv>,
+    }
+
+    impl PduBody for VendorSubmit {}
+
+    #[test]
+    fn vendor_round_trip() {
+        let vendor_submit = VendorSubmit {
+            service_type: COctetString::new(b"VND\0").unwrap(),
+            priority: 3,
+            sm_length: 5,
+            short_message: OctetString::new(b"Hello").unwrap(),
+            tlvs: alloc::vec![Tlv::from(
+                MessageSubmissionRequestTlvValue::UserMessageReference(UserMessageReference::new(
+                    16
+                ),)
+            )],
+        };
+
+        let pdu = Pdu::vendor(CommandId::Other(0x00010004), &vendor_submit);
+
+        assert_eq!(pdu.command_id(), CommandId::Other(0x00010004));
+        assert_eq!(pdu.length(), vendor_submit.length());
+
+        let decoded = pdu.decode_vendor::<VendorSubmit>().unwrap().unwrap();
+
+        assert_eq!(decoded, vendor_submit);
+
+        assert!(
+            Pdu::from(QuerySm::default())
+                .decode_vendor::<VendorSubmit>()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn vendor_decode_error() {
+        // short_message is shorter than sm_length
+        let body = [b'V', b'N', b'D', 0x00, 0x03, 0x05, b'H', b'i'];
+
+        let pdu = Pdu::Other {
+            command_id: CommandId::Other(0x00010004),
+            body: AnyOctetString::new(body),
+        };
+
+        assert!(pdu.decode_vendor::<VendorSubmit>().unwrap().is_err());
+    }
+}
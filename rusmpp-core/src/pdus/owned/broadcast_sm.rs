@@ -2,7 +2,7 @@ use rusmpp_macros::Rusmpp;
 
 use crate::{
     pdus::owned::Pdu,
-    tlvs::owned::{BroadcastRequestTlvValue, Tlv},
+    tlvs::owned::{BroadcastRequestTlvValue, Tlv, TlvIndex},
     types::owned::{COctetString, EmptyOrFullCOctetString},
     values::{owned::*, *},
 };
@@ -83,10 +83,21 @@ pub struct BroadcastSm {
     pub sm_default_msg_id: u8,
     /// Broadcast request TLVs ([`BroadcastRequestTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(super) tlvs: alloc::vec::Vec<Tlv>,
+    /// Lookup index of the `tlvs`, not encoded.
+    #[rusmpp(skip)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        any(feature = "serde", feature = "serde-deserialize-unchecked"),
+        serde(skip)
+    )]
+    pub(super) tlv_index: TlvIndex,
 }
 
 impl BroadcastSm {
+    /// The [`CommandId`](crate::CommandId) of the `broadcast_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000111;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         service_type: ServiceType,
@@ -117,6 +128,7 @@ impl BroadcastSm {
             data_coding,
             sm_default_msg_id,
             tlvs,
+            tlv_index: TlvIndex::new(),
         }
     }
 
@@ -124,15 +136,25 @@ impl BroadcastSm {
         &self.tlvs
     }
 
+    /// Returns the tags of the `TLV`s that are not allowed in a `broadcast_sm`, if any.
+    ///
+    /// Unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) tags are vendor specific and always allowed.
+    pub fn validate_allowed_tlvs(&self) -> Result<(), alloc::vec::Vec<crate::tlvs::TlvTag>> {
+        crate::validate::allowed_tlvs(&self.tlvs, BroadcastRequestTlvValue::TAGS)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<BroadcastRequestTlvValue>>) {
+        self.tlv_index.clear();
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
     }
 
     pub fn clear_tlvs(&mut self) {
+        self.tlv_index.clear();
         self.tlvs.clear();
     }
 
     pub fn push_tlv(&mut self, tlv: impl Into<BroadcastRequestTlvValue>) {
+        self.tlv_index.clear();
         self.tlvs.push(Tlv::from(tlv.into()));
     }
 
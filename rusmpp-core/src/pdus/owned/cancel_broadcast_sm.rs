@@ -2,7 +2,7 @@ use rusmpp_macros::Rusmpp;
 
 use crate::{
     pdus::owned::Pdu,
-    tlvs::owned::{CancelBroadcastTlvValue, Tlv},
+    tlvs::owned::{CancelBroadcastTlvValue, Tlv, TlvIndex},
     types::owned::COctetString,
     values::{owned::*, *},
 };
@@ -62,10 +62,21 @@ pub struct CancelBroadcastSm {
     pub source_addr: COctetString<1, 21>,
     /// Cancel broadcast  TLVs ([`CancelBroadcastTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(super) tlvs: alloc::vec::Vec<Tlv>,
+    /// Lookup index of the `tlvs`, not encoded.
+    #[rusmpp(skip)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        any(feature = "serde", feature = "serde-deserialize-unchecked"),
+        serde(skip)
+    )]
+    pub(super) tlv_index: TlvIndex,
 }
 
 impl CancelBroadcastSm {
+    /// The [`CommandId`](crate::CommandId) of the `cancel_broadcast_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000113;
+
     pub fn new(
         service_type: ServiceType,
         message_id: COctetString<1, 65>,
@@ -83,6 +94,7 @@ impl CancelBroadcastSm {
             source_addr_npi,
             source_addr,
             tlvs,
+            tlv_index: TlvIndex::new(),
         }
     }
 
@@ -90,15 +102,25 @@ impl CancelBroadcastSm {
         &self.tlvs
     }
 
+    /// Returns the tags of the `TLV`s that are not allowed in a `cancel_broadcast_sm`, if any.
+    ///
+    /// Unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) tags are vendor specific and always allowed.
+    pub fn validate_allowed_tlvs(&self) -> Result<(), alloc::vec::Vec<crate::tlvs::TlvTag>> {
+        crate::validate::allowed_tlvs(&self.tlvs, CancelBroadcastTlvValue::TAGS)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<CancelBroadcastTlvValue>>) {
+        self.tlv_index.clear();
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
     }
 
     pub fn clear_tlvs(&mut self) {
+        self.tlv_index.clear();
         self.tlvs.clear();
     }
 
     pub fn push_tlv(&mut self, tlv: impl Into<CancelBroadcastTlvValue>) {
+        self.tlv_index.clear();
         self.tlvs.push(Tlv::from(tlv.into()));
     }
 
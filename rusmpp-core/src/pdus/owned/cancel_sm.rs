@@ -98,6 +98,9 @@ pub struct CancelSm {
 }
 
 impl CancelSm {
+    /// The [`CommandId`](crate::CommandId) of the `cancel_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000008;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         service_type: ServiceType,
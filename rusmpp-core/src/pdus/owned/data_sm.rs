@@ -2,8 +2,11 @@ use rusmpp_macros::Rusmpp;
 
 use crate::{
     pdus::owned::Pdu,
-    tlvs::owned::{MessageSubmissionRequestTlvValue, Tlv},
-    types::owned::COctetString,
+    tlvs::{
+        TlvTag,
+        owned::{MessageSubmissionRequestTlvValue, Tlv, TlvIndex, TlvValue},
+    },
+    types::owned::{AnyOctetString, COctetString},
     values::{owned::*, *},
 };
 /// The data_sm operation is similar to the submit_sm in that it provides a means to submit a
@@ -63,10 +66,21 @@ pub struct DataSm {
     pub data_coding: DataCoding,
     /// Message submission request TLVs ([`MessageSubmissionRequestTlvValue`])
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(super) tlvs: alloc::vec::Vec<Tlv>,
+    /// Lookup index of the `tlvs`, not encoded.
+    #[rusmpp(skip)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        any(feature = "serde", feature = "serde-deserialize-unchecked"),
+        serde(skip)
+    )]
+    pub(super) tlv_index: TlvIndex,
 }
 
 impl DataSm {
+    /// The [`CommandId`](crate::CommandId) of the `data_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000103;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         service_type: ServiceType,
@@ -95,6 +109,7 @@ impl DataSm {
             registered_delivery,
             data_coding,
             tlvs,
+            tlv_index: TlvIndex::new(),
         }
     }
 
@@ -102,18 +117,56 @@ impl DataSm {
         &self.tlvs
     }
 
+    /// Returns the tags of the `TLV`s that are not allowed in a `data_sm`, if any.
+    ///
+    /// Unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) tags are vendor specific and always allowed.
+    pub fn validate_allowed_tlvs(&self) -> Result<(), alloc::vec::Vec<crate::tlvs::TlvTag>> {
+        crate::validate::allowed_tlvs(&self.tlvs, MessageSubmissionRequestTlvValue::TAGS)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<MessageSubmissionRequestTlvValue>>) {
+        self.tlv_index.clear();
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
     }
 
     pub fn clear_tlvs(&mut self) {
+        self.tlv_index.clear();
         self.tlvs.clear();
     }
 
     pub fn push_tlv(&mut self, tlv: impl Into<MessageSubmissionRequestTlvValue>) {
+        self.tlv_index.clear();
         self.tlvs.push(Tlv::from(tlv.into()));
     }
 
+    /// Returns the message carried in the `message_payload` TLV, if any.
+    pub fn message(&self) -> Option<&[u8]> {
+        self.tlvs.iter().find_map(|tlv| match tlv.value() {
+            Some(TlvValue::MessagePayload(message_payload)) => Some(message_payload.value.bytes()),
+            _ => None,
+        })
+    }
+
+    /// Sets the message in the `message_payload` TLV.
+    ///
+    /// Replaces the existing `message_payload` TLV, if any.
+    pub fn set_message(&mut self, bytes: impl AsRef<[u8]>) {
+        let tlv = Tlv::from(MessageSubmissionRequestTlvValue::MessagePayload(
+            MessagePayload::new(AnyOctetString::new(bytes)),
+        ));
+
+        self.tlv_index.clear();
+
+        match self
+            .tlvs
+            .iter_mut()
+            .find(|tlv| tlv.tag() == TlvTag::MessagePayload)
+        {
+            Some(existing) => *existing = tlv,
+            None => self.tlvs.push(tlv),
+        }
+    }
+
     pub fn builder() -> DataSmBuilder {
         DataSmBuilder::new()
     }
@@ -203,11 +256,42 @@ impl DataSmBuilder {
         self
     }
 
+    pub fn message(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.inner.set_message(bytes);
+        self
+    }
+
     pub fn build(self) -> DataSm {
         self.inner
     }
 }
 
+impl crate::validate::Validate for DataSm {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        use crate::{fields::SmppField, validate::ValidationError};
+
+        crate::validate::validate_ascii_address(SmppField::source_addr, self.source_addr.bytes())?;
+
+        crate::validate::validate_alphanumeric_address(
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr.as_str(),
+        )?;
+
+        ValidationError::non_empty(
+            SmppField::destination_addr,
+            self.destination_addr.is_empty(),
+        )?;
+
+        crate::validate::validate_ascii_address(
+            SmppField::destination_addr,
+            self.destination_addr.bytes(),
+        )?;
+
+        self.tlvs.validate()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -263,4 +347,27 @@ mod tests {
     fn encode_decode() {
         crate::tests::owned::encode_decode_with_length_test_instances::<DataSm>();
     }
+
+    #[test]
+    fn message() {
+        let mut data_sm = DataSm::default();
+
+        assert_eq!(data_sm.message(), None);
+
+        data_sm.push_tlv(MessageSubmissionRequestTlvValue::UserResponseCode(1));
+        data_sm.set_message(b"Hello");
+
+        assert_eq!(data_sm.message(), Some(&b"Hello"[..]));
+        assert_eq!(data_sm.tlvs().len(), 2);
+
+        // Replaces the existing payload
+        data_sm.set_message(b"World");
+
+        assert_eq!(data_sm.message(), Some(&b"World"[..]));
+        assert_eq!(data_sm.tlvs().len(), 2);
+
+        let data_sm = DataSm::builder().message(b"Hello").build();
+
+        assert_eq!(data_sm.message(), Some(&b"Hello"[..]));
+    }
 }
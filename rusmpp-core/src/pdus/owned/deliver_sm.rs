@@ -1,11 +1,14 @@
 use rusmpp_macros::Rusmpp;
 
+use alloc::{string::String, vec::Vec};
+
 use crate::{
     encode::Length,
-    pdus::owned::Pdu,
+    pdus::owned::{Pdu, TlvContainer},
+    text::TextError,
     tlvs::{
         TlvTag,
-        owned::{MessageDeliveryRequestTlvValue, Tlv},
+        owned::{MessageDeliveryRequestTlvValue, Tlv, TlvIndex, TlvValue},
     },
     types::owned::{COctetString, EmptyOrFullCOctetString, OctetString},
     values::{owned::*, *},
@@ -88,10 +91,21 @@ pub struct DeliverSm {
     short_message: OctetString<0, 255>,
     /// Message delivery request TLVs ([`MessageDeliveryRequestTlvValue`])
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(super) tlvs: alloc::vec::Vec<Tlv>,
+    /// Lookup index of the `tlvs`, not encoded.
+    #[rusmpp(skip)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        any(feature = "serde", feature = "serde-deserialize-unchecked"),
+        serde(skip)
+    )]
+    pub(super) tlv_index: TlvIndex,
 }
 
 impl DeliverSm {
+    /// The [`CommandId`](crate::CommandId) of the `deliver_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000005;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         service_type: ServiceType,
@@ -137,6 +151,7 @@ impl DeliverSm {
             sm_length,
             short_message,
             tlvs,
+            tlv_index: TlvIndex::new(),
         };
 
         submit_sm.clear_short_message_if_message_payload_exists();
@@ -167,17 +182,27 @@ impl DeliverSm {
         &self.tlvs
     }
 
+    /// Returns the tags of the `TLV`s that are not allowed in a `deliver_sm`, if any.
+    ///
+    /// Unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) tags are vendor specific and always allowed.
+    pub fn validate_allowed_tlvs(&self) -> Result<(), alloc::vec::Vec<crate::tlvs::TlvTag>> {
+        crate::validate::allowed_tlvs(&self.tlvs, MessageDeliveryRequestTlvValue::TAGS)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<MessageDeliveryRequestTlvValue>>) {
+        self.tlv_index.clear();
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
 
         self.clear_short_message_if_message_payload_exists();
     }
 
     pub fn clear_tlvs(&mut self) {
+        self.tlv_index.clear();
         self.tlvs.clear();
     }
 
     pub fn push_tlv(&mut self, tlv: impl Into<MessageDeliveryRequestTlvValue>) {
+        self.tlv_index.clear();
         self.tlvs.push(Tlv::from(tlv.into()));
 
         self.clear_short_message_if_message_payload_exists();
@@ -185,7 +210,7 @@ impl DeliverSm {
 
     /// Clears the short message and short message length if the message payload is set.
     /// Returns true if the short message and short message length were cleared.
-    fn clear_short_message_if_message_payload_exists(&mut self) -> bool {
+    pub(super) fn clear_short_message_if_message_payload_exists(&mut self) -> bool {
         let message_payload_exists = self
             .tlvs
             .iter()
@@ -206,6 +231,266 @@ impl DeliverSm {
     }
 }
 
+/// `UDH` information element of concatenated messages with an 8-bit reference.
+const IEI_CONCAT_8: u8 = 0x00;
+/// `UDH` information element of concatenated messages with a 16-bit reference.
+const IEI_CONCAT_16: u8 = 0x08;
+
+/// Concatenation info of a part of a long message, see [`DeliverSm::concat_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConcatInfo {
+    /// Reference shared by all parts of the message.
+    ///
+    /// 8-bit references are widened.
+    pub reference: u16,
+    /// Total number of parts.
+    pub total: u8,
+    /// Sequence number of this part, starting at 1.
+    pub sequence: u8,
+}
+
+/// User data header (`UDH`) of a message, see [`DeliverSm::user_data_text`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Udh {
+    bytes: Vec<u8>,
+}
+
+impl Udh {
+    /// Returns the information elements of the `UDH`, without the leading `UDH` length.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the information elements of the `UDH` as `(identifier, data)` pairs.
+    ///
+    /// Stops at a truncated information element.
+    pub fn elements(&self) -> impl Iterator<Item = (u8, &[u8])> {
+        udh_elements(&self.bytes)
+    }
+
+    /// Returns the concatenation info if the `UDH` contains a concatenated messages information element.
+    pub fn concat_info(&self) -> Option<ConcatInfo> {
+        udh_concat_info(&self.bytes)
+    }
+}
+
+/// Splits user data into the information elements of its `UDH` and the remaining payload.
+fn split_udh(bytes: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (&length, rest) = bytes.split_first()?;
+
+    rest.split_at_checked(length as usize)
+}
+
+fn udh_elements(mut header: &[u8]) -> impl Iterator<Item = (u8, &[u8])> {
+    core::iter::from_fn(move || {
+        let [iei, length, rest @ ..] = header else {
+            return None;
+        };
+
+        let (data, next) = rest.split_at_checked(*length as usize)?;
+
+        header = next;
+
+        Some((*iei, data))
+    })
+}
+
+fn udh_concat_info(header: &[u8]) -> Option<ConcatInfo> {
+    udh_elements(header).find_map(|(iei, data)| {
+        let (reference, total, sequence) = match (iei, data) {
+            (IEI_CONCAT_8, &[reference, total, sequence]) => (reference as u16, total, sequence),
+            (IEI_CONCAT_16, &[high, low, total, sequence]) => {
+                (u16::from_be_bytes([high, low]), total, sequence)
+            }
+            _ => return None,
+        };
+
+        Some(ConcatInfo {
+            reference,
+            total,
+            sequence,
+        })
+    })
+}
+
+impl DeliverSm {
+    /// Returns the concatenation info if this is a part of a long message.
+    ///
+    /// The info is read from the user data header (`UDH`) if the `UDHI` is set in the `esm_class`,
+    /// from the `sar_msg_ref_num`, `sar_total_segments` and `sar_segment_seqnum` `TLV`s otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     pdus::owned::{ConcatInfo, DeliverSm},
+    /// #     tlvs::owned::MessageDeliveryRequestTlvValue,
+    /// # };
+    /// let deliver_sm = DeliverSm::builder()
+    ///     .push_tlv(MessageDeliveryRequestTlvValue::SarMsgRefNum(42))
+    ///     .push_tlv(MessageDeliveryRequestTlvValue::SarTotalSegments(3))
+    ///     .push_tlv(MessageDeliveryRequestTlvValue::SarSegmentSeqnum(1))
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     deliver_sm.concat_info(),
+    ///     Some(ConcatInfo {
+    ///         reference: 42,
+    ///         total: 3,
+    ///         sequence: 1
+    ///     })
+    /// );
+    /// ```
+    pub fn concat_info(&self) -> Option<ConcatInfo> {
+        self.concat_part().map(|(info, _)| info)
+    }
+
+    /// Returns the `UDH` and the decoded text of the user data.
+    ///
+    /// The user data is the `message_payload` if present, the `short_message` otherwise.
+    /// If the `UDHI` is set in the `esm_class`, the user data starts with a `UDH`, which is stripped before
+    /// decoding the remaining bytes with `data_coding`, see [`decode_text`](crate::text::decode_text).
+    ///
+    /// Fails with [`TextError::Malformed`] if the `UDH` exceeds the user data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     pdus::owned::{ConcatInfo, DeliverSm},
+    /// #     types::owned::OctetString,
+    /// #     values::{DataCoding, EsmClass, GsmFeatures},
+    /// # };
+    /// let deliver_sm = DeliverSm::builder()
+    ///     .esm_class(EsmClass {
+    ///         gsm_features: GsmFeatures::UdhiIndicator,
+    ///         ..Default::default()
+    ///     })
+    ///     .data_coding(DataCoding::Ucs2)
+    ///     // Concatenated message, part 1 of 2, followed by "Hi"
+    ///     .short_message(OctetString::new(b"\x05\x00\x03\x2A\x02\x01\x00H\x00i").unwrap())
+    ///     .build();
+    ///
+    /// let (udh, text) = deliver_sm.user_data_text(DataCoding::Ucs2).unwrap();
+    ///
+    /// assert_eq!(text, "Hi");
+    /// assert_eq!(
+    ///     udh.unwrap().concat_info(),
+    ///     Some(ConcatInfo {
+    ///         reference: 0x2A,
+    ///         total: 2,
+    ///         sequence: 1
+    ///     })
+    /// );
+    /// ```
+    pub fn user_data_text(
+        &self,
+        data_coding: DataCoding,
+    ) -> Result<(Option<Udh>, String), TextError> {
+        let bytes = self.message_bytes();
+
+        if !self.has_udh() {
+            return Ok((None, crate::text::decode_text(bytes, data_coding)?));
+        }
+
+        let (header, payload) = split_udh(bytes).ok_or(TextError::Malformed)?;
+
+        let udh = Udh {
+            bytes: header.to_vec(),
+        };
+
+        Ok((Some(udh), crate::text::decode_text(payload, data_coding)?))
+    }
+
+    /// Returns the `short_message` followed by the `message_payload`.
+    ///
+    /// The `short_message` must be empty if the `message_payload` is present, yet some MCs split the content across both.
+    /// Unlike [`DeliverSm::user_data_text`], which reads the `message_payload` only if present, this lenient accessor keeps both.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     pdus::owned::DeliverSm,
+    /// #     tlvs::owned::MessageDeliveryRequestTlvValue,
+    /// #     types::owned::{AnyOctetString, OctetString},
+    /// #     values::owned::MessagePayload,
+    /// # };
+    /// let deliver_sm = DeliverSm::builder()
+    ///     .push_tlv(MessageDeliveryRequestTlvValue::MessagePayload(
+    ///         MessagePayload::new(AnyOctetString::new(b"Hello")),
+    ///     ))
+    ///     .build();
+    ///
+    /// assert_eq!(deliver_sm.full_message_bytes(), b"Hello");
+    /// ```
+    pub fn full_message_bytes(&self) -> Vec<u8> {
+        let payload = self
+            .get_tlv(TlvTag::MessagePayload)
+            .and_then(|tlv| match tlv.value() {
+                Some(TlvValue::MessagePayload(payload)) => Some(payload.value.bytes()),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        [self.short_message.bytes(), payload].concat()
+    }
+
+    /// Returns `true` if the `UDHI` is set in the `esm_class`.
+    fn has_udh(&self) -> bool {
+        matches!(
+            self.esm_class.gsm_features,
+            GsmFeatures::UdhiIndicator | GsmFeatures::SetUdhiAndReplyPath
+        )
+    }
+
+    /// Returns the `message_payload` if present, the `short_message` otherwise.
+    pub(crate) fn message_bytes(&self) -> &[u8] {
+        self.get_tlv(TlvTag::MessagePayload)
+            .and_then(|tlv| match tlv.value() {
+                Some(TlvValue::MessagePayload(payload)) => Some(payload.value.bytes()),
+                _ => None,
+            })
+            .unwrap_or(self.short_message.bytes())
+    }
+
+    /// Returns the concatenation info and the user data without the `UDH`.
+    pub(crate) fn concat_part(&self) -> Option<(ConcatInfo, &[u8])> {
+        let bytes = self.message_bytes();
+
+        if self.has_udh() {
+            let (header, payload) = split_udh(bytes)?;
+
+            if let Some(info) = udh_concat_info(header) {
+                return Some((info, payload));
+            }
+        }
+
+        let value = |tag| self.get_tlv(tag).and_then(|tlv| tlv.value());
+
+        match (
+            value(TlvTag::SarMsgRefNum),
+            value(TlvTag::SarTotalSegments),
+            value(TlvTag::SarSegmentSeqnum),
+        ) {
+            (
+                Some(TlvValue::SarMsgRefNum(reference)),
+                Some(TlvValue::SarTotalSegments(total)),
+                Some(TlvValue::SarSegmentSeqnum(sequence)),
+            ) => {
+                let info = ConcatInfo {
+                    reference: *reference,
+                    total: *total,
+                    sequence: *sequence,
+                };
+
+                Some((info, bytes))
+            }
+            _ => None,
+        }
+    }
+}
+
 impl From<DeliverSm> for Pdu {
     fn from(value: DeliverSm) -> Self {
         Self::DeliverSm(value)
@@ -336,6 +621,35 @@ impl DeliverSmBuilder {
     }
 }
 
+impl crate::validate::Validate for DeliverSm {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        use crate::{
+            fields::SmppField,
+            validate::{ValidationError, validate_short_message},
+        };
+
+        crate::validate::validate_ascii_address(SmppField::source_addr, self.source_addr.bytes())?;
+
+        crate::validate::validate_alphanumeric_address(
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr.as_str(),
+        )?;
+
+        ValidationError::non_empty(
+            SmppField::destination_addr,
+            self.destination_addr.is_empty(),
+        )?;
+
+        crate::validate::validate_ascii_address(
+            SmppField::destination_addr,
+            self.destination_addr.bytes(),
+        )?;
+
+        validate_short_message(self.sm_length, self.short_message.bytes(), &self.tlvs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -490,4 +804,180 @@ mod tests {
         assert_eq!(submit_sm.short_message(), &short_message);
         assert_eq!(submit_sm.sm_length(), short_message.length() as u8);
     }
+
+    #[test]
+    fn concat_info_udh() {
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass {
+                gsm_features: GsmFeatures::UdhiIndicator,
+                ..Default::default()
+            })
+            // Application port addressing followed by the 16-bit reference concatenation
+            .short_message(
+                OctetString::new(b"\x0C\x05\x04\x0B\x84\x23\xF0\x08\x04\x12\x34\x02\x01Hi")
+                    .unwrap(),
+            )
+            .build();
+
+        let (info, payload) = deliver_sm.concat_part().unwrap();
+
+        assert_eq!(
+            info,
+            ConcatInfo {
+                reference: 0x1234,
+                total: 2,
+                sequence: 1
+            }
+        );
+        assert_eq!(payload, b"Hi");
+
+        // Without the UDHI the UDH is regular user data
+        let deliver_sm = DeliverSm::builder()
+            .short_message(OctetString::new(b"\x05\x00\x03\x07\x02\x01Hi").unwrap())
+            .build();
+
+        assert_eq!(deliver_sm.concat_info(), None);
+
+        // Truncated UDH
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass {
+                gsm_features: GsmFeatures::UdhiIndicator,
+                ..Default::default()
+            })
+            .short_message(OctetString::new(b"\x05\x00\x03\x07").unwrap())
+            .build();
+
+        assert_eq!(deliver_sm.concat_info(), None);
+    }
+
+    #[test]
+    fn user_data_text() {
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass {
+                gsm_features: GsmFeatures::UdhiIndicator,
+                ..Default::default()
+            })
+            .data_coding(DataCoding::Ucs2)
+            // 16-bit reference concatenation followed by "Grüße" in UCS-2
+            .short_message(
+                OctetString::new(b"\x06\x08\x04\x12\x34\x03\x02\x00G\x00r\x00\xFC\x00\xDF\x00e")
+                    .unwrap(),
+            )
+            .build();
+
+        let (udh, text) = deliver_sm.user_data_text(DataCoding::Ucs2).unwrap();
+        let udh = udh.unwrap();
+
+        assert_eq!(text, "Grüße");
+        assert_eq!(udh.bytes(), [0x08, 0x04, 0x12, 0x34, 0x03, 0x02]);
+        assert_eq!(
+            udh.elements().collect::<Vec<_>>(),
+            [(0x08, &[0x12, 0x34, 0x03, 0x02][..])]
+        );
+        assert_eq!(
+            udh.concat_info(),
+            Some(ConcatInfo {
+                reference: 0x1234,
+                total: 3,
+                sequence: 2
+            })
+        );
+
+        // Without the UDHI all bytes are text
+        let deliver_sm = DeliverSm::builder()
+            .short_message(OctetString::new(b"Hi").unwrap())
+            .build();
+
+        assert_eq!(
+            deliver_sm.user_data_text(DataCoding::Ia5).unwrap(),
+            (None, String::from("Hi"))
+        );
+
+        // The UDH exceeds the user data
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass {
+                gsm_features: GsmFeatures::UdhiIndicator,
+                ..Default::default()
+            })
+            .short_message(OctetString::new(b"\x05\x00\x03").unwrap())
+            .build();
+
+        assert_eq!(
+            deliver_sm.user_data_text(DataCoding::Ucs2),
+            Err(TextError::Malformed)
+        );
+
+        // The payload is not valid UCS-2
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass {
+                gsm_features: GsmFeatures::UdhiIndicator,
+                ..Default::default()
+            })
+            .short_message(OctetString::new(b"\x00\x00").unwrap())
+            .build();
+
+        assert_eq!(
+            deliver_sm.user_data_text(DataCoding::Ucs2),
+            Err(TextError::Malformed)
+        );
+    }
+
+    #[test]
+    fn full_message_bytes() {
+        use crate::pdus::owned::PduBody;
+
+        let mut body = DeliverSm::builder()
+            .short_message(OctetString::new(b"Hello, ").unwrap())
+            .build()
+            .encode_body();
+
+        body.extend_from_slice(b"\x04\x24\x00\x06World!");
+
+        let deliver_sm = DeliverSm::decode_body(&body).unwrap();
+
+        assert_eq!(deliver_sm.short_message().bytes(), b"Hello, ");
+        assert_eq!(deliver_sm.full_message_bytes(), b"Hello, World!");
+
+        // The message_payload alone is read by the standard accessors
+        assert_eq!(
+            deliver_sm.user_data_text(DataCoding::Ia5).unwrap().1,
+            "World!"
+        );
+
+        let deliver_sm = DeliverSm::builder()
+            .short_message(OctetString::new(b"Hello").unwrap())
+            .build();
+
+        assert_eq!(deliver_sm.full_message_bytes(), b"Hello");
+        assert!(DeliverSm::default().full_message_bytes().is_empty());
+    }
+
+    #[test]
+    fn concat_info_sar() {
+        let deliver_sm = DeliverSm::builder()
+            .short_message(OctetString::new(b"Hi").unwrap())
+            .push_tlv(MessageDeliveryRequestTlvValue::SarMsgRefNum(0xABCD))
+            .push_tlv(MessageDeliveryRequestTlvValue::SarTotalSegments(3))
+            .push_tlv(MessageDeliveryRequestTlvValue::SarSegmentSeqnum(2))
+            .build();
+
+        let (info, payload) = deliver_sm.concat_part().unwrap();
+
+        assert_eq!(
+            info,
+            ConcatInfo {
+                reference: 0xABCD,
+                total: 3,
+                sequence: 2
+            }
+        );
+        assert_eq!(payload, b"Hi");
+
+        let deliver_sm = DeliverSm::builder()
+            .push_tlv(MessageDeliveryRequestTlvValue::SarMsgRefNum(0xABCD))
+            .push_tlv(MessageDeliveryRequestTlvValue::SarSegmentSeqnum(2))
+            .build();
+
+        assert_eq!(deliver_sm.concat_info(), None);
+    }
 }
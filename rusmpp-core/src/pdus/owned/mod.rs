@@ -3,6 +3,20 @@
 mod pdu;
 pub use pdu::Pdu;
 
+mod body;
+pub use body::PduBody;
+
+mod address;
+
+mod sm_default_msg_id;
+
+mod smpp_time;
+
+mod tlv_accessors;
+
+mod tlv_container;
+pub use tlv_container::TlvContainer;
+
 pub mod builders {
     pub use super::alert_notification::AlertNotificationBuilder;
     pub use super::bind::{BindReceiverBuilder, BindTransceiverBuilder, BindTransmitterBuilder};
@@ -69,7 +83,7 @@ mod data_sm;
 pub use data_sm::DataSm;
 
 mod deliver_sm;
-pub use deliver_sm::DeliverSm;
+pub use deliver_sm::{ConcatInfo, DeliverSm, Udh};
 
 mod outbind;
 pub use outbind::Outbind;
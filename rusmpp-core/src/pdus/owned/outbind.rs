@@ -26,6 +26,9 @@ pub struct Outbind {
 }
 
 impl Outbind {
+    /// The [`CommandId`](crate::CommandId) of the `outbind` PDU.
+    pub const COMMAND_ID: u32 = 0x0000000B;
+
     pub fn new(system_id: COctetString<1, 16>, password: COctetString<1, 9>) -> Self {
         Self {
             system_id,
@@ -69,6 +72,14 @@ impl OutbindBuilder {
     }
 }
 
+impl crate::validate::Validate for Outbind {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        use crate::{fields::SmppField, validate::ValidationError};
+
+        ValidationError::non_empty(SmppField::system_id, self.system_id.is_empty())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -1,8 +1,8 @@
 use crate::{
     CommandId,
     decode::{
-        DecodeError, DecodeResultExt,
-        owned::{Decode, DecodeWithKeyOptional, DecodeWithLength},
+        DecodeContext, DecodeError, DecodeResultExt,
+        owned::{Decode, DecodeWithContext, DecodeWithKeyOptional, DecodeWithLength},
     },
     encode::{Encode, Length},
     types::owned::AnyOctetString,
@@ -177,12 +177,203 @@ pub enum Pdu {
     /// attempt and for successful attempts will also include the
     /// current state of the message.
     CancelBroadcastSmResp,
+    /// Body of a command with an unknown `command_id`, kept as opaque bytes.
+    ///
+    /// Re-encodes byte-for-byte, so proxies forward unknown commands transparently.
     Other {
         command_id: CommandId,
         body: AnyOctetString,
     },
 }
 
+impl Pdu {
+    /// Returns the `TLV` list of the `PDU`, if any.
+    pub(crate) fn tlvs(&self) -> &[crate::tlvs::owned::Tlv] {
+        match self {
+            Pdu::SubmitSm(body) => body.tlvs(),
+            Pdu::SubmitSmResp(body) => body.tlvs(),
+            Pdu::DeliverSm(body) => body.tlvs(),
+            Pdu::DeliverSmResp(body) => body.tlvs(),
+            Pdu::DataSm(body) => body.tlvs(),
+            Pdu::DataSmResp(body) => body.tlvs(),
+            Pdu::SubmitMulti(body) => body.tlvs(),
+            Pdu::SubmitMultiResp(body) => body.tlvs(),
+            Pdu::BroadcastSm(body) => body.tlvs(),
+            Pdu::BroadcastSmResp(body) => body.tlvs(),
+            Pdu::QueryBroadcastSmResp(body) => body.tlvs(),
+            Pdu::CancelBroadcastSm(body) => body.tlvs(),
+            _ => &[],
+        }
+    }
+
+    /// Returns all `TLV`s of the `PDU`, including the single optional `TLV` of some `PDU`s.
+    pub(crate) fn all_tlvs(&self) -> impl Iterator<Item = &crate::tlvs::owned::Tlv> {
+        let tlv = match self {
+            Pdu::BindTransmitterResp(body) => body.sc_interface_version_tlv(),
+            Pdu::BindReceiverResp(body) => body.sc_interface_version_tlv(),
+            Pdu::BindTransceiverResp(body) => body.sc_interface_version_tlv(),
+            Pdu::AlertNotification(body) => body.ms_availability_status_tlv(),
+            Pdu::QueryBroadcastSm(body) => body.user_message_reference_tlv(),
+            Pdu::ReplaceSm(body) => body.message_payload_tlv(),
+            _ => None,
+        };
+
+        self.tlvs().iter().chain(tlv)
+    }
+
+    /// Returns the encoded length of all `TLV`s of the `PDU`.
+    ///
+    /// The `TLV`s are always encoded at the end of the body.
+    pub(crate) fn tlvs_length(&self) -> usize {
+        self.all_tlvs().map(Length::length).sum()
+    }
+}
+
+impl Pdu {
+    /// Takes the `TLV` list of the `PDU`, if any.
+    pub(crate) fn into_tlvs(self) -> alloc::vec::Vec<crate::tlvs::owned::Tlv> {
+        match self {
+            Pdu::SubmitSm(body) => body.into_parts().tlvs,
+            Pdu::SubmitSmResp(body) => body.into_parts().tlvs,
+            Pdu::DeliverSm(body) => body.into_parts().tlvs,
+            Pdu::DeliverSmResp(body) => body.into_parts().tlvs,
+            Pdu::DataSm(body) => body.into_parts().tlvs,
+            Pdu::DataSmResp(body) => body.into_parts().tlvs,
+            Pdu::SubmitMulti(body) => body.into_parts().tlvs,
+            Pdu::SubmitMultiResp(body) => body.into_parts().tlvs,
+            Pdu::BroadcastSm(body) => body.into_parts().tlvs,
+            Pdu::BroadcastSmResp(body) => body.into_parts().tlvs,
+            Pdu::QueryBroadcastSmResp(body) => body.into_parts().tlvs,
+            Pdu::CancelBroadcastSm(body) => body.into_parts().tlvs,
+            _ => alloc::vec::Vec::new(),
+        }
+    }
+}
+
+impl crate::encode::EncodeFields for Pdu {
+    fn field(&self, name: &str) -> Option<&dyn Encode> {
+        match self {
+            Pdu::BindTransmitter(body) => body.field(name),
+            Pdu::BindTransmitterResp(body) => body.field(name),
+            Pdu::BindReceiver(body) => body.field(name),
+            Pdu::BindReceiverResp(body) => body.field(name),
+            Pdu::BindTransceiver(body) => body.field(name),
+            Pdu::BindTransceiverResp(body) => body.field(name),
+            Pdu::Outbind(body) => body.field(name),
+            Pdu::AlertNotification(body) => body.field(name),
+            Pdu::SubmitSm(body) => body.field(name),
+            Pdu::SubmitSmResp(body) => body.field(name),
+            Pdu::QuerySm(body) => body.field(name),
+            Pdu::QuerySmResp(body) => body.field(name),
+            Pdu::DeliverSm(body) => body.field(name),
+            Pdu::DeliverSmResp(body) => body.field(name),
+            Pdu::DataSm(body) => body.field(name),
+            Pdu::DataSmResp(body) => body.field(name),
+            Pdu::CancelSm(body) => body.field(name),
+            Pdu::ReplaceSm(body) => body.field(name),
+            Pdu::SubmitMulti(body) => body.field(name),
+            Pdu::SubmitMultiResp(body) => body.field(name),
+            Pdu::BroadcastSm(body) => body.field(name),
+            Pdu::BroadcastSmResp(body) => body.field(name),
+            Pdu::QueryBroadcastSm(body) => body.field(name),
+            Pdu::QueryBroadcastSmResp(body) => body.field(name),
+            Pdu::CancelBroadcastSm(body) => body.field(name),
+            Pdu::Other { body, .. } => match name {
+                "body" => Some(body),
+                _ => None,
+            },
+            Pdu::Unbind
+            | Pdu::UnbindResp
+            | Pdu::EnquireLink
+            | Pdu::EnquireLinkResp
+            | Pdu::GenericNack
+            | Pdu::CancelSmResp
+            | Pdu::ReplaceSmResp
+            | Pdu::CancelBroadcastSmResp => None,
+        }
+    }
+
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        match self {
+            Pdu::BindTransmitter(body) => body.mandatory_fields(),
+            Pdu::BindTransmitterResp(body) => body.mandatory_fields(),
+            Pdu::BindReceiver(body) => body.mandatory_fields(),
+            Pdu::BindReceiverResp(body) => body.mandatory_fields(),
+            Pdu::BindTransceiver(body) => body.mandatory_fields(),
+            Pdu::BindTransceiverResp(body) => body.mandatory_fields(),
+            Pdu::Outbind(body) => body.mandatory_fields(),
+            Pdu::AlertNotification(body) => body.mandatory_fields(),
+            Pdu::SubmitSm(body) => body.mandatory_fields(),
+            Pdu::SubmitSmResp(body) => body.mandatory_fields(),
+            Pdu::QuerySm(body) => body.mandatory_fields(),
+            Pdu::QuerySmResp(body) => body.mandatory_fields(),
+            Pdu::DeliverSm(body) => body.mandatory_fields(),
+            Pdu::DeliverSmResp(body) => body.mandatory_fields(),
+            Pdu::DataSm(body) => body.mandatory_fields(),
+            Pdu::DataSmResp(body) => body.mandatory_fields(),
+            Pdu::CancelSm(body) => body.mandatory_fields(),
+            Pdu::ReplaceSm(body) => body.mandatory_fields(),
+            Pdu::SubmitMulti(body) => body.mandatory_fields(),
+            Pdu::SubmitMultiResp(body) => body.mandatory_fields(),
+            Pdu::BroadcastSm(body) => body.mandatory_fields(),
+            Pdu::BroadcastSmResp(body) => body.mandatory_fields(),
+            Pdu::QueryBroadcastSm(body) => body.mandatory_fields(),
+            Pdu::QueryBroadcastSmResp(body) => body.mandatory_fields(),
+            Pdu::CancelBroadcastSm(body) => body.mandatory_fields(),
+            Pdu::Other { .. } => &["body"],
+            Pdu::Unbind
+            | Pdu::UnbindResp
+            | Pdu::EnquireLink
+            | Pdu::EnquireLinkResp
+            | Pdu::GenericNack
+            | Pdu::CancelSmResp
+            | Pdu::ReplaceSmResp
+            | Pdu::CancelBroadcastSmResp => &[],
+        }
+    }
+}
+
+impl crate::validate::Validate for Pdu {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        match self {
+            Pdu::BindTransmitter(body) => body.validate(),
+            Pdu::BindTransmitterResp(body) => body.validate(),
+            Pdu::BindReceiver(body) => body.validate(),
+            Pdu::BindReceiverResp(body) => body.validate(),
+            Pdu::BindTransceiver(body) => body.validate(),
+            Pdu::BindTransceiverResp(body) => body.validate(),
+            Pdu::Outbind(body) => body.validate(),
+            Pdu::AlertNotification(body) => body.validate(),
+            Pdu::SubmitSm(body) => body.validate(),
+            Pdu::SubmitSmResp(body) => body.tlvs().validate(),
+            Pdu::QuerySm(body) => body.validate(),
+            Pdu::DeliverSm(body) => body.validate(),
+            Pdu::DeliverSmResp(body) => body.tlvs().validate(),
+            Pdu::DataSm(body) => body.validate(),
+            Pdu::DataSmResp(body) => body.tlvs().validate(),
+            Pdu::ReplaceSm(body) => body.validate(),
+            Pdu::SubmitMulti(body) => body.validate(),
+            Pdu::SubmitMultiResp(body) => body.tlvs().validate(),
+            Pdu::BroadcastSm(body) => body.tlvs().validate(),
+            Pdu::BroadcastSmResp(body) => body.tlvs().validate(),
+            Pdu::QueryBroadcastSm(body) => body.validate(),
+            Pdu::QueryBroadcastSmResp(body) => body.tlvs().validate(),
+            Pdu::CancelBroadcastSm(body) => body.tlvs().validate(),
+            Pdu::QuerySmResp(_)
+            | Pdu::CancelSm(_)
+            | Pdu::Unbind
+            | Pdu::UnbindResp
+            | Pdu::EnquireLink
+            | Pdu::EnquireLinkResp
+            | Pdu::GenericNack
+            | Pdu::CancelSmResp
+            | Pdu::ReplaceSmResp
+            | Pdu::CancelBroadcastSmResp
+            | Pdu::Other { .. } => Ok(()),
+        }
+    }
+}
+
 impl Pdu {
     pub const fn command_id(&self) -> CommandId {
         match self {
@@ -224,16 +415,56 @@ impl Pdu {
             Pdu::CancelBroadcastSmResp => CommandId::CancelBroadcastSmResp,
         }
     }
+
+    /// Writes the encoded [`Pdu`] into `w`.
+    ///
+    /// [`Pdu`]s that may carry a `message_payload` stream their [`Tlv`](crate::tlvs::owned::Tlv)s,
+    /// the rest are small enough to be encoded into an intermediate buffer.
+    #[cfg(feature = "std")]
+    pub(crate) fn encode_to_writer<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        match self {
+            Pdu::SubmitSm(body) => write_streaming_tlvs(body, &body.tlvs, w),
+            Pdu::DeliverSm(body) => write_streaming_tlvs(body, &body.tlvs, w),
+            Pdu::DataSm(body) => write_streaming_tlvs(body, &body.tlvs, w),
+            Pdu::SubmitMulti(body) => write_streaming_tlvs(body, &body.tlvs, w),
+            _ => crate::encode::write_encoded(self, w),
+        }
+    }
+}
+
+/// Writes the encoded mandatory fields of `body` into `w`, followed by its streamed `tlvs`.
+///
+/// The field order is the one of the `Rusmpp` derive, see [`EncodeFields::encode_mandatory_fields`](crate::encode::EncodeFields::encode_mandatory_fields).
+#[cfg(feature = "std")]
+fn write_streaming_tlvs<T: crate::encode::EncodeFields + Length, W: std::io::Write>(
+    body: &T,
+    tlvs: &[crate::tlvs::owned::Tlv],
+    w: &mut W,
+) -> std::io::Result<()> {
+    let mut buf = alloc::vec![0; body.length() - tlvs.length()];
+
+    let size = body.encode_mandatory_fields(&mut buf);
+
+    w.write_all(&buf[..size])?;
+
+    for tlv in tlvs {
+        tlv.encode_to_writer(w)?;
+    }
+
+    Ok(())
 }
 
 impl Length for Pdu {
     fn length(&self) -> usize {
         match self {
             Pdu::BindTransmitter(body) => body.length(),
+            Pdu::BindTransmitterResp(body) if body.is_header_only() => 0,
             Pdu::BindTransmitterResp(body) => body.length(),
             Pdu::BindReceiver(body) => body.length(),
+            Pdu::BindReceiverResp(body) if body.is_header_only() => 0,
             Pdu::BindReceiverResp(body) => body.length(),
             Pdu::BindTransceiver(body) => body.length(),
+            Pdu::BindTransceiverResp(body) if body.is_header_only() => 0,
             Pdu::BindTransceiverResp(body) => body.length(),
             Pdu::Outbind(body) => body.length(),
             Pdu::AlertNotification(body) => body.length(),
@@ -271,10 +502,13 @@ impl Encode for Pdu {
     fn encode(&self, dst: &mut [u8]) -> usize {
         match self {
             Pdu::BindTransmitter(body) => body.encode(dst),
+            Pdu::BindTransmitterResp(body) if body.is_header_only() => 0,
             Pdu::BindTransmitterResp(body) => body.encode(dst),
             Pdu::BindReceiver(body) => body.encode(dst),
+            Pdu::BindReceiverResp(body) if body.is_header_only() => 0,
             Pdu::BindReceiverResp(body) => body.encode(dst),
             Pdu::BindTransceiver(body) => body.encode(dst),
+            Pdu::BindTransceiverResp(body) if body.is_header_only() => 0,
             Pdu::BindTransceiverResp(body) => body.encode(dst),
             Pdu::Outbind(body) => body.encode(dst),
             Pdu::AlertNotification(body) => body.encode(dst),
@@ -315,6 +549,38 @@ impl DecodeWithKeyOptional for Pdu {
         key: Self::Key,
         src: &[u8],
         length: usize,
+    ) -> Result<Option<(Self, usize)>, DecodeError> {
+        Self::decode_with_context(key, src, length, &mut DecodeContext::default())
+    }
+}
+
+impl Pdu {
+    /// Returns the body of a successful bind_resp with the id `key` received without a body.
+    ///
+    /// Some v3.3 MCs omit the `system_id` of a bind_resp entirely.
+    pub(crate) fn header_only_bind_resp(key: CommandId) -> Option<Self> {
+        match key {
+            CommandId::BindTransmitterResp => {
+                Some(Pdu::BindTransmitterResp(BindTransmitterResp::header_only()))
+            }
+            CommandId::BindReceiverResp => {
+                Some(Pdu::BindReceiverResp(BindReceiverResp::header_only()))
+            }
+            CommandId::BindTransceiverResp => {
+                Some(Pdu::BindTransceiverResp(BindTransceiverResp::header_only()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Decodes the body of the command with the id `key`.
+    ///
+    /// Rejects duplicate `TLV`s and `TLV` length mismatches according to the options of the `context`.
+    pub(crate) fn decode_with_context(
+        key: CommandId,
+        src: &[u8],
+        length: usize,
+        context: &mut DecodeContext,
     ) -> Result<Option<(Self, usize)>, DecodeError> {
         if length == 0 {
             let body = match key {
@@ -335,60 +601,66 @@ impl DecodeWithKeyOptional for Pdu {
         let (body, size) = match key {
             CommandId::BindTransmitter => Decode::decode(src).map_decoded(Self::BindTransmitter)?,
             CommandId::BindTransmitterResp => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::BindTransmitterResp)?
+                DecodeWithContext::decode_with_context(src, length, context)
+                    .map_decoded(Self::BindTransmitterResp)?
             }
             CommandId::BindReceiver => Decode::decode(src).map_decoded(Self::BindReceiver)?,
             CommandId::BindReceiverResp => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::BindReceiverResp)?
+                DecodeWithContext::decode_with_context(src, length, context)
+                    .map_decoded(Self::BindReceiverResp)?
             }
             CommandId::BindTransceiver => Decode::decode(src).map_decoded(Self::BindTransceiver)?,
             CommandId::BindTransceiverResp => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::BindTransceiverResp)?
+                DecodeWithContext::decode_with_context(src, length, context)
+                    .map_decoded(Self::BindTransceiverResp)?
             }
             CommandId::Outbind => Decode::decode(src).map_decoded(Self::Outbind)?,
             CommandId::AlertNotification => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::AlertNotification)?
-            }
-            CommandId::SubmitSm => SubmitSm::decode(src, length).map_decoded(Self::SubmitSm)?,
-            CommandId::SubmitSmResp => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::SubmitSmResp)?
+                DecodeWithContext::decode_with_context(src, length, context)
+                    .map_decoded(Self::AlertNotification)?
             }
+            CommandId::SubmitSm => DecodeWithContext::decode_with_context(src, length, context)
+                .map_decoded(Self::SubmitSm)?,
+            CommandId::SubmitSmResp => DecodeWithContext::decode_with_context(src, length, context)
+                .map_decoded(Self::SubmitSmResp)?,
             CommandId::QuerySm => Decode::decode(src).map_decoded(Self::QuerySm)?,
             CommandId::QuerySmResp => Decode::decode(src).map_decoded(Self::QuerySmResp)?,
-            CommandId::DeliverSm => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::DeliverSm)?
-            }
+            CommandId::DeliverSm => DecodeWithContext::decode_with_context(src, length, context)
+                .map_decoded(Self::DeliverSm)?,
             CommandId::DeliverSmResp => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::DeliverSmResp)?
-            }
-            CommandId::DataSm => DecodeWithLength::decode(src, length).map_decoded(Self::DataSm)?,
-            CommandId::DataSmResp => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::DataSmResp)?
+                DecodeWithContext::decode_with_context(src, length, context)
+                    .map_decoded(Self::DeliverSmResp)?
             }
+            CommandId::DataSm => DecodeWithContext::decode_with_context(src, length, context)
+                .map_decoded(Self::DataSm)?,
+            CommandId::DataSmResp => DecodeWithContext::decode_with_context(src, length, context)
+                .map_decoded(Self::DataSmResp)?,
             CommandId::CancelSm => Decode::decode(src).map_decoded(Self::CancelSm)?,
-            CommandId::ReplaceSm => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::ReplaceSm)?
-            }
-            CommandId::SubmitMulti => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::SubmitMulti)?
-            }
+            CommandId::ReplaceSm => DecodeWithContext::decode_with_context(src, length, context)
+                .map_decoded(Self::ReplaceSm)?,
+            CommandId::SubmitMulti => DecodeWithContext::decode_with_context(src, length, context)
+                .map_decoded(Self::SubmitMulti)?,
             CommandId::SubmitMultiResp => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::SubmitMultiResp)?
-            }
-            CommandId::BroadcastSm => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::BroadcastSm)?
+                DecodeWithContext::decode_with_context(src, length, context)
+                    .map_decoded(Self::SubmitMultiResp)?
             }
+            CommandId::BroadcastSm => DecodeWithContext::decode_with_context(src, length, context)
+                .map_decoded(Self::BroadcastSm)?,
             CommandId::BroadcastSmResp => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::BroadcastSmResp)?
+                DecodeWithContext::decode_with_context(src, length, context)
+                    .map_decoded(Self::BroadcastSmResp)?
             }
             CommandId::QueryBroadcastSm => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::QueryBroadcastSm)?
+                DecodeWithContext::decode_with_context(src, length, context)
+                    .map_decoded(Self::QueryBroadcastSm)?
             }
             CommandId::QueryBroadcastSmResp => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::QueryBroadcastSmResp)?
+                DecodeWithContext::decode_with_context(src, length, context)
+                    .map_decoded(Self::QueryBroadcastSmResp)?
             }
             CommandId::CancelBroadcastSm => {
-                DecodeWithLength::decode(src, length).map_decoded(Self::CancelBroadcastSm)?
+                DecodeWithContext::decode_with_context(src, length, context)
+                    .map_decoded(Self::CancelBroadcastSm)?
             }
             CommandId::Other(_) => {
                 DecodeWithLength::decode(src, length).map_decoded(|body| Pdu::Other {
@@ -410,3 +682,113 @@ impl DecodeWithKeyOptional for Pdu {
         Ok(Some((body, size)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_id_consts_match_spec() {
+        assert_eq!(BindTransmitter::COMMAND_ID, 0x00000002);
+        assert_eq!(SubmitSm::COMMAND_ID, 0x00000004);
+        assert_eq!(SubmitSmResp::COMMAND_ID, 0x80000004);
+        assert_eq!(DeliverSm::COMMAND_ID, 0x00000005);
+        assert_eq!(DataSmResp::COMMAND_ID, 0x80000103);
+        assert_eq!(CancelBroadcastSm::COMMAND_ID, 0x00000113);
+    }
+
+    #[test]
+    fn command_id_consts_match_pdu() {
+        macro_rules! assert_command_id {
+            ($($pdu:ident),* $(,)?) => {
+                $(
+                    assert_eq!(
+                        $pdu::COMMAND_ID,
+                        u32::from(Pdu::from($pdu::default()).command_id()),
+                        stringify!($pdu)
+                    );
+                )*
+            };
+        }
+
+        assert_command_id!(
+            BindTransmitter,
+            BindTransmitterResp,
+            BindReceiver,
+            BindReceiverResp,
+            BindTransceiver,
+            BindTransceiverResp,
+            Outbind,
+            AlertNotification,
+            SubmitSm,
+            SubmitSmResp,
+            QuerySm,
+            QuerySmResp,
+            DeliverSm,
+            DeliverSmResp,
+            DataSm,
+            DataSmResp,
+            CancelSm,
+            ReplaceSm,
+            SubmitMulti,
+            SubmitMultiResp,
+            BroadcastSm,
+            BroadcastSmResp,
+            QueryBroadcastSm,
+            QueryBroadcastSmResp,
+            CancelBroadcastSm,
+        );
+    }
+
+    #[test]
+    fn header_only_bind_resp() {
+        use crate::{
+            CommandStatus,
+            command::owned::{Command, encode_many},
+        };
+
+        // bind_transceiver_resp without a body
+        let bytes = [
+            0x00, 0x00, 0x00, 0x10, 0x80, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x01,
+        ];
+
+        let command = Command::try_from(&bytes[..]).unwrap();
+
+        assert_eq!(command.status(), CommandStatus::EsmeRok);
+
+        let Some(Pdu::BindTransceiverResp(bind_resp)) = command.pdu() else {
+            panic!("Expected a bind_transceiver_resp, got {:?}", command.pdu());
+        };
+
+        assert!(bind_resp.is_header_only());
+        assert!(bind_resp.system_id.is_empty());
+        assert_eq!(bind_resp.sc_interface_version(), None);
+
+        // Re-encoded without a body
+        let mut buf = alloc::vec::Vec::new();
+
+        encode_many(core::slice::from_ref(&command), &mut buf);
+
+        assert_eq!(buf, bytes);
+
+        // Error responses are decoded without a body
+        let bytes = [
+            0x00, 0x00, 0x00, 0x10, 0x80, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0E, 0x00, 0x00,
+            0x00, 0x01,
+        ];
+
+        let command = Command::try_from(&bytes[..]).unwrap();
+
+        assert_eq!(command.status(), CommandStatus::EsmeRinvpaswd);
+        assert_eq!(command.pdu(), None);
+
+        // Other bodies are still required
+        let bytes = [
+            0x00, 0x00, 0x00, 0x10, 0x80, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x01,
+        ];
+
+        assert_eq!(Command::try_from(&bytes[..]).unwrap().pdu(), None);
+    }
+}
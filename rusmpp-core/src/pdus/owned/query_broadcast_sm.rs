@@ -52,6 +52,9 @@ pub struct QueryBroadcastSm {
 }
 
 impl QueryBroadcastSm {
+    /// The [`CommandId`](crate::CommandId) of the `query_broadcast_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000112;
+
     pub fn new(
         message_id: COctetString<1, 65>,
         source_addr_ton: Ton,
@@ -149,6 +152,16 @@ impl QueryBroadcastSmBuilder {
     }
 }
 
+impl crate::validate::Validate for QueryBroadcastSm {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        use crate::{fields::SmppField, validate::ValidationError};
+
+        ValidationError::non_empty(SmppField::message_id, self.message_id.is_empty())?;
+
+        self.user_message_reference.validate()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
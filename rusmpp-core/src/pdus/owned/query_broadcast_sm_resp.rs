@@ -2,7 +2,7 @@ use rusmpp_macros::Rusmpp;
 
 use crate::{
     pdus::owned::Pdu,
-    tlvs::owned::{QueryBroadcastResponseTlvValue, Tlv},
+    tlvs::owned::{QueryBroadcastResponseTlvValue, Tlv, TlvIndex},
     types::owned::COctetString,
 };
 
@@ -19,32 +19,57 @@ pub struct QueryBroadcastSmResp {
     pub message_id: COctetString<1, 65>,
     /// Query broadcast response TLVs ([`QueryBroadcastResponseTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(super) tlvs: alloc::vec::Vec<Tlv>,
+    /// Lookup index of the `tlvs`, not encoded.
+    #[rusmpp(skip)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        any(feature = "serde", feature = "serde-deserialize-unchecked"),
+        serde(skip)
+    )]
+    pub(super) tlv_index: TlvIndex,
 }
 
 impl QueryBroadcastSmResp {
+    /// The [`CommandId`](crate::CommandId) of the `query_broadcast_sm_resp` PDU.
+    pub const COMMAND_ID: u32 = 0x80000112;
+
     pub fn new(
         message_id: COctetString<1, 65>,
         tlvs: alloc::vec::Vec<impl Into<QueryBroadcastResponseTlvValue>>,
     ) -> Self {
         let tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
 
-        Self { message_id, tlvs }
+        Self {
+            message_id,
+            tlvs,
+            tlv_index: TlvIndex::new(),
+        }
     }
 
     pub fn tlvs(&self) -> &[Tlv] {
         &self.tlvs
     }
 
+    /// Returns the tags of the `TLV`s that are not allowed in a `query_broadcast_sm_resp`, if any.
+    ///
+    /// Unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) tags are vendor specific and always allowed.
+    pub fn validate_allowed_tlvs(&self) -> Result<(), alloc::vec::Vec<crate::tlvs::TlvTag>> {
+        crate::validate::allowed_tlvs(&self.tlvs, QueryBroadcastResponseTlvValue::TAGS)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<QueryBroadcastResponseTlvValue>>) {
+        self.tlv_index.clear();
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
     }
 
     pub fn clear_tlvs(&mut self) {
+        self.tlv_index.clear();
         self.tlvs.clear();
     }
 
     pub fn push_tlv(&mut self, tlv: impl Into<QueryBroadcastResponseTlvValue>) {
+        self.tlv_index.clear();
         self.tlvs.push(Tlv::from(tlv.into()));
     }
 
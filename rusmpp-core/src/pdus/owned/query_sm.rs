@@ -8,7 +8,7 @@ use crate::{pdus::owned::Pdu, types::owned::COctetString, values::*};
 /// Where the original submit_sm, data_sm or submit_multi ‘source address’ was defaulted to
 /// NULL, then the source address in the query_sm command should also be set to NULL.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = roundtrip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
@@ -47,6 +47,9 @@ pub struct QuerySm {
 }
 
 impl QuerySm {
+    /// The [`CommandId`](crate::CommandId) of the `query_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000003;
+
     pub fn new(
         message_id: COctetString<1, 65>,
         source_addr_ton: Ton,
@@ -107,6 +110,14 @@ impl QuerySmBuilder {
     }
 }
 
+impl crate::validate::Validate for QuerySm {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        use crate::{fields::SmppField, validate::ValidationError};
+
+        ValidationError::non_empty(SmppField::message_id, self.message_id.is_empty())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
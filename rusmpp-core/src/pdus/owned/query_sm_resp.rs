@@ -35,6 +35,9 @@ pub struct QuerySmResp {
 }
 
 impl QuerySmResp {
+    /// The [`CommandId`](crate::CommandId) of the `query_sm_resp` PDU.
+    pub const COMMAND_ID: u32 = 0x80000003;
+
     pub fn new(
         message_id: COctetString<1, 65>,
         final_date: EmptyOrFullCOctetString<17>,
@@ -80,6 +80,9 @@ pub struct ReplaceSm {
 }
 
 impl ReplaceSm {
+    /// The [`CommandId`](crate::CommandId) of the `replace_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000007;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         message_id: COctetString<1, 65>,
@@ -251,6 +254,23 @@ impl ReplaceSmBuilder {
     }
 }
 
+impl crate::validate::Validate for ReplaceSm {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        use crate::{
+            fields::SmppField,
+            validate::{ValidationError, validate_short_message},
+        };
+
+        ValidationError::non_empty(SmppField::message_id, self.message_id.is_empty())?;
+
+        validate_short_message(
+            self.sm_length,
+            self.short_message.bytes(),
+            self.message_payload.as_slice(),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
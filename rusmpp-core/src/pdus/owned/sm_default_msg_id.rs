@@ -0,0 +1,65 @@
+use super::*;
+
+macro_rules! impl_sm_default_msg_id {
+    ($($pdu:ident),* $(,)?) => {
+        $(
+            impl $pdu {
+                /// Returns the id of the canned message referenced by the `sm_default_msg_id`.
+                ///
+                /// Canned messages are predefined and stored in the MC.
+                /// An `sm_default_msg_id` of `0` references no canned message and returns [`None`].
+                pub const fn canned_message_id(&self) -> Option<u8> {
+                    match self.sm_default_msg_id {
+                        0 => None,
+                        id => Some(id),
+                    }
+                }
+
+                /// Sets the `sm_default_msg_id` to the id of a canned message, [`None`] sets it to `0`.
+                pub const fn set_canned_message_id(&mut self, canned_message_id: Option<u8>) {
+                    self.sm_default_msg_id = match canned_message_id {
+                        Some(id) => id,
+                        None => 0,
+                    };
+                }
+            }
+        )*
+    };
+}
+
+impl_sm_default_msg_id!(BroadcastSm, DeliverSm, ReplaceSm, SubmitMulti, SubmitSm);
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode::owned::DecodeWithLength, encode::Encode};
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut submit_sm = SubmitSm::default();
+
+        assert_eq!(submit_sm.canned_message_id(), None);
+
+        submit_sm.set_canned_message_id(Some(7));
+
+        assert_eq!(submit_sm.sm_default_msg_id, 7);
+
+        let mut buf = [0; 64];
+        let size = submit_sm.encode(&mut buf);
+
+        let (decoded, _) = SubmitSm::decode(&buf[..size], size).unwrap();
+
+        assert_eq!(decoded.sm_default_msg_id, 7);
+        assert_eq!(decoded.canned_message_id(), Some(7));
+
+        submit_sm.set_canned_message_id(None);
+
+        assert_eq!(submit_sm.sm_default_msg_id, 0);
+        assert_eq!(submit_sm.canned_message_id(), None);
+
+        let replace_sm = ReplaceSm::builder().sm_default_msg_id(255).build();
+
+        assert_eq!(replace_sm.canned_message_id(), Some(255));
+    }
+}
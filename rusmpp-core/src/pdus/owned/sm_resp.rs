@@ -2,12 +2,12 @@ use rusmpp_macros::Rusmpp;
 
 use crate::{
     pdus::owned::Pdu,
-    tlvs::owned::{MessageDeliveryResponseTlvValue, Tlv},
+    tlvs::owned::{MessageDeliveryResponseTlvValue, Tlv, TlvIndex},
     types::owned::COctetString,
 };
 
 macro_rules! sm_resp {
-    ($name:ident) => {
+    ($name:ident, $command_id:literal) => {
         #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
         #[rusmpp(decode = owned, test = skip)]
         #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
@@ -20,17 +20,32 @@ macro_rules! sm_resp {
             message_id: COctetString<1, 65>,
             /// Message delivery response TLVs ([`MessageDeliveryResponseTlvValue`])
             #[rusmpp(length = "unchecked")]
-            tlvs: alloc::vec::Vec<Tlv>,
+            pub(super) tlvs: alloc::vec::Vec<Tlv>,
+            /// Lookup index of the `tlvs`, not encoded.
+            #[rusmpp(skip)]
+            #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+            #[cfg_attr(
+                any(feature = "serde", feature = "serde-deserialize-unchecked"),
+                serde(skip)
+            )]
+            pub(super) tlv_index: TlvIndex,
         }
 
         impl $name {
+            /// The [`CommandId`](crate::CommandId) of the PDU.
+            pub const COMMAND_ID: u32 = $command_id;
+
             pub fn new(
                 message_id: COctetString<1, 65>,
                 tlvs: alloc::vec::Vec<impl Into<MessageDeliveryResponseTlvValue>>,
             ) -> Self {
                 let tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
 
-                Self { message_id, tlvs }
+                Self {
+                    message_id,
+                    tlvs,
+                    tlv_index: TlvIndex::new(),
+                }
             }
 
             pub fn message_id(&self) -> &COctetString<1, 65> {
@@ -41,14 +56,25 @@ macro_rules! sm_resp {
                 &self.tlvs
             }
 
+            /// Returns the tags of the `TLV`s that are not allowed in the PDU, if any.
+            ///
+            /// Unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) tags are vendor specific and always allowed.
+            pub fn validate_allowed_tlvs(
+                &self,
+            ) -> Result<(), alloc::vec::Vec<crate::tlvs::TlvTag>> {
+                crate::validate::allowed_tlvs(&self.tlvs, MessageDeliveryResponseTlvValue::TAGS)
+            }
+
             pub fn set_tlvs(
                 &mut self,
                 tlvs: alloc::vec::Vec<impl Into<MessageDeliveryResponseTlvValue>>,
             ) {
+                self.tlv_index.clear();
                 self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
             }
 
             pub fn push_tlv(&mut self, tlv: impl Into<MessageDeliveryResponseTlvValue>) {
+                self.tlv_index.clear();
                 self.tlvs.push(Tlv::from(tlv.into()));
             }
 
@@ -96,8 +122,8 @@ macro_rules! sm_resp {
     };
 }
 
-sm_resp!(DeliverSmResp);
-sm_resp!(DataSmResp);
+sm_resp!(DeliverSmResp, 0x80000005);
+sm_resp!(DataSmResp, 0x80000103);
 
 impl From<DeliverSmResp> for Pdu {
     fn from(value: DeliverSmResp) -> Self {
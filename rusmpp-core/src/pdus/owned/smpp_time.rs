@@ -0,0 +1,167 @@
+use crate::{
+    types::owned::EmptyOrFullCOctetString,
+    values::{SmppTime, SmppTimeError},
+};
+
+use super::*;
+
+macro_rules! impl_smpp_time {
+    ($($pdu:ident),* $(,)?) => {
+        $(
+            impl $pdu {
+                /// Returns the `schedule_delivery_time` as an [`SmppTime`].
+                ///
+                /// Returns [`None`] if the `schedule_delivery_time` is empty (immediate delivery) or malformed.
+                pub fn schedule_delivery_time(&self) -> Option<SmppTime> {
+                    smpp_time(&self.schedule_delivery_time)
+                }
+
+                /// Sets the `schedule_delivery_time`, [`None`] sets it to empty (immediate delivery).
+                ///
+                /// Fails if a field of the [`SmppTime`] is out of its range.
+                pub fn set_schedule_delivery_time(
+                    &mut self,
+                    schedule_delivery_time: Option<SmppTime>,
+                ) -> Result<(), SmppTimeError> {
+                    self.schedule_delivery_time = c_octet_string(schedule_delivery_time)?;
+
+                    Ok(())
+                }
+
+                /// Returns the `validity_period` as an [`SmppTime`].
+                ///
+                /// Returns [`None`] if the `validity_period` is empty (MC default validity period) or malformed.
+                pub fn validity_period(&self) -> Option<SmppTime> {
+                    smpp_time(&self.validity_period)
+                }
+
+                /// Sets the `validity_period`, [`None`] sets it to empty (MC default validity period).
+                ///
+                /// Fails if a field of the [`SmppTime`] is out of its range.
+                pub fn set_validity_period(
+                    &mut self,
+                    validity_period: Option<SmppTime>,
+                ) -> Result<(), SmppTimeError> {
+                    self.validity_period = c_octet_string(validity_period)?;
+
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_smpp_time!(ReplaceSm, SubmitMulti, SubmitSm);
+
+fn smpp_time(value: &EmptyOrFullCOctetString<17>) -> Option<SmppTime> {
+    let bytes = value.bytes();
+
+    // Without the null terminator
+    SmppTime::parse(&bytes[..bytes.len() - 1]).ok()
+}
+
+fn c_octet_string(time: Option<SmppTime>) -> Result<EmptyOrFullCOctetString<17>, SmppTimeError> {
+    let Some(time) = time else {
+        return Ok(EmptyOrFullCOctetString::empty());
+    };
+
+    let mut bytes = [0; SmppTime::LENGTH + 1];
+
+    bytes[..SmppTime::LENGTH].copy_from_slice(&time.to_bytes()?);
+
+    Ok(EmptyOrFullCOctetString::new(bytes).expect("SmppTime is 16 ascii characters"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decode::owned::DecodeWithLength,
+        encode::Encode,
+        values::{AbsoluteTime, RelativeTime},
+    };
+
+    use super::*;
+
+    #[test]
+    fn relative_validity_period() {
+        let mut submit_sm = SubmitSm::default();
+
+        assert_eq!(submit_sm.validity_period(), None);
+
+        let validity_period = SmppTime::Relative(RelativeTime {
+            days: 2,
+            hours: 12,
+            ..Default::default()
+        });
+
+        submit_sm
+            .set_validity_period(Some(validity_period))
+            .unwrap();
+
+        assert_eq!(submit_sm.validity_period.bytes(), b"000002120000000R\0");
+
+        let mut buf = [0; 64];
+        let size = submit_sm.encode(&mut buf);
+
+        let (decoded, _) = SubmitSm::decode(&buf[..size], size).unwrap();
+
+        assert_eq!(decoded.validity_period(), Some(validity_period));
+
+        submit_sm.set_validity_period(None).unwrap();
+
+        assert!(submit_sm.validity_period.is_empty());
+    }
+
+    #[test]
+    fn schedule_delivery_time() {
+        let absolute = AbsoluteTime {
+            year: 26,
+            month: 1,
+            day: 31,
+            hour: 8,
+            minute: 30,
+            second: 0,
+            tenths: 0,
+            utc_offset: -8,
+        };
+
+        let schedule_delivery_time = SmppTime::Absolute(absolute);
+
+        let mut replace_sm = ReplaceSm::default();
+
+        replace_sm
+            .set_schedule_delivery_time(Some(schedule_delivery_time))
+            .unwrap();
+
+        assert_eq!(
+            replace_sm.schedule_delivery_time.bytes(),
+            b"260131083000008-\0"
+        );
+        assert_eq!(
+            replace_sm.schedule_delivery_time(),
+            Some(schedule_delivery_time)
+        );
+
+        let out_of_range = SmppTime::Absolute(AbsoluteTime {
+            hour: 24,
+            ..absolute
+        });
+
+        assert_eq!(
+            replace_sm.set_schedule_delivery_time(Some(out_of_range)),
+            Err(SmppTimeError::OutOfRange)
+        );
+
+        // Left untouched
+        assert_eq!(
+            replace_sm.schedule_delivery_time(),
+            Some(schedule_delivery_time)
+        );
+
+        let submit_multi = SubmitMulti::builder()
+            .validity_period(EmptyOrFullCOctetString::new(b"2023-10-01T12:00\0").unwrap())
+            .build();
+
+        assert_eq!(submit_multi.validity_period(), None);
+    }
+}
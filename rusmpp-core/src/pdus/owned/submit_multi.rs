@@ -5,7 +5,7 @@ use crate::{
     pdus::owned::Pdu,
     tlvs::{
         TlvTag,
-        owned::{MessageSubmissionRequestTlvValue, Tlv},
+        owned::{MessageSubmissionRequestTlvValue, Tlv, TlvIndex},
     },
     types::owned::{COctetString, EmptyOrFullCOctetString, OctetString},
     values::{owned::*, *},
@@ -97,10 +97,21 @@ pub struct SubmitMulti {
     short_message: OctetString<0, 255>,
     /// Message submission request TLVs ([`MessageSubmissionRequestTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(super) tlvs: alloc::vec::Vec<Tlv>,
+    /// Lookup index of the `tlvs`, not encoded.
+    #[rusmpp(skip)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        any(feature = "serde", feature = "serde-deserialize-unchecked"),
+        serde(skip)
+    )]
+    pub(super) tlv_index: TlvIndex,
 }
 
 impl SubmitMulti {
+    /// The [`CommandId`](crate::CommandId) of the `submit_multi` PDU.
+    pub const COMMAND_ID: u32 = 0x00000021;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         service_type: ServiceType,
@@ -144,6 +155,7 @@ impl SubmitMulti {
             sm_length,
             short_message,
             tlvs,
+            tlv_index: TlvIndex::new(),
         };
 
         submit_multi.clear_short_message_if_message_payload_exists();
@@ -197,17 +209,27 @@ impl SubmitMulti {
         &self.tlvs
     }
 
+    /// Returns the tags of the `TLV`s that are not allowed in a `submit_multi`, if any.
+    ///
+    /// Unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) tags are vendor specific and always allowed.
+    pub fn validate_allowed_tlvs(&self) -> Result<(), alloc::vec::Vec<crate::tlvs::TlvTag>> {
+        crate::validate::allowed_tlvs(&self.tlvs, MessageSubmissionRequestTlvValue::TAGS)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<MessageSubmissionRequestTlvValue>>) {
+        self.tlv_index.clear();
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
 
         self.clear_short_message_if_message_payload_exists();
     }
 
     pub fn clear_tlvs(&mut self) {
+        self.tlv_index.clear();
         self.tlvs.clear();
     }
 
     pub fn push_tlv(&mut self, tlv: impl Into<MessageSubmissionRequestTlvValue>) {
+        self.tlv_index.clear();
         self.tlvs.push(Tlv::from(tlv.into()));
 
         self.clear_short_message_if_message_payload_exists();
@@ -215,7 +237,7 @@ impl SubmitMulti {
 
     /// Clears the short message and short message length if the message payload is set.
     /// Returns true if the short message and short message length were cleared.
-    fn clear_short_message_if_message_payload_exists(&mut self) -> bool {
+    pub(super) fn clear_short_message_if_message_payload_exists(&mut self) -> bool {
         let message_payload_exists = self
             .tlvs
             .iter()
@@ -366,6 +388,42 @@ impl SubmitMultiBuilder {
     }
 }
 
+impl crate::validate::Validate for SubmitMulti {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        use crate::{
+            fields::SmppField,
+            validate::{ValidationError, validate_short_message},
+        };
+
+        crate::validate::validate_ascii_address(SmppField::source_addr, self.source_addr.bytes())?;
+
+        crate::validate::validate_alphanumeric_address(
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr.as_str(),
+        )?;
+
+        ValidationError::non_empty(SmppField::dest_address, self.dest_address.is_empty())?;
+
+        ValidationError::length_match(
+            SmppField::number_of_dests,
+            self.number_of_dests as usize,
+            self.dest_address.len(),
+        )?;
+
+        for dest_address in &self.dest_address {
+            if let DestAddress::SmeAddress(sme_address) = dest_address {
+                crate::validate::validate_ascii_address(
+                    SmppField::destination_addr,
+                    sme_address.destination_addr.bytes(),
+                )?;
+            }
+        }
+
+        validate_short_message(self.sm_length, self.short_message.bytes(), &self.tlvs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -569,4 +627,66 @@ mod tests {
         assert_eq!(submit_sm.number_of_dests(), 0);
         assert!(submit_sm.dest_address().is_empty());
     }
+
+    #[test]
+    fn validate() {
+        use crate::{
+            fields::SmppField,
+            validate::{Validate, ValidationError},
+        };
+
+        assert_eq!(
+            SubmitMulti::default().validate(),
+            Err(ValidationError::EmptyField {
+                field: SmppField::dest_address
+            })
+        );
+
+        for submit_multi in SubmitMulti::instances()
+            .into_iter()
+            .filter(|submit_multi| !submit_multi.dest_address().is_empty())
+        {
+            assert!(submit_multi.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn validate_non_ascii_address() {
+        use crate::{
+            fields::SmppField,
+            validate::{Validate, ValidationError},
+        };
+
+        let sme_address = |addr: &str| {
+            DestAddress::SmeAddress(SmeAddress::new(
+                Ton::International,
+                Npi::Isdn,
+                COctetString::new_unchecked(addr),
+            ))
+        };
+
+        // UTF-8 encoded 'é' in the second destination
+        let submit_multi = SubmitMulti::builder()
+            .source_addr(COctetString::from_str("Rusmpp").unwrap())
+            .push_dest_address(sme_address("491701234567\0"))
+            .push_dest_address(sme_address("+49170é\0"))
+            .build();
+
+        assert_eq!(
+            submit_multi.validate(),
+            Err(ValidationError::NotAsciiAddress {
+                field: SmppField::destination_addr
+            })
+        );
+
+        // Distribution list names are not addresses
+        let submit_multi = SubmitMulti::builder()
+            .push_dest_address(sme_address("491701234567\0"))
+            .push_dest_address(DestAddress::DistributionListName(
+                DistributionListName::new(COctetString::from_str("Rusmpp-List").unwrap()),
+            ))
+            .build();
+
+        assert!(submit_multi.validate().is_ok());
+    }
 }
@@ -2,7 +2,7 @@ use rusmpp_macros::Rusmpp;
 
 use crate::{
     pdus::owned::Pdu,
-    tlvs::owned::{MessageSubmissionResponseTlvValue, Tlv},
+    tlvs::owned::{MessageSubmissionResponseTlvValue, Tlv, TlvIndex},
     types::owned::COctetString,
     values::owned::*,
 };
@@ -29,10 +29,21 @@ pub struct SubmitMultiResp {
     unsuccess_sme: alloc::vec::Vec<UnsuccessSme>,
     /// Message submission response TLVs ([`MessageSubmissionResponseTlvValue`])
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(super) tlvs: alloc::vec::Vec<Tlv>,
+    /// Lookup index of the `tlvs`, not encoded.
+    #[rusmpp(skip)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        any(feature = "serde", feature = "serde-deserialize-unchecked"),
+        serde(skip)
+    )]
+    pub(super) tlv_index: TlvIndex,
 }
 
 impl SubmitMultiResp {
+    /// The [`CommandId`](crate::CommandId) of the `submit_multi_resp` PDU.
+    pub const COMMAND_ID: u32 = 0x80000021;
+
     pub fn new(
         message_id: COctetString<1, 65>,
         unsuccess_sme: alloc::vec::Vec<UnsuccessSme>,
@@ -47,6 +58,7 @@ impl SubmitMultiResp {
             no_unsuccess,
             unsuccess_sme,
             tlvs,
+            tlv_index: TlvIndex::new(),
         }
     }
 
@@ -77,18 +89,28 @@ impl SubmitMultiResp {
         &self.tlvs
     }
 
+    /// Returns the tags of the `TLV`s that are not allowed in a `submit_multi_resp`, if any.
+    ///
+    /// Unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) tags are vendor specific and always allowed.
+    pub fn validate_allowed_tlvs(&self) -> Result<(), alloc::vec::Vec<crate::tlvs::TlvTag>> {
+        crate::validate::allowed_tlvs(&self.tlvs, MessageSubmissionResponseTlvValue::TAGS)
+    }
+
     pub fn set_tlvs(
         &mut self,
         tlvs: alloc::vec::Vec<impl Into<MessageSubmissionResponseTlvValue>>,
     ) {
+        self.tlv_index.clear();
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
     }
 
     pub fn clear_tlvs(&mut self) {
+        self.tlv_index.clear();
         self.tlvs.clear();
     }
 
     pub fn push_tlv(&mut self, tlv: impl Into<MessageSubmissionResponseTlvValue>) {
+        self.tlv_index.clear();
         self.tlvs.push(Tlv::from(tlv.into()));
     }
 
@@ -2,12 +2,13 @@ use rusmpp_macros::Rusmpp;
 
 use crate::{
     encode::Length,
-    pdus::owned::Pdu,
+    pdus::owned::{Pdu, TlvContainer},
+    text::TextError,
     tlvs::{
         TlvTag,
-        owned::{MessageSubmissionRequestTlvValue, Tlv},
+        owned::{MessageSubmissionRequestTlvValue, Tlv, TlvIndex, TlvValue},
     },
-    types::owned::{COctetString, EmptyOrFullCOctetString, OctetString},
+    types::owned::{AnyOctetString, COctetString, EmptyOrFullCOctetString, OctetString},
     values::{owned::*, *},
 };
 
@@ -86,10 +87,21 @@ pub struct SubmitSm {
     short_message: OctetString<0, 255>,
     /// Message submission request TLVs ([`MessageSubmissionRequestTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(super) tlvs: alloc::vec::Vec<Tlv>,
+    /// Lookup index of the `tlvs`, not encoded.
+    #[rusmpp(skip)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        any(feature = "serde", feature = "serde-deserialize-unchecked"),
+        serde(skip)
+    )]
+    pub(super) tlv_index: TlvIndex,
 }
 
 impl SubmitSm {
+    /// The [`CommandId`](crate::CommandId) of the `submit_sm` PDU.
+    pub const COMMAND_ID: u32 = 0x00000004;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         service_type: ServiceType,
@@ -135,6 +147,7 @@ impl SubmitSm {
             sm_length,
             short_message,
             tlvs,
+            tlv_index: TlvIndex::new(),
         };
 
         submit_sm.clear_short_message_if_message_payload_exists();
@@ -161,21 +174,99 @@ impl SubmitSm {
         !self.clear_short_message_if_message_payload_exists()
     }
 
+    /// Decodes the message text using `data_coding`, see [`decode_text`](crate::text::decode_text).
+    ///
+    /// The text is read from the `message_payload` if present, from the `short_message` otherwise.
+    pub fn text(&self, data_coding: DataCoding) -> Result<alloc::string::String, TextError> {
+        let payload = self
+            .get_tlv(TlvTag::MessagePayload)
+            .and_then(|tlv| match tlv.value() {
+                Some(TlvValue::MessagePayload(payload)) => Some(payload.value.bytes()),
+                _ => None,
+            });
+
+        crate::text::decode_text(payload.unwrap_or(self.short_message.bytes()), data_coding)
+    }
+
+    /// Returns the `short_message` followed by the `message_payload`.
+    ///
+    /// The `short_message` must be empty if the `message_payload` is present, yet some ESMEs split the content across both.
+    /// Unlike [`SubmitSm::text`], which reads the `message_payload` only if present, this lenient accessor keeps both.
+    pub fn full_message_bytes(&self) -> alloc::vec::Vec<u8> {
+        let payload = self
+            .get_tlv(TlvTag::MessagePayload)
+            .and_then(|tlv| match tlv.value() {
+                Some(TlvValue::MessagePayload(payload)) => Some(payload.value.bytes()),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        [self.short_message.bytes(), payload].concat()
+    }
+
+    /// Encodes `text` using `data_coding`, see [`encode_text`](crate::text::encode_text), and sets the `data_coding`.
+    ///
+    /// Texts of up to 255 bytes are set as the `short_message`, removing the `message_payload`.
+    /// Longer texts are set as the `message_payload`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{pdus::owned::SubmitSm, values::DataCoding};
+    /// let mut submit_sm = SubmitSm::default();
+    ///
+    /// submit_sm.set_text("Hi 😀", DataCoding::Ucs2).unwrap();
+    ///
+    /// assert_eq!(submit_sm.sm_length(), 10);
+    /// assert_eq!(submit_sm.data_coding, DataCoding::Ucs2);
+    /// assert_eq!(submit_sm.text(DataCoding::Ucs2).unwrap(), "Hi 😀");
+    /// ```
+    pub fn set_text(&mut self, text: &str, data_coding: DataCoding) -> Result<(), TextError> {
+        let bytes = crate::text::encode_text(text, data_coding)?;
+
+        self.data_coding = data_coding;
+
+        match OctetString::new(&bytes) {
+            Ok(short_message) => {
+                self.tlv_index.clear();
+                self.tlvs.retain(|tlv| tlv.tag() != TlvTag::MessagePayload);
+                self.set_short_message(short_message);
+            }
+            Err(_) => {
+                self.set_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                    MessagePayload::new(AnyOctetString::from(bytes)),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn tlvs(&self) -> &[Tlv] {
         &self.tlvs
     }
 
+    /// Returns the tags of the `TLV`s that are not allowed in a `submit_sm`, if any.
+    ///
+    /// Unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) tags are vendor specific and always allowed.
+    pub fn validate_allowed_tlvs(&self) -> Result<(), alloc::vec::Vec<crate::tlvs::TlvTag>> {
+        crate::validate::allowed_tlvs(&self.tlvs, MessageSubmissionRequestTlvValue::TAGS)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<MessageSubmissionRequestTlvValue>>) {
+        self.tlv_index.clear();
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
 
         self.clear_short_message_if_message_payload_exists();
     }
 
     pub fn clear_tlvs(&mut self) {
+        self.tlv_index.clear();
         self.tlvs.clear();
     }
 
     pub fn push_tlv(&mut self, tlv: impl Into<MessageSubmissionRequestTlvValue>) {
+        self.tlv_index.clear();
         self.tlvs.push(Tlv::from(tlv.into()));
 
         self.clear_short_message_if_message_payload_exists();
@@ -183,7 +274,7 @@ impl SubmitSm {
 
     /// Clears the short message and short message length if the message payload is set.
     /// Returns true if the short message and short message length were cleared.
-    fn clear_short_message_if_message_payload_exists(&mut self) -> bool {
+    pub(super) fn clear_short_message_if_message_payload_exists(&mut self) -> bool {
         let message_payload_exists = self
             .tlvs
             .iter()
@@ -334,11 +425,40 @@ impl SubmitSmBuilder {
     }
 }
 
+impl crate::validate::Validate for SubmitSm {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        use crate::{
+            fields::SmppField,
+            validate::{ValidationError, validate_short_message},
+        };
+
+        crate::validate::validate_ascii_address(SmppField::source_addr, self.source_addr.bytes())?;
+
+        crate::validate::validate_alphanumeric_address(
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr.as_str(),
+        )?;
+
+        ValidationError::non_empty(
+            SmppField::destination_addr,
+            self.destination_addr.is_empty(),
+        )?;
+
+        crate::validate::validate_ascii_address(
+            SmppField::destination_addr,
+            self.destination_addr.bytes(),
+        )?;
+
+        validate_short_message(self.sm_length, self.short_message.bytes(), &self.tlvs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::{tests::TestInstance, types::owned::AnyOctetString};
+    use crate::tests::TestInstance;
 
     use super::*;
 
@@ -427,11 +547,96 @@ mod tests {
         }
     }
 
+    #[test]
+    fn full_message_bytes() {
+        use crate::pdus::owned::PduBody;
+
+        let mut body = SubmitSm::builder()
+            .short_message(OctetString::new(b"Hello, ").unwrap())
+            .build()
+            .encode_body();
+
+        body.extend_from_slice(b"\x04\x24\x00\x06World!");
+
+        let submit_sm = SubmitSm::decode_body(&body).unwrap();
+
+        assert_eq!(submit_sm.full_message_bytes(), b"Hello, World!");
+        assert_eq!(submit_sm.text(DataCoding::Ia5).unwrap(), "World!");
+    }
+
     #[test]
     fn encode_decode() {
         crate::tests::owned::encode_decode_with_length_test_instances::<SubmitSm>();
     }
 
+    #[test]
+    fn set_tlv_replaces_push_tlv_appends() {
+        let mut submit_sm = SubmitSm::builder()
+            .short_message(OctetString::new(b"Short Message").unwrap())
+            .build();
+
+        submit_sm.push_tlv(MessageSubmissionRequestTlvValue::UserResponseCode(1));
+        submit_sm.push_tlv(MessageSubmissionRequestTlvValue::UserResponseCode(2));
+
+        assert_eq!(submit_sm.tlvs().len(), 2);
+
+        let replaced = submit_sm.set_tlv(MessageSubmissionRequestTlvValue::UserResponseCode(3));
+
+        assert_eq!(
+            replaced,
+            Some(Tlv::from(
+                MessageSubmissionRequestTlvValue::UserResponseCode(2)
+            ))
+        );
+        assert_eq!(
+            submit_sm.tlvs(),
+            [Tlv::from(
+                MessageSubmissionRequestTlvValue::UserResponseCode(3)
+            )]
+        );
+
+        // Inserted if absent, the message payload still clears the short message
+        let replaced = submit_sm.set_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+            MessagePayload::new(AnyOctetString::new(b"Message Payload")),
+        ));
+
+        assert!(replaced.is_none());
+        assert_eq!(submit_sm.tlvs().len(), 2);
+        assert_eq!(submit_sm.short_message(), &OctetString::empty());
+    }
+
+    #[test]
+    fn extend_tlvs() {
+        let mut submit_sm = SubmitSm::builder()
+            .short_message(OctetString::new(b"Short Message").unwrap())
+            .push_tlv(MessageSubmissionRequestTlvValue::UserResponseCode(1))
+            .build();
+
+        submit_sm.extend_tlvs([
+            MessageSubmissionRequestTlvValue::SourcePort(2),
+            MessageSubmissionRequestTlvValue::MessagePayload(MessagePayload::new(
+                AnyOctetString::new(b"Message Payload"),
+            )),
+            MessageSubmissionRequestTlvValue::DestPort(3),
+        ]);
+
+        assert_eq!(
+            submit_sm
+                .tlvs()
+                .iter()
+                .map(Tlv::tag)
+                .collect::<alloc::vec::Vec<_>>(),
+            [
+                TlvTag::UserResponseCode,
+                TlvTag::SourcePort,
+                TlvTag::MessagePayload,
+                TlvTag::DestPort,
+            ]
+        );
+        assert_eq!(submit_sm.short_message(), &OctetString::empty());
+        assert_eq!(submit_sm.sm_length(), 0);
+    }
+
     #[test]
     fn short_message_length() {
         let short_message = OctetString::new(b"Short Message").unwrap();
@@ -522,4 +727,142 @@ mod tests {
         assert_eq!(submit_sm.short_message(), &short_message);
         assert_eq!(submit_sm.sm_length(), short_message.length() as u8);
     }
+
+    #[test]
+    fn validate() {
+        use crate::{
+            fields::SmppField,
+            validate::{Validate, ValidationError},
+        };
+
+        let submit_sm = SubmitSm::builder()
+            .short_message(OctetString::new(b"Short Message").unwrap())
+            .build();
+
+        assert_eq!(
+            submit_sm.validate(),
+            Err(ValidationError::EmptyField {
+                field: SmppField::destination_addr
+            })
+        );
+
+        let submit_sm = SubmitSm::builder()
+            .destination_addr(COctetString::from_str("Destination Address").unwrap())
+            .short_message(OctetString::new(b"Short Message").unwrap())
+            .build();
+
+        assert!(submit_sm.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_non_ascii_address() {
+        use crate::{
+            fields::SmppField,
+            types::COctetStringError,
+            validate::{Validate, ValidationError},
+        };
+
+        // UTF-8 encoded 'é'
+        assert!(matches!(
+            COctetString::<1, 21>::from_str("+49170é"),
+            Err(COctetStringError::NotAscii)
+        ));
+
+        let submit_sm = SubmitSm::builder()
+            .source_addr(COctetString::new_unchecked("Rusmpp\0"))
+            .destination_addr(COctetString::new_unchecked("+49170é\0"))
+            .build();
+
+        assert_eq!(
+            submit_sm.validate(),
+            Err(ValidationError::NotAsciiAddress {
+                field: SmppField::destination_addr
+            })
+        );
+
+        let submit_sm = SubmitSm::builder()
+            .source_addr(COctetString::new_unchecked("Rusmpp\u{1F600}\0"))
+            .destination_addr(COctetString::from_str("491701234567").unwrap())
+            .build();
+
+        assert_eq!(
+            submit_sm.validate(),
+            Err(ValidationError::NotAsciiAddress {
+                field: SmppField::source_addr
+            })
+        );
+    }
+
+    #[test]
+    fn validate_allowed_tlvs() {
+        use crate::{decode::owned::DecodeWithLength, encode::Encode, tlvs::TlvTag};
+
+        let submit_sm = SubmitSm::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::UserMessageReference(
+                UserMessageReference::new(16),
+            ))
+            .build();
+
+        assert!(submit_sm.validate_allowed_tlvs().is_ok());
+
+        let mut buf = alloc::vec![0; submit_sm.length()];
+        let size = submit_sm.encode(&mut buf);
+
+        buf.truncate(size);
+
+        // receipted_message_id belongs to deliver_sm
+        buf.extend_from_slice(&[0x00, 0x1E, 0x00, 0x02, b'1', 0x00]);
+        // Vendor specific
+        buf.extend_from_slice(&[0x14, 0x00, 0x00, 0x01, 0xFF]);
+
+        let (submit_sm, _) = SubmitSm::decode(&buf, buf.len()).unwrap();
+
+        assert_eq!(submit_sm.tlvs().len(), 3);
+        assert_eq!(
+            submit_sm.validate_allowed_tlvs(),
+            Err(alloc::vec![TlvTag::ReceiptedMessageId])
+        );
+    }
+
+    #[test]
+    fn text() {
+        let mut submit_sm = SubmitSm::default();
+
+        submit_sm
+            .set_text("Hello, World!", DataCoding::McSpecific)
+            .unwrap();
+
+        assert_eq!(submit_sm.short_message().bytes(), b"Hello, World!");
+        assert_eq!(
+            submit_sm.text(DataCoding::McSpecific).unwrap(),
+            "Hello, World!"
+        );
+
+        submit_sm.set_text("Hi 👋🏽", DataCoding::Ucs2).unwrap();
+
+        assert_eq!(submit_sm.data_coding, DataCoding::Ucs2);
+        assert_eq!(submit_sm.sm_length(), 14);
+        assert_eq!(submit_sm.text(DataCoding::Ucs2).unwrap(), "Hi 👋🏽");
+
+        // Too long for the short_message
+        let long = "😀".repeat(64);
+
+        submit_sm.set_text(&long, DataCoding::Ucs2).unwrap();
+
+        assert_eq!(submit_sm.sm_length(), 0);
+        assert!(submit_sm.get_tlv(TlvTag::MessagePayload).is_some());
+        assert_eq!(submit_sm.text(DataCoding::Ucs2).unwrap(), long);
+
+        // Short texts replace the message_payload
+        submit_sm.set_text("Hi", DataCoding::Latin1).unwrap();
+
+        assert!(submit_sm.get_tlv(TlvTag::MessagePayload).is_none());
+        assert_eq!(submit_sm.text(DataCoding::Latin1).unwrap(), "Hi");
+
+        assert_eq!(
+            submit_sm.set_text("😀", DataCoding::McSpecific),
+            Err(TextError::UnrepresentableChar { char: '😀' })
+        );
+        assert_eq!(submit_sm.data_coding, DataCoding::Latin1);
+    }
 }
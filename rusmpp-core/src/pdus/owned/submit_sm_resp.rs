@@ -2,8 +2,8 @@ use rusmpp_macros::Rusmpp;
 
 use crate::{
     pdus::owned::Pdu,
-    tlvs::owned::{MessageSubmissionResponseTlvValue, Tlv},
-    types::owned::COctetString,
+    tlvs::owned::{MessageSubmissionResponseTlvValue, Tlv, TlvIndex},
+    types::{COctetStringError, owned::COctetString},
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
@@ -18,39 +18,80 @@ pub struct SubmitSmResp {
     message_id: COctetString<1, 65>,
     /// Message submission response TLVs ([`MessageSubmissionResponseTlvValue`])
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(super) tlvs: alloc::vec::Vec<Tlv>,
+    /// Lookup index of the `tlvs`, not encoded.
+    #[rusmpp(skip)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        any(feature = "serde", feature = "serde-deserialize-unchecked"),
+        serde(skip)
+    )]
+    pub(super) tlv_index: TlvIndex,
 }
 
 impl SubmitSmResp {
+    /// The [`CommandId`](crate::CommandId) of the `submit_sm_resp` PDU.
+    pub const COMMAND_ID: u32 = 0x80000004;
+
     pub fn new(
         message_id: COctetString<1, 65>,
         tlvs: alloc::vec::Vec<impl Into<MessageSubmissionResponseTlvValue>>,
     ) -> Self {
         let tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
 
-        Self { message_id, tlvs }
+        Self {
+            message_id,
+            tlvs,
+            tlv_index: TlvIndex::new(),
+        }
     }
 
     pub fn message_id(&self) -> &COctetString<1, 65> {
         &self.message_id
     }
 
+    /// Returns the `message_id` as a [`str`].
+    ///
+    /// This is the ID to store for correlating delivery receipts, see [`normalize_message_id`](crate::message_id::normalize_message_id).
+    pub fn message_id_str(&self) -> &str {
+        self.message_id.as_str()
+    }
+
+    /// Sets the `message_id` from a [`str`].
+    ///
+    /// Fails if the `message_id` exceeds the maximum of 64 characters.
+    pub fn set_message_id(&mut self, message_id: &str) -> Result<(), COctetStringError> {
+        self.message_id = message_id.parse()?;
+
+        Ok(())
+    }
+
     pub fn tlvs(&self) -> &[Tlv] {
         &self.tlvs
     }
 
+    /// Returns the tags of the `TLV`s that are not allowed in a `submit_sm_resp`, if any.
+    ///
+    /// Unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) tags are vendor specific and always allowed.
+    pub fn validate_allowed_tlvs(&self) -> Result<(), alloc::vec::Vec<crate::tlvs::TlvTag>> {
+        crate::validate::allowed_tlvs(&self.tlvs, MessageSubmissionResponseTlvValue::TAGS)
+    }
+
     pub fn set_tlvs(
         &mut self,
         tlvs: alloc::vec::Vec<impl Into<MessageSubmissionResponseTlvValue>>,
     ) {
+        self.tlv_index.clear();
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
     }
 
     pub fn clear_tlvs(&mut self) {
+        self.tlv_index.clear();
         self.tlvs.clear();
     }
 
     pub fn push_tlv(&mut self, tlv: impl Into<MessageSubmissionResponseTlvValue>) {
+        self.tlv_index.clear();
         self.tlvs.push(Tlv::from(tlv.into()));
     }
 
@@ -133,6 +174,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn set_message_id() {
+        let mut submit_sm_resp = SubmitSmResp::default();
+
+        submit_sm_resp
+            .set_message_id("12345678901234567890123")
+            .unwrap();
+        assert_eq!(
+            submit_sm_resp.message_id().as_str(),
+            "12345678901234567890123"
+        );
+
+        let too_long = "1".repeat(65);
+        let error = submit_sm_resp.set_message_id(&too_long).unwrap_err();
+        assert!(matches!(
+            error,
+            COctetStringError::TooManyBytes {
+                actual: 66,
+                max: 65
+            }
+        ));
+    }
+
+    #[test]
+    fn message_id_str() {
+        use crate::pdus::owned::PduBody;
+
+        let submit_sm_resp = SubmitSmResp::builder()
+            .message_id(COctetString::from_str("0x4D2").unwrap())
+            .build();
+
+        let decoded = SubmitSmResp::decode_body(&submit_sm_resp.encode_body()).unwrap();
+
+        assert_eq!(decoded.message_id_str(), "0x4D2");
+        assert_eq!(SubmitSmResp::default().message_id_str(), "");
+    }
+
     #[test]
     fn encode_decode() {
         crate::tests::owned::encode_decode_with_length_test_instances::<SubmitSmResp>();
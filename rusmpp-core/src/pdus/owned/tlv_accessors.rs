@@ -0,0 +1,697 @@
+use crate::{
+    tlvs::{
+        TlvTag,
+        owned::{
+            BroadcastRequestTlvValue, MessageDeliveryRequestTlvValue,
+            MessageDeliveryResponseTlvValue, MessageSubmissionRequestTlvValue,
+            MessageSubmissionResponseTlvValue, Tlv, TlvValue,
+        },
+    },
+    types::owned::{COctetString, OctetString},
+    values::{owned::Subaddress, *},
+};
+
+use super::*;
+
+/// Implements a typed getter and setter for a `TLV` of PDUs with a `TLV` list.
+///
+/// `Copy` values are returned by value, values given as `&Type` by reference.
+macro_rules! impl_tlv_accessors {
+    ($name:ident, $set_name:ident, $variant:ident: &$ty:ty; $($pdu:ident: $tlv_value:ident),* $(,)?) => {
+        $(
+            impl $pdu {
+                #[doc = concat!("Returns the value of the `", stringify!($name), "` `TLV`, if any.")]
+                pub fn $name(&self) -> Option<&$ty> {
+                    match self.get_tlv(TlvTag::$variant)?.value()? {
+                        TlvValue::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+
+                impl_tlv_accessors!(@set $name, $set_name, $variant: $ty, $tlv_value);
+            }
+        )*
+    };
+    ($name:ident, $set_name:ident, $variant:ident: $ty:ty; $($pdu:ident: $tlv_value:ident),* $(,)?) => {
+        $(
+            impl $pdu {
+                #[doc = concat!("Returns the value of the `", stringify!($name), "` `TLV`, if any.")]
+                pub fn $name(&self) -> Option<$ty> {
+                    match self.get_tlv(TlvTag::$variant)?.value()? {
+                        TlvValue::$variant(value) => Some(*value),
+                        _ => None,
+                    }
+                }
+
+                impl_tlv_accessors!(@set $name, $set_name, $variant: $ty, $tlv_value);
+            }
+        )*
+    };
+    (@set $name:ident, $set_name:ident, $variant:ident: $ty:ty, $tlv_value:ident) => {
+        #[doc = concat!("Sets the `", stringify!($name), "` `TLV`, removing it if `value` is [`None`].")]
+        ///
+        /// Returns the previous `TLV`, if any.
+        pub fn $set_name(&mut self, value: Option<$ty>) -> Option<Tlv> {
+            match value {
+                Some(value) => self.set_tlv($tlv_value::$variant(value)),
+                None => self.remove_tlv(TlvTag::$variant),
+            }
+        }
+    };
+}
+
+impl_tlv_accessors!(
+    additional_status_info_text, set_additional_status_info_text, AdditionalStatusInfoText: &COctetString<1, 256>;
+    DataSmResp: MessageDeliveryResponseTlvValue,
+    DeliverSmResp: MessageDeliveryResponseTlvValue,
+    SubmitMultiResp: MessageSubmissionResponseTlvValue,
+    SubmitSmResp: MessageSubmissionResponseTlvValue,
+);
+
+impl_tlv_accessors!(
+    dpf_result, set_dpf_result, DpfResult: DpfResult;
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMultiResp: MessageSubmissionResponseTlvValue,
+    SubmitSmResp: MessageSubmissionResponseTlvValue,
+);
+
+impl_tlv_accessors!(
+    set_dpf, set_set_dpf, SetDpf: SetDpf;
+    DataSm: MessageSubmissionRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    more_messages_to_send, set_more_messages_to_send, MoreMessagesToSend: MoreMessagesToSend;
+    DataSm: MessageSubmissionRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    callback_num, set_callback_num, CallbackNum: &OctetString<4, 19>;
+    BroadcastSm: BroadcastRequestTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    callback_num_pres_ind, set_callback_num_pres_ind, CallbackNumPresInd: CallbackNumPresInd;
+    BroadcastSm: BroadcastRequestTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    callback_num_atag, set_callback_num_atag, CallbackNumAtag: &OctetString<0, 65>;
+    BroadcastSm: BroadcastRequestTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    source_subaddress, set_source_subaddress, SourceSubaddress: &Subaddress;
+    BroadcastSm: BroadcastRequestTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    dest_subaddress, set_dest_subaddress, DestSubaddress: &Subaddress;
+    BroadcastSm: BroadcastRequestTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    ussd_service_op, set_ussd_service_op, UssdServiceOp: UssdServiceOp;
+    DataSm: MessageSubmissionRequestTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    its_reply_type, set_its_reply_type, ItsReplyType: ItsReplyType;
+    DataSm: MessageSubmissionRequestTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    its_session_info, set_its_session_info, ItsSessionInfo: ItsSessionInfo;
+    DataSm: MessageSubmissionRequestTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    display_time, set_display_time, DisplayTime: DisplayTime;
+    BroadcastSm: BroadcastRequestTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    ms_msg_wait_facilities, set_ms_msg_wait_facilities, MsMsgWaitFacilities: MsMsgWaitFacilities;
+    DataSm: MessageSubmissionRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    source_port, set_source_port, SourcePort: u16;
+    BroadcastSm: BroadcastRequestTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    dest_port, set_dest_port, DestPort: u16;
+    BroadcastSm: BroadcastRequestTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    language_indicator, set_language_indicator, LanguageIndicator: LanguageIndicator;
+    BroadcastSm: BroadcastRequestTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    privacy_indicator, set_privacy_indicator, PrivacyIndicator: PrivacyIndicator;
+    BroadcastSm: BroadcastRequestTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    number_of_messages, set_number_of_messages, NumberOfMessages: NumberOfMessages;
+    DataSm: MessageSubmissionRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    sms_signal, set_sms_signal, SmsSignal: u16;
+    BroadcastSm: BroadcastRequestTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+impl_tlv_accessors!(
+    alert_on_message_delivery, set_alert_on_message_delivery, AlertOnMessageDelivery: AlertOnMessageDelivery;
+    BroadcastSm: BroadcastRequestTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue,
+);
+
+/// Implements the handling of the zero-length `alert_on_message_delivery` `TLV`.
+macro_rules! impl_alert_on_message_delivery_empty {
+    ($($pdu:ident),* $(,)?) => {
+        $(
+            impl $pdu {
+                /// Returns `true` if the `alert_on_message_delivery` `TLV` is present, with or without a value.
+                ///
+                /// [`alert_on_message_delivery`](Self::alert_on_message_delivery) returns [`None`] for the zero-length form.
+                pub fn has_alert_on_message_delivery(&self) -> bool {
+                    self.get_tlv(TlvTag::AlertOnMessageDelivery).is_some()
+                }
+
+                /// Sets the zero-length `alert_on_message_delivery` `TLV`, see [`Tlv::empty`].
+                ///
+                /// Returns the previous `TLV`, if any.
+                pub fn set_alert_on_message_delivery_empty(&mut self) -> Option<Tlv> {
+                    crate::tlvs::owned::set_tlv(
+                        &mut self.tlvs,
+                        Tlv::empty(TlvTag::AlertOnMessageDelivery),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_alert_on_message_delivery_empty!(BroadcastSm, DataSm, SubmitMulti, SubmitSm);
+
+impl_tlv_accessors!(
+    delivery_failure_reason, set_delivery_failure_reason, DeliveryFailureReason: DeliveryFailureReason;
+    DataSmResp: MessageDeliveryResponseTlvValue,
+    DeliverSmResp: MessageDeliveryResponseTlvValue,
+    SubmitMultiResp: MessageSubmissionResponseTlvValue,
+    SubmitSmResp: MessageSubmissionResponseTlvValue,
+);
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use super::*;
+
+    fn round_trip<T: PduBody>(pdu: &T) -> T {
+        T::decode_body(&pdu.encode_body()).unwrap()
+    }
+
+    #[test]
+    fn additional_status_info_text() {
+        let mut submit_sm_resp = SubmitSmResp::default();
+
+        assert_eq!(submit_sm_resp.additional_status_info_text(), None);
+
+        let text = COctetString::from_str("Destination barred").unwrap();
+
+        submit_sm_resp.set_additional_status_info_text(Some(text.clone()));
+
+        let decoded = round_trip(&submit_sm_resp);
+
+        assert_eq!(decoded.additional_status_info_text(), Some(&text));
+        assert_eq!(
+            decoded
+                .additional_status_info_text()
+                .map(COctetString::as_str),
+            Some("Destination barred")
+        );
+
+        // Replacing keeps a single TLV
+        let mut deliver_sm_resp = DeliverSmResp::default();
+
+        deliver_sm_resp.set_additional_status_info_text(Some(text.clone()));
+        deliver_sm_resp.set_additional_status_info_text(Some(
+            COctetString::from_str("Handset memory full").unwrap(),
+        ));
+
+        let decoded = round_trip(&deliver_sm_resp);
+
+        assert_eq!(decoded.tlvs().len(), 1);
+        assert_eq!(
+            decoded
+                .additional_status_info_text()
+                .map(COctetString::as_str),
+            Some("Handset memory full")
+        );
+
+        let previous = submit_sm_resp.set_additional_status_info_text(None);
+
+        assert!(previous.is_some());
+        assert_eq!(submit_sm_resp.additional_status_info_text(), None);
+        assert!(submit_sm_resp.tlvs().is_empty());
+    }
+
+    #[test]
+    fn delivery_pending_flag() {
+        let mut submit_sm = SubmitSm::default();
+
+        assert_eq!(submit_sm.set_dpf(), None);
+
+        submit_sm.set_set_dpf(Some(SetDpf::Requested));
+
+        assert_eq!(round_trip(&submit_sm).set_dpf(), Some(SetDpf::Requested));
+
+        let mut submit_sm_resp = SubmitSmResp::default();
+
+        submit_sm_resp.set_dpf_result(Some(DpfResult::Set));
+        submit_sm_resp
+            .set_delivery_failure_reason(Some(DeliveryFailureReason::DestinationUnavailable));
+
+        let decoded = round_trip(&submit_sm_resp);
+
+        assert_eq!(decoded.dpf_result(), Some(DpfResult::Set));
+        assert_eq!(
+            decoded.delivery_failure_reason(),
+            Some(DeliveryFailureReason::DestinationUnavailable)
+        );
+
+        let mut deliver_sm = DeliverSm::default();
+
+        deliver_sm.set_dpf_result(Some(DpfResult::NotSet));
+
+        assert_eq!(
+            round_trip(&deliver_sm).dpf_result(),
+            Some(DpfResult::NotSet)
+        );
+
+        let mut deliver_sm_resp = DeliverSmResp::default();
+
+        deliver_sm_resp.set_delivery_failure_reason(Some(DeliveryFailureReason::Other(0x42)));
+
+        assert_eq!(
+            round_trip(&deliver_sm_resp).delivery_failure_reason(),
+            Some(DeliveryFailureReason::Other(0x42))
+        );
+
+        submit_sm.set_set_dpf(None);
+
+        assert_eq!(submit_sm.set_dpf(), None);
+    }
+
+    #[test]
+    fn more_messages_to_send() {
+        let mut submit_sm = SubmitSm::default();
+
+        assert_eq!(submit_sm.more_messages_to_send(), None);
+
+        for more in [true, false] {
+            submit_sm.set_more_messages_to_send(Some(more.into()));
+
+            let decoded = round_trip(&submit_sm);
+
+            assert_eq!(
+                decoded
+                    .more_messages_to_send()
+                    .map(MoreMessagesToSend::is_more_messages_to_follow),
+                Some(more)
+            );
+            assert_eq!(decoded.tlvs().len(), 1);
+        }
+    }
+
+    #[test]
+    fn callback_num() {
+        use crate::values::owned::CallbackNum;
+
+        let callback_num = CallbackNum::new(
+            DigitModeIndicator::Ascii,
+            Ton::International,
+            Npi::Isdn,
+            OctetString::new(b"491701234567").unwrap(),
+        );
+
+        let mut submit_sm = SubmitSm::default();
+
+        submit_sm.set_callback_num(Some(OctetString::from(&callback_num)));
+        submit_sm.set_callback_num_pres_ind(Some(CallbackNumPresInd::new(
+            Presentation::PresentationRestricted,
+            Screening::VerifiedAndPassed,
+        )));
+        submit_sm.set_callback_num_atag(Some(OctetString::new(b"\x00Hotline").unwrap()));
+
+        let decoded = round_trip(&submit_sm);
+
+        assert_eq!(
+            decoded.callback_num().map(CallbackNum::from),
+            Some(callback_num)
+        );
+        assert_eq!(
+            decoded.callback_num().map(|value| value.bytes()[0]),
+            Some(0x01)
+        );
+        assert_eq!(
+            decoded.callback_num_pres_ind(),
+            Some(CallbackNumPresInd::new(
+                Presentation::PresentationRestricted,
+                Screening::VerifiedAndPassed,
+            ))
+        );
+        assert_eq!(
+            decoded.callback_num_atag().map(OctetString::bytes),
+            Some(&b"\x00Hotline"[..])
+        );
+    }
+
+    #[test]
+    fn subaddress() {
+        let source = Subaddress::new(
+            SubaddressTag::NsapEven,
+            OctetString::new([0x49, 0x17, 0x01, 0x23]).unwrap(),
+        );
+        let dest = Subaddress::new(
+            SubaddressTag::NsapOdd,
+            OctetString::new([0x49, 0x17, 0x05]).unwrap(),
+        );
+
+        let mut deliver_sm = DeliverSm::default();
+
+        deliver_sm.set_source_subaddress(Some(source.clone()));
+        deliver_sm.set_dest_subaddress(Some(dest.clone()));
+
+        let decoded = round_trip(&deliver_sm);
+
+        assert_eq!(decoded.source_subaddress(), Some(&source));
+        assert_eq!(decoded.dest_subaddress(), Some(&dest));
+        assert_eq!(
+            decoded
+                .get_tlv(TlvTag::SourceSubaddress)
+                .map(Tlv::value_length),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn ussd_service_op() {
+        let mut submit_sm = SubmitSm::default();
+
+        submit_sm.set_ussd_service_op(Some(UssdServiceOp::UssrRequest));
+
+        let decoded = round_trip(&submit_sm);
+
+        assert_eq!(decoded.ussd_service_op(), Some(UssdServiceOp::UssrRequest));
+        assert_eq!(
+            decoded
+                .get_tlv(TlvTag::UssdServiceOp)
+                .map(Tlv::value_length),
+            Some(1)
+        );
+
+        let mut deliver_sm = DeliverSm::default();
+
+        deliver_sm.set_ussd_service_op(Some(UssdServiceOp::UssrConfirm));
+
+        assert_eq!(
+            round_trip(&deliver_sm).ussd_service_op(),
+            Some(UssdServiceOp::UssrConfirm)
+        );
+    }
+
+    #[test]
+    fn interactive_teleservice() {
+        let mut data_sm = DataSm::default();
+
+        data_sm.set_its_reply_type(Some(ItsReplyType::Menu));
+        data_sm.set_its_session_info(Some(ItsSessionInfo::new(3, 0b0000_0101)));
+
+        let decoded = round_trip(&data_sm);
+
+        assert_eq!(decoded.its_reply_type(), Some(ItsReplyType::Menu));
+        assert_eq!(
+            decoded.its_session_info(),
+            Some(ItsSessionInfo::new(3, 0b0000_0101))
+        );
+        assert_eq!(
+            decoded
+                .get_tlv(TlvTag::ItsSessionInfo)
+                .map(Tlv::value_length),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn message_waiting() {
+        let mut submit_sm = SubmitSm::default();
+
+        submit_sm.set_display_time(Some(DisplayTime::Temporary));
+        submit_sm.set_ms_msg_wait_facilities(Some(MsMsgWaitFacilities::new(
+            Indicator::Active,
+            TypeOfMessage::VoicemailMessageWaiting,
+        )));
+
+        let decoded = round_trip(&submit_sm);
+
+        assert_eq!(decoded.display_time(), Some(DisplayTime::Temporary));
+
+        let facilities = decoded.ms_msg_wait_facilities().unwrap();
+
+        assert_eq!(facilities.indicator, Indicator::Active);
+        assert_eq!(
+            facilities.type_of_message,
+            TypeOfMessage::VoicemailMessageWaiting
+        );
+        assert_eq!(u8::from(facilities), 0b1000_0000);
+    }
+
+    #[test]
+    fn ports() {
+        // WAP push
+        let mut submit_sm = SubmitSm::default();
+
+        submit_sm.set_source_port(Some(9200));
+        submit_sm.set_dest_port(Some(2948));
+
+        let decoded = round_trip(&submit_sm);
+
+        assert_eq!(decoded.source_port(), Some(9200));
+        assert_eq!(decoded.dest_port(), Some(2948));
+        assert_eq!(
+            decoded.get_tlv(TlvTag::DestPort).map(Tlv::value_length),
+            Some(2)
+        );
+
+        submit_sm.set_source_port(None);
+
+        assert_eq!(submit_sm.source_port(), None);
+        assert_eq!(submit_sm.dest_port(), Some(2948));
+    }
+
+    #[test]
+    fn language_indicator() {
+        let mut broadcast_sm = BroadcastSm::default();
+
+        broadcast_sm.set_language_indicator(Some(LanguageIndicator::French));
+
+        assert_eq!(
+            round_trip(&broadcast_sm).language_indicator(),
+            Some(LanguageIndicator::French)
+        );
+
+        let mut deliver_sm = DeliverSm::default();
+
+        // Values defined by CMT-136 beyond the named ones
+        deliver_sm.set_language_indicator(Some(LanguageIndicator::Other(0x0A)));
+
+        assert_eq!(
+            round_trip(&deliver_sm).language_indicator(),
+            Some(LanguageIndicator::Other(0x0A))
+        );
+    }
+
+    #[test]
+    fn privacy_indicator_and_priority() {
+        for privacy_indicator in [
+            PrivacyIndicator::NotRestricted,
+            PrivacyIndicator::Restricted,
+            PrivacyIndicator::Confidential,
+            PrivacyIndicator::Secret,
+        ] {
+            let mut submit_sm = SubmitSm::default();
+
+            submit_sm.set_privacy_indicator(Some(privacy_indicator));
+
+            assert_eq!(
+                round_trip(&submit_sm).privacy_indicator(),
+                Some(privacy_indicator)
+            );
+        }
+
+        // The mandatory priority_flag is typed by the network specific priority levels
+        for priority in [
+            GsmSms::None,
+            GsmSms::Priority1,
+            GsmSms::Priority2,
+            GsmSms::Priority3,
+        ] {
+            let submit_sm = SubmitSm::builder()
+                .priority_flag(PriorityFlag::from(priority))
+                .build();
+
+            let decoded = round_trip(&submit_sm);
+
+            assert_eq!(GsmSms::from(u8::from(decoded.priority_flag)), priority);
+        }
+    }
+
+    #[test]
+    fn number_of_messages() {
+        let mut submit_sm = SubmitSm::default();
+
+        // Voicemail notification with 3 stored messages
+        submit_sm.set_number_of_messages(Some(NumberOfMessages::from(3)));
+
+        let decoded = round_trip(&submit_sm);
+
+        assert_eq!(
+            decoded.number_of_messages(),
+            Some(NumberOfMessages::Allowed(3))
+        );
+        assert_eq!(decoded.number_of_messages().map(u8::from), Some(3));
+
+        submit_sm.set_number_of_messages(Some(NumberOfMessages::from(100)));
+
+        assert_eq!(
+            round_trip(&submit_sm).number_of_messages(),
+            Some(NumberOfMessages::Other(100))
+        );
+    }
+
+    #[test]
+    fn sms_signal() {
+        let mut submit_multi = SubmitMulti::default();
+
+        submit_multi.set_sms_signal(Some(0x0105));
+
+        let decoded = round_trip(&submit_multi);
+
+        assert_eq!(decoded.sms_signal(), Some(0x0105));
+        assert_eq!(
+            decoded.get_tlv(TlvTag::SmsSignal).map(Tlv::value_length),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn alert_on_message_delivery() {
+        let mut submit_sm = SubmitSm::default();
+
+        assert!(!submit_sm.has_alert_on_message_delivery());
+
+        // One byte form
+        submit_sm.set_alert_on_message_delivery(Some(AlertOnMessageDelivery::UseHighPriorityAlert));
+
+        let decoded = round_trip(&submit_sm);
+
+        assert!(decoded.has_alert_on_message_delivery());
+        assert_eq!(
+            decoded.alert_on_message_delivery(),
+            Some(AlertOnMessageDelivery::UseHighPriorityAlert)
+        );
+        assert_eq!(
+            decoded
+                .get_tlv(TlvTag::AlertOnMessageDelivery)
+                .map(Tlv::value_length),
+            Some(1)
+        );
+
+        // Zero-length form replaces the one byte form
+        let previous = submit_sm.set_alert_on_message_delivery_empty();
+
+        assert!(previous.is_some());
+
+        let encoded = submit_sm.encode_body();
+
+        assert_eq!(&encoded[encoded.len() - 4..], &[0x13, 0x0C, 0x00, 0x00]);
+
+        let decoded = SubmitSm::decode_body(&encoded).unwrap();
+
+        assert!(decoded.has_alert_on_message_delivery());
+        assert_eq!(decoded.alert_on_message_delivery(), None);
+        assert_eq!(decoded.tlvs().len(), 1);
+
+        submit_sm.set_alert_on_message_delivery(None);
+
+        assert!(!submit_sm.has_alert_on_message_delivery());
+    }
+}
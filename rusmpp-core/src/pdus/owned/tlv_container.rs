@@ -0,0 +1,355 @@
+use alloc::vec::Vec;
+
+use crate::tlvs::{
+    TlvTag,
+    owned::{
+        BroadcastRequestTlvValue, BroadcastResponseTlvValue, CancelBroadcastTlvValue,
+        MessageDeliveryRequestTlvValue, MessageDeliveryResponseTlvValue,
+        MessageSubmissionRequestTlvValue, MessageSubmissionResponseTlvValue,
+        QueryBroadcastResponseTlvValue, Tlv,
+    },
+};
+
+use super::*;
+
+/// `PDU`s with a list of [`Tlv`]s.
+///
+/// The default methods keep the invariants of the `PDU`,
+/// e.g. a `submit_sm` clears its `short_message` when a `message_payload` is set.
+pub trait TlvContainer {
+    /// The `TLV` values allowed in the `PDU`, e.g. [`MessageSubmissionRequestTlvValue`] for a `submit_sm`.
+    type TlvValue: Into<Tlv>;
+
+    /// The tags of the `TLV`s allowed in the `PDU`.
+    const TLV_TAGS: &'static [TlvTag];
+
+    /// Returns the `TLV`s.
+    fn tlvs(&self) -> &[Tlv];
+
+    /// Returns the `TLV`s mutably.
+    ///
+    /// Call [`tlvs_changed`](Self::tlvs_changed) after adding `TLV`s through this accessor.
+    fn tlvs_mut(&mut self) -> &mut Vec<Tlv>;
+
+    /// Restores the invariants of the `PDU` after `TLV`s were added.
+    fn tlvs_changed(&mut self) {}
+
+    /// Returns the last [`Tlv`] with the given `tag`, if any.
+    ///
+    /// See [`get_tlv`](crate::tlvs::owned::get_tlv). The `PDU`s of this crate look up large `TLV` lists in a [`TlvIndex`](crate::tlvs::owned::TlvIndex).
+    fn get_tlv(&self, tag: TlvTag) -> Option<&Tlv> {
+        crate::tlvs::owned::get_tlv(self.tlvs(), tag)
+    }
+
+    /// Sets the `TLV`, replacing the first `TLV` with the same tag or appending it if there is none.
+    ///
+    /// Further `TLV`s with the same tag are removed. Returns the replaced `TLV` returned by [`get_tlv`](Self::get_tlv), if any.
+    fn set_tlv(&mut self, tlv: impl Into<Self::TlvValue>) -> Option<Tlv> {
+        let replaced = crate::tlvs::owned::set_tlv(self.tlvs_mut(), tlv.into().into());
+
+        self.tlvs_changed();
+
+        replaced
+    }
+
+    /// Appends all `tlvs`.
+    fn extend_tlvs(&mut self, tlvs: impl IntoIterator<Item = impl Into<Self::TlvValue>>) {
+        self.tlvs_mut()
+            .extend(tlvs.into_iter().map(|tlv| tlv.into().into()));
+
+        self.tlvs_changed();
+    }
+
+    /// Removes all `TLV`s with the given `tag`. Returns the removed `TLV` returned by [`get_tlv`](Self::get_tlv), if any.
+    fn remove_tlv(&mut self, tag: TlvTag) -> Option<Tlv> {
+        crate::tlvs::owned::remove_tlv(self.tlvs_mut(), tag)
+    }
+
+    /// Removes the `TLV`s equal to an earlier `TLV`, keeping the first occurrence.
+    ///
+    /// See [`dedup_tlvs_by_value`](crate::tlvs::owned::dedup_tlvs_by_value).
+    fn dedup_tlvs_by_value(&mut self) {
+        crate::tlvs::owned::dedup_tlvs_by_value(self.tlvs_mut());
+    }
+
+    /// Copies the `TLV`s of `other` into `self`, e.g. to apply a `TLV` template to many `PDU`s.
+    ///
+    /// `TLV`s whose tag is not allowed in `self` are skipped,
+    /// unknown ([`TlvTag::Other`]) tags are vendor specific and always copied.
+    ///
+    /// If `overwrite` is `true`, the `TLV`s of `self` with a tag of a copied `TLV` are replaced.
+    /// Otherwise only the `TLV`s with a tag not yet in `self` are copied,
+    /// one per tag: the `TLV` returned by [`get_tlv`](Self::get_tlv) of `other`.
+    /// Copied `TLV`s are appended in their order in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::{
+    /// #     pdus::owned::{SubmitSm, TlvContainer},
+    /// #     tlvs::owned::{MessageSubmissionRequestTlvValue, Tlv},
+    /// # };
+    /// let template = SubmitSm::builder()
+    ///     .push_tlv(MessageSubmissionRequestTlvValue::UserMessageReference(1.into()))
+    ///     .push_tlv(MessageSubmissionRequestTlvValue::SourcePort(2))
+    ///     .build();
+    ///
+    /// let mut submit_sm = SubmitSm::builder()
+    ///     .push_tlv(MessageSubmissionRequestTlvValue::SourcePort(3))
+    ///     .build();
+    ///
+    /// submit_sm.merge_tlvs_from(&template, false);
+    ///
+    /// assert_eq!(
+    ///     submit_sm.tlvs(),
+    ///     [
+    ///         Tlv::from(MessageSubmissionRequestTlvValue::SourcePort(3)),
+    ///         Tlv::from(MessageSubmissionRequestTlvValue::UserMessageReference(1.into())),
+    ///     ]
+    /// );
+    /// ```
+    fn merge_tlvs_from(&mut self, other: &impl TlvContainer, overwrite: bool) {
+        merge_tlvs(self.tlvs_mut(), other.tlvs(), Self::TLV_TAGS, overwrite);
+
+        self.tlvs_changed();
+    }
+}
+
+macro_rules! impl_tlv_container {
+    ($($pdu:ident: $tlv_value:ident $(=> $changed:ident)?),* $(,)?) => {
+        $(
+            impl TlvContainer for $pdu {
+                type TlvValue = $tlv_value;
+
+                const TLV_TAGS: &'static [TlvTag] = $tlv_value::TAGS;
+
+                fn tlvs(&self) -> &[Tlv] {
+                    &self.tlvs
+                }
+
+                fn tlvs_mut(&mut self) -> &mut Vec<Tlv> {
+                    self.tlv_index.clear();
+
+                    &mut self.tlvs
+                }
+
+                fn get_tlv(&self, tag: TlvTag) -> Option<&Tlv> {
+                    self.tlv_index.get(&self.tlvs, tag)
+                }
+
+                $(
+                    fn tlvs_changed(&mut self) {
+                        self.$changed();
+                    }
+                )?
+            }
+        )*
+    };
+}
+
+impl_tlv_container!(
+    BroadcastSm: BroadcastRequestTlvValue,
+    BroadcastSmResp: BroadcastResponseTlvValue,
+    CancelBroadcastSm: CancelBroadcastTlvValue,
+    DataSm: MessageSubmissionRequestTlvValue,
+    DataSmResp: MessageDeliveryResponseTlvValue,
+    DeliverSm: MessageDeliveryRequestTlvValue => clear_short_message_if_message_payload_exists,
+    DeliverSmResp: MessageDeliveryResponseTlvValue,
+    QueryBroadcastSmResp: QueryBroadcastResponseTlvValue,
+    SubmitMulti: MessageSubmissionRequestTlvValue => clear_short_message_if_message_payload_exists,
+    SubmitMultiResp: MessageSubmissionResponseTlvValue,
+    SubmitSm: MessageSubmissionRequestTlvValue => clear_short_message_if_message_payload_exists,
+    SubmitSmResp: MessageSubmissionResponseTlvValue,
+);
+
+fn merge_tlvs(tlvs: &mut Vec<Tlv>, other: &[Tlv], allowed: &[TlvTag], overwrite: bool) {
+    let merged: Vec<&Tlv> = other
+        .iter()
+        .filter(|tlv| matches!(tlv.tag(), TlvTag::Other(_)) || allowed.contains(&tlv.tag()))
+        .collect();
+
+    if overwrite {
+        tlvs.retain(|tlv| !merged.iter().any(|merged| merged.tag() == tlv.tag()));
+        tlvs.extend(merged.into_iter().cloned());
+
+        return;
+    }
+
+    let existing: Vec<TlvTag> = tlvs.iter().map(Tlv::tag).collect();
+
+    // The last occurrence of each tag wins, see `get_tlv`
+    let last =
+        |position: usize, tag: TlvTag| !merged[position + 1..].iter().any(|tlv| tlv.tag() == tag);
+
+    tlvs.extend(
+        merged
+            .iter()
+            .enumerate()
+            .filter(|(position, tlv)| !existing.contains(&tlv.tag()) && last(*position, tlv.tag()))
+            .map(|(_, tlv)| (*tlv).clone()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tlvs::owned::{MessageDeliveryRequestTlvValue, TlvValue},
+        types::owned::{AnyOctetString, OctetString},
+        values::owned::MessagePayload,
+    };
+
+    use super::*;
+
+    fn template() -> SubmitSm {
+        SubmitSm::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::SourcePort(1))
+            .push_tlv(MessageSubmissionRequestTlvValue::DestPort(2))
+            .push_tlv(MessageSubmissionRequestTlvValue::Other {
+                tag: TlvTag::Other(0x1400),
+                value: AnyOctetString::new([0xAB]),
+            })
+            .build()
+    }
+
+    fn submit_sm() -> SubmitSm {
+        SubmitSm::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::SourcePort(3))
+            .push_tlv(MessageSubmissionRequestTlvValue::UserResponseCode(4))
+            .build()
+    }
+
+    #[test]
+    fn without_overwrite() {
+        let mut submit_sm = submit_sm();
+
+        submit_sm.merge_tlvs_from(&template(), false);
+
+        assert_eq!(
+            submit_sm.tlvs(),
+            [
+                Tlv::new(TlvValue::SourcePort(3)),
+                Tlv::new(TlvValue::UserResponseCode(4)),
+                Tlv::new(TlvValue::DestPort(2)),
+                Tlv::new(TlvValue::Other {
+                    tag: TlvTag::Other(0x1400),
+                    value: AnyOctetString::new([0xAB]),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn without_overwrite_duplicates() {
+        let template = SubmitSm::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::DestPort(1))
+            .push_tlv(MessageSubmissionRequestTlvValue::UserResponseCode(2))
+            .push_tlv(MessageSubmissionRequestTlvValue::DestPort(3))
+            .build();
+
+        let mut submit_sm = SubmitSm::default();
+
+        submit_sm.merge_tlvs_from(&template, false);
+
+        assert_eq!(
+            submit_sm.tlvs(),
+            [
+                Tlv::new(TlvValue::UserResponseCode(2)),
+                Tlv::new(TlvValue::DestPort(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn with_overwrite() {
+        let mut submit_sm = submit_sm();
+
+        submit_sm.merge_tlvs_from(&template(), true);
+
+        assert_eq!(
+            submit_sm.tlvs(),
+            [
+                Tlv::new(TlvValue::UserResponseCode(4)),
+                Tlv::new(TlvValue::SourcePort(1)),
+                Tlv::new(TlvValue::DestPort(2)),
+                Tlv::new(TlvValue::Other {
+                    tag: TlvTag::Other(0x1400),
+                    value: AnyOctetString::new([0xAB]),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn indexed_lookup_after_mutation() {
+        let mut submit_sm = SubmitSm::builder()
+            .tlvs(
+                (0..64)
+                    .map(|i| MessageSubmissionRequestTlvValue::Other {
+                        tag: TlvTag::Other(0x1400 + i),
+                        value: AnyOctetString::new([0xAB]),
+                    })
+                    .collect(),
+            )
+            .build();
+
+        let tag = TlvTag::SourcePort;
+
+        assert_eq!(submit_sm.get_tlv(tag), None);
+
+        submit_sm.push_tlv(MessageSubmissionRequestTlvValue::SourcePort(1));
+
+        assert_eq!(
+            submit_sm.get_tlv(tag),
+            Some(&Tlv::new(TlvValue::SourcePort(1)))
+        );
+
+        submit_sm.set_tlv(MessageSubmissionRequestTlvValue::SourcePort(2));
+
+        assert_eq!(
+            submit_sm.get_tlv(tag),
+            Some(&Tlv::new(TlvValue::SourcePort(2)))
+        );
+
+        submit_sm.remove_tlv(TlvTag::Other(0x1400));
+
+        assert_eq!(
+            submit_sm.get_tlv(tag),
+            crate::tlvs::owned::get_tlv(submit_sm.tlvs(), tag)
+        );
+        assert_eq!(submit_sm.get_tlv(TlvTag::Other(0x1400)), None);
+
+        submit_sm.remove_tlv(tag);
+
+        assert_eq!(submit_sm.get_tlv(tag), None);
+    }
+
+    #[test]
+    fn across_pdus() {
+        let deliver_sm = DeliverSm::builder()
+            .push_tlv(MessageDeliveryRequestTlvValue::SourcePort(1))
+            .push_tlv(MessageDeliveryRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new(b"Message Payload")),
+            ))
+            .push_tlv(MessageDeliveryRequestTlvValue::UserResponseCode(2))
+            .build();
+
+        let mut submit_sm = SubmitSm::builder()
+            .short_message(OctetString::new(b"Short Message").unwrap())
+            .build();
+
+        submit_sm.merge_tlvs_from(&deliver_sm, false);
+
+        assert_eq!(submit_sm.tlvs().len(), 3);
+
+        // The message_payload supersedes the short_message
+        assert!(submit_sm.short_message().is_empty());
+        assert_eq!(submit_sm.sm_length(), 0);
+
+        let mut submit_sm_resp = SubmitSmResp::default();
+
+        submit_sm_resp.merge_tlvs_from(&deliver_sm, true);
+
+        // None of the TLVs are allowed in a submit_sm_resp
+        assert!(submit_sm_resp.tlvs().is_empty());
+    }
+}
@@ -0,0 +1,429 @@
+//! Reassembly of concatenated messages.
+//!
+//! Long messages are split into parts, each delivered in its own `deliver_sm`.
+//! The parts carry their concatenation info either in a user data header (`UDH`) at the start of the `short_message`
+//! or in the `sar_msg_ref_num`, `sar_total_segments` and `sar_segment_seqnum` `TLV`s, see [`DeliverSm::concat_info`].
+
+use core::time::Duration;
+
+use alloc::vec::Vec;
+
+use crate::{
+    pdus::owned::{ConcatInfo, DeliverSm},
+    values::{DataCoding, owned::Address},
+};
+
+/// A message reassembled by a [`Reassembler`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReassembledMessage {
+    /// The sender of the message.
+    pub source: Address,
+    /// The [`DataCoding`] of the first part.
+    pub data_coding: DataCoding,
+    /// The concatenated user data of all parts, without their `UDH`s.
+    ///
+    /// The user data of messages that are not concatenated is kept as is.
+    pub payload: Vec<u8>,
+}
+
+/// Buffers the parts of concatenated messages until all parts arrived.
+///
+/// Parts are keyed by their sender and concatenation reference and may arrive in any order.
+/// Incomplete messages are dropped once they are older than the timeout. The clock is driven by [`Reassembler::advance`].
+///
+/// To bound the memory used by a peer sending parts of ever new messages, limit the incomplete messages using
+/// [`Reassembler::with_max_pending_messages`] and [`Reassembler::with_max_total_buffered_bytes`].
+/// The oldest incomplete messages are evicted once a limit is exceeded, see [`Reassembler::evicted`].
+///
+/// # Example
+///
+/// ```rust
+/// # use core::time::Duration;
+/// # use rusmpp_core::{
+/// #     pdus::owned::DeliverSm,
+/// #     reassembly::Reassembler,
+/// #     tlvs::owned::MessageDeliveryRequestTlvValue,
+/// #     types::owned::OctetString,
+/// # };
+/// let part = |sequence, text: &[u8]| {
+///     DeliverSm::builder()
+///         .short_message(OctetString::new(text).unwrap())
+///         .push_tlv(MessageDeliveryRequestTlvValue::SarMsgRefNum(42))
+///         .push_tlv(MessageDeliveryRequestTlvValue::SarTotalSegments(2))
+///         .push_tlv(MessageDeliveryRequestTlvValue::SarSegmentSeqnum(sequence))
+///         .build()
+/// };
+///
+/// let mut reassembler = Reassembler::new(Duration::from_secs(60));
+///
+/// assert!(reassembler.push(&part(2, b"World!")).is_none());
+///
+/// let message = reassembler.push(&part(1, b"Hello, ")).unwrap();
+///
+/// assert_eq!(message.payload, b"Hello, World!");
+/// ```
+#[derive(Debug)]
+pub struct Reassembler {
+    timeout: Duration,
+    /// Time elapsed since the creation, see [`Reassembler::advance`].
+    clock: Duration,
+    /// Incomplete messages, oldest first.
+    pending: Vec<Pending>,
+    max_pending_messages: usize,
+    max_total_buffered_bytes: usize,
+    /// Bytes of all buffered parts.
+    buffered_bytes: usize,
+    evicted: u64,
+}
+
+#[derive(Debug)]
+struct Pending {
+    source: Address,
+    reference: u16,
+    data_coding: DataCoding,
+    /// Parts by sequence number, starting at 1.
+    parts: Vec<Option<Vec<u8>>>,
+    /// Bytes of the buffered parts.
+    bytes: usize,
+    started: Duration,
+}
+
+impl Reassembler {
+    /// Creates a new [`Reassembler`] dropping incomplete messages after `timeout`.
+    pub const fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            clock: Duration::ZERO,
+            pending: Vec::new(),
+            max_pending_messages: usize::MAX,
+            max_total_buffered_bytes: usize::MAX,
+            buffered_bytes: 0,
+            evicted: 0,
+        }
+    }
+
+    /// Limits the number of incomplete messages.
+    ///
+    /// Unlimited by default.
+    pub const fn with_max_pending_messages(mut self, max_pending_messages: usize) -> Self {
+        self.max_pending_messages = max_pending_messages;
+        self
+    }
+
+    /// Limits the bytes of the buffered parts of all incomplete messages.
+    ///
+    /// Unlimited by default.
+    pub const fn with_max_total_buffered_bytes(mut self, max_total_buffered_bytes: usize) -> Self {
+        self.max_total_buffered_bytes = max_total_buffered_bytes;
+        self
+    }
+
+    /// Handles a received `deliver_sm`.
+    ///
+    /// Returns the reassembled message once all parts arrived. Messages that are not concatenated are returned right away.
+    pub fn push(&mut self, deliver_sm: &DeliverSm) -> Option<ReassembledMessage> {
+        let source = deliver_sm.source_address();
+
+        let Some((info, payload)) = deliver_sm.concat_part() else {
+            return Some(ReassembledMessage {
+                source,
+                data_coding: deliver_sm.data_coding,
+                payload: deliver_sm.message_bytes().to_vec(),
+            });
+        };
+
+        let ConcatInfo {
+            reference,
+            total,
+            sequence,
+        } = info;
+
+        if sequence == 0 || sequence > total {
+            return None;
+        }
+
+        let position = self
+            .pending
+            .iter()
+            .position(|pending| pending.source == source && pending.reference == reference);
+
+        // A reused reference with a different number of parts starts a new message
+        let position = match position {
+            Some(position) if self.pending[position].parts.len() == total as usize => position,
+            _ => {
+                if let Some(position) = position {
+                    self.remove(position);
+                }
+
+                self.pending.push(Pending {
+                    source,
+                    reference,
+                    data_coding: deliver_sm.data_coding,
+                    parts: alloc::vec![None; total as usize],
+                    bytes: 0,
+                    started: self.clock,
+                });
+
+                self.pending.len() - 1
+            }
+        };
+
+        let pending = &mut self.pending[position];
+
+        let replaced = pending.parts[sequence as usize - 1]
+            .replace(payload.to_vec())
+            .map_or(0, |part| part.len());
+
+        pending.bytes = pending.bytes - replaced + payload.len();
+        self.buffered_bytes = self.buffered_bytes - replaced + payload.len();
+
+        if pending.parts.iter().any(Option::is_none) {
+            while self.pending.len() > self.max_pending_messages
+                || self.buffered_bytes > self.max_total_buffered_bytes
+            {
+                self.remove(0);
+                self.evicted += 1;
+            }
+
+            return None;
+        }
+
+        let pending = self.remove(position);
+
+        Some(ReassembledMessage {
+            source: pending.source,
+            data_coding: pending.data_coding,
+            payload: pending.parts.into_iter().flatten().flatten().collect(),
+        })
+    }
+
+    /// Advances the clock by `elapsed`, dropping the incomplete messages older than the timeout.
+    ///
+    /// Returns the number of dropped messages.
+    pub fn advance(&mut self, elapsed: Duration) -> usize {
+        self.clock += elapsed;
+
+        let before = self.pending.len();
+
+        let (clock, timeout) = (self.clock, self.timeout);
+
+        self.pending
+            .retain(|pending| clock.saturating_sub(pending.started) < timeout);
+
+        self.buffered_bytes = self.pending.iter().map(|pending| pending.bytes).sum();
+
+        before - self.pending.len()
+    }
+
+    /// Returns the number of incomplete messages.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns the bytes of the buffered parts of all incomplete messages.
+    pub const fn buffered_bytes(&self) -> usize {
+        self.buffered_bytes
+    }
+
+    /// Returns the number of incomplete messages evicted because a limit was exceeded.
+    pub const fn evicted(&self) -> u64 {
+        self.evicted
+    }
+
+    fn remove(&mut self, position: usize) -> Pending {
+        let pending = self.pending.remove(position);
+
+        self.buffered_bytes -= pending.bytes;
+
+        pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tlvs::owned::MessageDeliveryRequestTlvValue,
+        types::owned::{COctetString, OctetString},
+        values::{EsmClass, GsmFeatures},
+    };
+
+    use super::*;
+
+    fn udh_part(source: &str, reference: u8, total: u8, sequence: u8, text: &[u8]) -> DeliverSm {
+        let mut short_message = alloc::vec![0x05, 0x00, 0x03, reference, total, sequence];
+
+        short_message.extend_from_slice(text);
+
+        DeliverSm::builder()
+            .source_addr(source.parse::<COctetString<1, 21>>().unwrap())
+            .esm_class(EsmClass {
+                gsm_features: GsmFeatures::UdhiIndicator,
+                ..Default::default()
+            })
+            .short_message(OctetString::new(short_message).unwrap())
+            .build()
+    }
+
+    #[test]
+    fn out_of_order() {
+        let mut reassembler = Reassembler::new(Duration::from_secs(60));
+
+        assert!(
+            reassembler
+                .push(&udh_part("491701234567", 7, 2, 2, b"World!"))
+                .is_none()
+        );
+
+        // Same reference from another sender
+        assert!(
+            reassembler
+                .push(&udh_part("491707654321", 7, 2, 1, b"Bye, "))
+                .is_none()
+        );
+
+        assert_eq!(reassembler.pending(), 2);
+
+        let message = reassembler
+            .push(&udh_part("491701234567", 7, 2, 1, b"Hello, "))
+            .unwrap();
+
+        assert_eq!(message.source.addr.as_str(), "491701234567");
+        assert_eq!(message.payload, b"Hello, World!");
+        assert_eq!(reassembler.pending(), 1);
+    }
+
+    #[test]
+    fn udh_16_bit_reference() {
+        let part = |sequence, text: &[u8]| {
+            let mut short_message = alloc::vec![0x06, 0x08, 0x04, 0x12, 0x34, 2, sequence];
+
+            short_message.extend_from_slice(text);
+
+            DeliverSm::builder()
+                .esm_class(EsmClass {
+                    gsm_features: GsmFeatures::SetUdhiAndReplyPath,
+                    ..Default::default()
+                })
+                .short_message(OctetString::new(short_message).unwrap())
+                .build()
+        };
+
+        let mut reassembler = Reassembler::new(Duration::from_secs(60));
+
+        assert!(reassembler.push(&part(1, b"ab")).is_none());
+        assert_eq!(reassembler.push(&part(2, b"cd")).unwrap().payload, b"abcd");
+    }
+
+    #[test]
+    fn not_concatenated() {
+        let deliver_sm = DeliverSm::builder()
+            .short_message(OctetString::new(b"Hi").unwrap())
+            .build();
+
+        let mut reassembler = Reassembler::new(Duration::from_secs(60));
+
+        assert_eq!(reassembler.push(&deliver_sm).unwrap().payload, b"Hi");
+
+        // SAR TLVs without the total are ignored
+        let deliver_sm = DeliverSm::builder()
+            .short_message(OctetString::new(b"Hi").unwrap())
+            .push_tlv(MessageDeliveryRequestTlvValue::SarMsgRefNum(1))
+            .build();
+
+        assert_eq!(reassembler.push(&deliver_sm).unwrap().payload, b"Hi");
+        assert_eq!(reassembler.pending(), 0);
+    }
+
+    #[test]
+    fn timeout() {
+        let mut reassembler = Reassembler::new(Duration::from_secs(60));
+
+        reassembler.push(&udh_part("491701234567", 1, 2, 1, b"a"));
+
+        assert_eq!(reassembler.advance(Duration::from_secs(30)), 0);
+
+        reassembler.push(&udh_part("491701234567", 2, 2, 1, b"b"));
+
+        assert_eq!(reassembler.advance(Duration::from_secs(30)), 1);
+        assert_eq!(reassembler.pending(), 1);
+
+        // The expired message starts over
+        assert!(
+            reassembler
+                .push(&udh_part("491701234567", 1, 2, 2, b"c"))
+                .is_none()
+        );
+
+        assert_eq!(
+            reassembler
+                .push(&udh_part("491701234567", 2, 2, 2, b"d"))
+                .unwrap()
+                .payload,
+            b"bd"
+        );
+    }
+
+    #[test]
+    fn max_pending_messages() {
+        let mut reassembler =
+            Reassembler::new(Duration::from_secs(60)).with_max_pending_messages(2);
+
+        reassembler.push(&udh_part("491701234567", 1, 2, 1, b"a"));
+        reassembler.push(&udh_part("491701234567", 2, 2, 1, b"b"));
+        reassembler.push(&udh_part("491701234567", 3, 2, 1, b"c"));
+
+        assert_eq!(reassembler.pending(), 2);
+        assert_eq!(reassembler.evicted(), 1);
+        assert_eq!(reassembler.buffered_bytes(), 2);
+
+        // The oldest message was evicted and starts over
+        assert!(
+            reassembler
+                .push(&udh_part("491701234567", 1, 2, 2, b"a"))
+                .is_none()
+        );
+        assert_eq!(reassembler.evicted(), 2);
+
+        assert_eq!(
+            reassembler
+                .push(&udh_part("491701234567", 3, 2, 2, b"d"))
+                .unwrap()
+                .payload,
+            b"cd"
+        );
+        assert_eq!(reassembler.pending(), 1);
+    }
+
+    #[test]
+    fn max_total_buffered_bytes() {
+        let mut reassembler =
+            Reassembler::new(Duration::from_secs(60)).with_max_total_buffered_bytes(10);
+
+        reassembler.push(&udh_part("491701234567", 1, 3, 1, b"aaaa"));
+        reassembler.push(&udh_part("491701234567", 1, 3, 2, b"aaaa"));
+        reassembler.push(&udh_part("491701234567", 2, 2, 1, b"bb"));
+
+        assert_eq!(reassembler.evicted(), 0);
+        assert_eq!(reassembler.buffered_bytes(), 10);
+
+        // A repeated part replaces the buffered one
+        reassembler.push(&udh_part("491701234567", 2, 2, 1, b"bb"));
+
+        assert_eq!(reassembler.buffered_bytes(), 10);
+
+        reassembler.push(&udh_part("491701234567", 3, 2, 1, b"c"));
+
+        assert_eq!(reassembler.evicted(), 1);
+        assert_eq!(reassembler.pending(), 2);
+        assert_eq!(reassembler.buffered_bytes(), 3);
+
+        // Completed messages are not counted
+        reassembler.push(&udh_part("491701234567", 2, 2, 2, b"b"));
+
+        assert_eq!(reassembler.buffered_bytes(), 1);
+
+        reassembler.advance(Duration::from_secs(60));
+
+        assert_eq!(reassembler.buffered_bytes(), 0);
+    }
+}
@@ -0,0 +1,195 @@
+//! Traffic statistics over decoded commands.
+
+use alloc::collections::BTreeMap;
+
+use crate::{CommandId, command::owned::Command, encode::Length, tlvs::TlvTag};
+
+/// Accumulates the traffic mix of a stream of [`Command`]s.
+///
+/// Tracks the number of commands per [`CommandId`], the average and maximum size of the encoded commands
+/// and how often each `TLV` is used. Sizes are the `command_length`s, including the header.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{
+/// #     CommandId, CommandStatus,
+/// #     command::owned::Command,
+/// #     pdus::owned::{Pdu, SubmitSm},
+/// #     stats::Stats,
+/// # };
+/// let mut stats = Stats::new();
+///
+/// stats.record(&Command::new(CommandStatus::EsmeRok, 1, Pdu::EnquireLink));
+/// stats.record(&Command::new(CommandStatus::EsmeRok, 2, Pdu::EnquireLink));
+///
+/// assert_eq!(stats.count(), 2);
+/// assert_eq!(stats.count_of(CommandId::EnquireLink), 2);
+/// assert_eq!(stats.max_size(), 16);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stats {
+    commands: BTreeMap<CommandId, u64>,
+    tlvs: BTreeMap<TlvTag, u64>,
+    count: u64,
+    total_size: u64,
+    max_size: usize,
+}
+
+impl Stats {
+    /// Creates a new, empty [`Stats`].
+    pub const fn new() -> Self {
+        Self {
+            commands: BTreeMap::new(),
+            tlvs: BTreeMap::new(),
+            count: 0,
+            total_size: 0,
+            max_size: 0,
+        }
+    }
+
+    /// Records a command.
+    pub fn record(&mut self, command: &Command) {
+        let size = 4 + command.length();
+
+        *self.commands.entry(command.id()).or_default() += 1;
+
+        self.count += 1;
+        self.total_size += size as u64;
+        self.max_size = self.max_size.max(size);
+
+        for tlv in command.pdu().map(|pdu| pdu.tlvs()).unwrap_or_default() {
+            *self.tlvs.entry(tlv.tag()).or_default() += 1;
+        }
+    }
+
+    /// Returns the number of recorded commands.
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the number of recorded commands with the given `id`.
+    pub fn count_of(&self, id: CommandId) -> u64 {
+        self.commands.get(&id).copied().unwrap_or_default()
+    }
+
+    /// Returns the number of recorded commands per [`CommandId`], ordered by id.
+    pub fn counts(&self) -> impl Iterator<Item = (CommandId, u64)> + '_ {
+        self.commands.iter().map(|(id, count)| (*id, *count))
+    }
+
+    /// Returns the average size of the recorded commands, `0` if none were recorded.
+    pub fn average_size(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        self.total_size as f64 / self.count as f64
+    }
+
+    /// Returns the size of the largest recorded command, `0` if none were recorded.
+    pub const fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Returns how often a `TLV` with the given `tag` was recorded.
+    pub fn tlv_count(&self, tag: TlvTag) -> u64 {
+        self.tlvs.get(&tag).copied().unwrap_or_default()
+    }
+
+    /// Returns how often each `TLV` was recorded, ordered by tag.
+    pub fn tlv_counts(&self) -> impl Iterator<Item = (TlvTag, u64)> + '_ {
+        self.tlvs.iter().map(|(tag, count)| (*tag, *count))
+    }
+}
+
+impl<'a> Extend<&'a Command> for Stats {
+    fn extend<I: IntoIterator<Item = &'a Command>>(&mut self, commands: I) {
+        for command in commands {
+            self.record(command);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::{
+        CommandStatus,
+        pdus::owned::{Pdu, SubmitSm},
+        tlvs::owned::MessageSubmissionRequestTlvValue,
+        types::owned::{AnyOctetString, OctetString},
+        values::{UserMessageReference, owned::MessagePayload},
+    };
+
+    use super::*;
+
+    #[test]
+    fn aggregates() {
+        let submit_sm = SubmitSm::builder()
+            .short_message(OctetString::new(b"Hi").unwrap())
+            .push_tlv(MessageSubmissionRequestTlvValue::UserMessageReference(
+                UserMessageReference::new(1),
+            ))
+            .build();
+
+        let long_submit_sm = SubmitSm::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::UserMessageReference(
+                UserMessageReference::new(2),
+            ))
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new([b'a'; 300])),
+            ))
+            .build();
+
+        let commands = [
+            Command::new(CommandStatus::EsmeRok, 1, submit_sm),
+            Command::new(CommandStatus::EsmeRok, 2, long_submit_sm),
+            Command::new(CommandStatus::EsmeRok, 3, Pdu::EnquireLink),
+            Command::new(CommandStatus::EsmeRok, 4, Pdu::EnquireLinkResp),
+        ];
+
+        let sizes: Vec<_> = commands
+            .iter()
+            .map(|command| 4 + command.length())
+            .collect();
+
+        let mut stats = Stats::new();
+
+        stats.extend(&commands);
+
+        assert_eq!(stats.count(), 4);
+        assert_eq!(stats.count_of(CommandId::SubmitSm), 2);
+        assert_eq!(stats.count_of(CommandId::EnquireLink), 1);
+        assert_eq!(stats.count_of(CommandId::Unbind), 0);
+        assert_eq!(
+            stats.counts().collect::<Vec<_>>(),
+            [
+                (CommandId::SubmitSm, 2),
+                (CommandId::EnquireLink, 1),
+                (CommandId::EnquireLinkResp, 1),
+            ]
+        );
+
+        assert_eq!(stats.max_size(), sizes[1]);
+        assert_eq!(
+            stats.average_size(),
+            sizes.iter().sum::<usize>() as f64 / 4.0
+        );
+
+        assert_eq!(stats.tlv_count(TlvTag::UserMessageReference), 2);
+        assert_eq!(stats.tlv_count(TlvTag::MessagePayload), 1);
+        assert_eq!(stats.tlv_count(TlvTag::SourcePort), 0);
+        assert_eq!(stats.tlv_counts().count(), 2);
+    }
+
+    #[test]
+    fn empty() {
+        let stats = Stats::default();
+
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.average_size(), 0.0);
+        assert_eq!(stats.max_size(), 0);
+    }
+}
@@ -0,0 +1,226 @@
+//! Integrity-checked framing for persisted commands.
+//!
+//! Stored commands are framed as the encoded command, including its `command_length`,
+//! followed by the big-endian `CRC-32` (`IEEE 802.3`) of these bytes.
+//! The trailer detects storage corruption when the command is read back.
+//!
+//! This framing is meant for storage only and must not be sent to an `SMPP` peer.
+
+use alloc::vec::Vec;
+
+use crate::{
+    command::owned::{Command, encode_many},
+    decode::DecodeError,
+};
+
+/// Length of the `CRC-32` trailer.
+pub const CHECKSUM_LENGTH: usize = 4;
+
+/// Appends the encoded `command` followed by its `CRC-32` trailer to `buf`.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{
+/// #     CommandStatus,
+/// #     command::owned::Command,
+/// #     pdus::owned::Pdu,
+/// #     storage::{read_checked, write_checked},
+/// # };
+/// let command = Command::new(CommandStatus::EsmeRok, 1, Pdu::EnquireLink);
+///
+/// let mut buf = Vec::new();
+///
+/// write_checked(&command, &mut buf);
+/// write_checked(&command, &mut buf);
+///
+/// let (decoded, rest) = read_checked(&buf).unwrap();
+///
+/// assert_eq!(decoded, command);
+/// assert_eq!(rest.len(), 20);
+/// ```
+pub fn write_checked(command: &Command, buf: &mut Vec<u8>) {
+    let start = buf.len();
+
+    encode_many(core::slice::from_ref(command), buf);
+
+    let checksum = crc32(&buf[start..]);
+
+    buf.extend_from_slice(&checksum.to_be_bytes());
+}
+
+/// Reads a command written by [`write_checked`] from the start of `bytes`.
+///
+/// Returns the command and the remaining bytes, starting at the next stored command.
+pub fn read_checked(bytes: &[u8]) -> Result<(Command, &[u8]), ReadCheckedError> {
+    let header = crate::command::peek_header(bytes).ok_or(ReadCheckedError::Incomplete)?;
+
+    let command_length = header.command_length as usize;
+
+    if bytes.len() < command_length.saturating_add(CHECKSUM_LENGTH) {
+        return Err(ReadCheckedError::Incomplete);
+    }
+
+    let (frame, rest) = bytes.split_at(command_length);
+    let (trailer, rest) = rest.split_at(CHECKSUM_LENGTH);
+
+    let expected = u32::from_be_bytes([trailer[0], trailer[1], trailer[2], trailer[3]]);
+    let actual = crc32(frame);
+
+    if expected != actual {
+        return Err(ReadCheckedError::ChecksumMismatch { expected, actual });
+    }
+
+    let command = Command::try_from(frame).map_err(ReadCheckedError::Decode)?;
+
+    Ok((command, rest))
+}
+
+/// An error that can occur when reading a command using [`read_checked`].
+#[derive(Debug)]
+pub enum ReadCheckedError {
+    /// The input ends before the command or its trailer.
+    Incomplete,
+    /// The stored `CRC-32` does not match the stored command.
+    ChecksumMismatch { expected: u32, actual: u32 },
+    /// The checksum matches but the command failed to decode.
+    Decode(DecodeError),
+}
+
+impl core::fmt::Display for ReadCheckedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ReadCheckedError::Incomplete => write!(f, "Incomplete stored command"),
+            ReadCheckedError::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "Checksum mismatch. expected: {expected:#010X}, actual: {actual:#010X}"
+            ),
+            ReadCheckedError::Decode(error) => write!(f, "Decode error: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for ReadCheckedError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ReadCheckedError::Decode(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+
+            bit += 1;
+        }
+
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+};
+
+/// `CRC-32` (`IEEE 802.3`) of `bytes`.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, byte| {
+        CRC32_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CommandStatus,
+        pdus::owned::SubmitSm,
+        types::owned::{COctetString, OctetString},
+    };
+
+    use super::*;
+
+    fn command() -> Command {
+        Command::new(
+            CommandStatus::EsmeRok,
+            42,
+            SubmitSm::builder()
+                .destination_addr(COctetString::new(b"491701234567\0").unwrap())
+                .short_message(OctetString::new(b"Hello").unwrap())
+                .build(),
+        )
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut buf = Vec::new();
+
+        write_checked(&command(), &mut buf);
+        write_checked(&Command::default(), &mut buf);
+
+        let (first, rest) = read_checked(&buf).unwrap();
+        let (second, rest) = read_checked(rest).unwrap();
+
+        assert_eq!(first, command());
+        assert_eq!(second, Command::default());
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn flipped_bit() {
+        let mut buf = Vec::new();
+
+        write_checked(&command(), &mut buf);
+
+        for index in 0..buf.len() {
+            for bit in 0..8 {
+                let mut corrupted = buf.clone();
+
+                corrupted[index] ^= 1 << bit;
+
+                // A flipped command_length may also truncate the stored command
+                assert!(
+                    matches!(
+                        read_checked(&corrupted),
+                        Err(ReadCheckedError::ChecksumMismatch { .. }
+                            | ReadCheckedError::Incomplete)
+                    ),
+                    "index: {index}, bit: {bit}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn incomplete() {
+        let mut buf = Vec::new();
+
+        write_checked(&command(), &mut buf);
+
+        assert!(matches!(
+            read_checked(&buf[..buf.len() - 1]),
+            Err(ReadCheckedError::Incomplete)
+        ));
+        assert!(matches!(
+            read_checked(&buf[..8]),
+            Err(ReadCheckedError::Incomplete)
+        ));
+    }
+}
@@ -0,0 +1,203 @@
+//! Tests for the [`Rusmpp`](rusmpp_macros::Rusmpp) derive attributes.
+
+use rusmpp_macros::Rusmpp;
+
+use crate::{
+    decode::{
+        DecodeError,
+        owned::{Decode, DecodeWithKey, DecodeWithLength},
+    },
+    encode::{Encode, Length},
+    types::owned::{AnyOctetString, COctetString},
+};
+
+/// A legacy `TLV` whose `value_length` includes a 2-byte checksum.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Rusmpp)]
+#[rusmpp(crate = "crate", decode = owned)]
+struct ChecksummedTlv {
+    tag: u16,
+    value_length: u16,
+    checksum: u16,
+    #[rusmpp(length = u16::saturating_sub(value_length, 2))]
+    value: AnyOctetString,
+}
+
+#[test]
+fn length_expression() {
+    let tlv = ChecksummedTlv {
+        tag: 0x1400,
+        value_length: 5,
+        checksum: 0xABCD,
+        value: AnyOctetString::new(b"abc"),
+    };
+
+    let mut buf = [0; 16];
+    let size = tlv.encode(&mut buf);
+
+    assert_eq!(size, tlv.length());
+    assert_eq!(size, 9);
+
+    // Trailing bytes are not part of the value
+    buf[size..size + 2].copy_from_slice(&[0xFF, 0xFF]);
+
+    let (decoded, decoded_size) = ChecksummedTlv::decode(&buf[..size + 2], size + 2).unwrap();
+
+    assert_eq!(decoded_size, size);
+    assert_eq!(decoded, tlv);
+}
+
+#[test]
+fn length_expression_malformed() {
+    // value_length: 1, shorter than the checksum
+    let buf = [0x14, 0x00, 0x00, 0x01, 0xAB, 0xCD];
+
+    let (decoded, size) = ChecksummedTlv::decode(&buf, buf.len()).unwrap();
+
+    assert_eq!(size, 6);
+    assert_eq!(decoded.value_length, 1);
+    assert!(decoded.value.is_empty());
+}
+
+/// Value of a nested `TLV`, selected by its tag and subtype.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NestedValue {
+    Balance(u32),
+    Currency(COctetString<1, 4>),
+    Other(AnyOctetString),
+}
+
+impl Length for NestedValue {
+    fn length(&self) -> usize {
+        match self {
+            NestedValue::Balance(value) => value.length(),
+            NestedValue::Currency(value) => value.length(),
+            NestedValue::Other(value) => value.length(),
+        }
+    }
+}
+
+impl Encode for NestedValue {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        match self {
+            NestedValue::Balance(value) => value.encode(dst),
+            NestedValue::Currency(value) => value.encode(dst),
+            NestedValue::Other(value) => value.encode(dst),
+        }
+    }
+}
+
+impl DecodeWithKey for NestedValue {
+    type Key = (u16, u8);
+
+    fn decode(key: Self::Key, src: &[u8], length: usize) -> Result<(Self, usize), DecodeError> {
+        match key {
+            (0x1500, 0x01) => {
+                let (value, size) = <u32 as Decode>::decode(src)?;
+
+                Ok((NestedValue::Balance(value), size))
+            }
+            (0x1500, 0x02) => {
+                let (value, size) = <COctetString<1, 4> as Decode>::decode(src)?;
+
+                Ok((NestedValue::Currency(value), size))
+            }
+            _ => {
+                let (value, size) = AnyOctetString::decode(src, length)?;
+
+                Ok((NestedValue::Other(value), size))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Rusmpp)]
+#[rusmpp(crate = "crate", decode = owned)]
+struct NestedTlv {
+    tag: u16,
+    subtype: u8,
+    value_length: u16,
+    #[rusmpp(key = (tag, subtype), length = value_length)]
+    value: Option<NestedValue>,
+}
+
+#[test]
+fn composite_key() {
+    let tlvs = [
+        NestedTlv {
+            tag: 0x1500,
+            subtype: 0x01,
+            value_length: 4,
+            value: Some(NestedValue::Balance(1000)),
+        },
+        NestedTlv {
+            tag: 0x1500,
+            subtype: 0x02,
+            value_length: 4,
+            value: Some(NestedValue::Currency(COctetString::new(b"EUR\0").unwrap())),
+        },
+        // Same tag, unknown subtype
+        NestedTlv {
+            tag: 0x1500,
+            subtype: 0x03,
+            value_length: 4,
+            value: Some(NestedValue::Other(AnyOctetString::new([0, 0, 3, 232]))),
+        },
+        NestedTlv {
+            tag: 0x1500,
+            subtype: 0x01,
+            value_length: 0,
+            value: None,
+        },
+    ];
+
+    for tlv in tlvs {
+        let mut buf = [0; 16];
+        let size = tlv.encode(&mut buf);
+
+        let (decoded, decoded_size) =
+            <NestedTlv as DecodeWithLength>::decode(&buf[..size], size).unwrap();
+
+        assert_eq!(decoded_size, size);
+        assert_eq!(decoded, tlv);
+    }
+}
+
+/// `test = roundtrip` catching a field that does not decode to what it encodes.
+#[cfg(feature = "arbitrary")]
+mod round_trip {
+    use super::*;
+
+    /// Decodes to one more than it encodes.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, arbitrary::Arbitrary)]
+    struct BrokenByte(u8);
+
+    impl Length for BrokenByte {
+        fn length(&self) -> usize {
+            1
+        }
+    }
+
+    impl Encode for BrokenByte {
+        fn encode(&self, dst: &mut [u8]) -> usize {
+            self.0.encode(dst)
+        }
+    }
+
+    impl Decode for BrokenByte {
+        fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+            let (value, size) = <u8 as Decode>::decode(src)?;
+
+            Ok((BrokenByte(value.wrapping_add(1)), size))
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Rusmpp, arbitrary::Arbitrary)]
+    #[rusmpp(
+        decode = owned,
+        test = roundtrip(should_panic = "Decoded value does not encode back to its bytes")
+    )]
+    struct BrokenPdu {
+        priority_flag: u8,
+        protocol_id: BrokenByte,
+    }
+}
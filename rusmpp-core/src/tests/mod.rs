@@ -1,6 +1,8 @@
 pub mod borrowed;
 pub mod owned;
 
+mod derive;
+
 /// Trait for creating test instances of a type.
 pub trait TestInstance: Sized {
     /// Create test instances of the type.
@@ -1,4 +1,4 @@
-use std::boxed::Box;
+use std::{boxed::Box, string::ToString};
 
 use crate::{
     CommandId,
@@ -55,6 +55,90 @@ where
     }
 }
 
+/// Property test for encoding and decoding of a type.
+///
+/// Generates values of `T` with its [`Arbitrary`](arbitrary::Arbitrary) implementation from deterministic pseudo-random bytes,
+/// encodes them and decodes them back.
+///
+/// Arbitrary values are not necessarily valid `SMPP` values, e.g. a [`COctetString`](crate::types::owned::COctetString) without a null terminator.
+/// Values that fail to decode are skipped and counted, at least 1/64 of the cases must decode. Decoded values must encode back to exactly the bytes they were decoded from
+/// and decode again to themselves.
+#[cfg(feature = "arbitrary")]
+pub fn encode_decode_arbitrary<T>()
+where
+    T: for<'a> arbitrary::Arbitrary<'a> + core::fmt::Debug + PartialEq + Encode + DecodeWithLength,
+{
+    const CASES: u64 = 4096;
+
+    let mut decoded_cases = 0;
+    let mut skipped_cases = 0;
+
+    for case in 0..CASES {
+        // xorshift64, biased towards zeros and ASCII to produce decodable strings
+        let mut state = case.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+
+        let bytes: alloc::vec::Vec<u8> = (0..512)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+
+                match state % 8 {
+                    0 => 0,
+                    1 => (state >> 32) as u8,
+                    _ => (state >> 32) as u8 & 0x7F,
+                }
+            })
+            .collect();
+
+        let Ok(original) = arbitrary::Unstructured::new(&bytes).arbitrary::<T>() else {
+            skipped_cases += 1;
+
+            continue;
+        };
+
+        let encoded = encode_to_vec(&original);
+
+        let Ok((decoded, size)) = T::decode(&encoded, encoded.len()) else {
+            skipped_cases += 1;
+
+            continue;
+        };
+
+        assert_eq!(
+            encode_to_vec(&decoded),
+            &encoded[..size],
+            "Decoded value does not encode back to its bytes, case: {case}, original: {original:?}, decoded: {decoded:?}"
+        );
+
+        let (decoded_again, _) =
+            T::decode(&encoded[..size], size).expect("Failed to decode re-encoded value");
+
+        assert_eq!(decoded_again, decoded, "case: {case}");
+
+        decoded_cases += 1;
+    }
+
+    assert_eq!(decoded_cases + skipped_cases, CASES);
+
+    // Most arbitrary values are not valid SMPP values, but a test that skips nearly everything tests nothing
+    assert!(
+        decoded_cases >= CASES / 64,
+        "Too few arbitrary values could be decoded, decoded: {decoded_cases}, skipped: {skipped_cases}"
+    );
+}
+
+#[cfg(feature = "arbitrary")]
+fn encode_to_vec<T: Encode>(value: &T) -> alloc::vec::Vec<u8> {
+    let mut buf = alloc::vec![0; value.length()];
+
+    let size = value.encode(&mut buf);
+
+    buf.truncate(size);
+
+    buf
+}
+
 /// Trait for chaining test commands.
 ///
 /// Type erased, otherwise rustc will allocate 30 quintillion petabytes during monomorphization when compiling [`test_commands`] and fail due to `OOM`.
@@ -131,6 +215,119 @@ pub fn test_commands() -> alloc::vec::Vec<Command> {
         .collect()
 }
 
+/// Asserts that `command` decodes back to itself after encoding, including the `command_length`.
+///
+/// On failure, the path of the first differing field is reported, e.g. `pdu.SubmitSm.short_message`.
+pub fn assert_symmetric(command: &Command) {
+    let command_length = 4 + command.length();
+
+    let mut buf = alloc::vec![0; command_length];
+
+    buf[..4].copy_from_slice(&(command_length as u32).to_be_bytes());
+    command.encode(&mut buf[4..]);
+
+    let decoded = Command::try_from(&buf[..])
+        .unwrap_or_else(|error| panic!("Failed to decode {:?}: {error}", command.id()));
+
+    if let Some(difference) = difference(command, &decoded) {
+        panic!(
+            "Decoded {:?} differs from the encoded command. {difference}",
+            command.id()
+        );
+    }
+}
+
+/// Describes the first difference between the debug representations of `expected` and `actual`, if any.
+fn difference(expected: &Command, actual: &Command) -> Option<alloc::string::String> {
+    let expected = std::format!("{expected:#?}");
+    let actual = std::format!("{actual:#?}");
+
+    let expected_lines: alloc::vec::Vec<_> = expected.lines().collect();
+    let actual_lines: alloc::vec::Vec<_> = actual.lines().collect();
+
+    let index = (0..expected_lines.len().max(actual_lines.len()))
+        .find(|&index| expected_lines.get(index) != actual_lines.get(index))?;
+
+    fn line<'a>(lines: &[&'a str], index: usize) -> &'a str {
+        lines.get(index).map(|line| line.trim()).unwrap_or("<none>")
+    }
+
+    Some(std::format!(
+        "field: {}, expected: `{}`, actual: `{}`",
+        field_path(&expected_lines, index),
+        line(&expected_lines, index),
+        line(&actual_lines, index),
+    ))
+}
+
+/// Builds the path of the field at `index` from the indentation of the pretty-printed debug `lines`.
+fn field_path(lines: &[&str], index: usize) -> alloc::string::String {
+    let indentation = |line: &str| line.len() - line.trim_start().len();
+
+    let name = |line: &str| {
+        let line = line.trim();
+
+        line.split([':', ' ', '(', '{'])
+            .next()
+            .unwrap_or(line)
+            .to_string()
+    };
+
+    let mut path = alloc::vec::Vec::new();
+
+    if let Some(line) = lines.get(index) {
+        path.push(name(line));
+
+        let mut current = indentation(line);
+
+        for line in lines[..index].iter().rev() {
+            if indentation(line) < current {
+                current = indentation(line);
+
+                path.push(name(line));
+            }
+        }
+    }
+
+    // The outermost line is the command itself
+    path.pop();
+    path.reverse();
+    // Newtype variants repeat their inner type, e.g. `SubmitSm(SubmitSm { .. })`
+    path.dedup();
+    path.join(".")
+}
+
+#[test]
+fn symmetry() {
+    for command in test_commands() {
+        assert_symmetric(&command);
+    }
+}
+
+#[test]
+fn symmetry_reports_field() {
+    use crate::{CommandStatus, types::owned::OctetString};
+
+    let command = |short_message: &[u8]| {
+        Command::new(
+            CommandStatus::EsmeRok,
+            1,
+            SubmitSm::builder()
+                .short_message(OctetString::new(short_message).unwrap())
+                .build(),
+        )
+    };
+
+    let reported = difference(&command(b"Hi"), &command(b"Ho")).unwrap();
+
+    assert!(
+        reported.starts_with("field: pdu.SubmitSm.short_message.bytes,"),
+        "{reported}"
+    );
+
+    assert!(difference(&command(b"Hi"), &command(b"Hi")).is_none());
+}
+
 #[test]
 #[ignore = "observation test"]
 fn print_decode_errors() {
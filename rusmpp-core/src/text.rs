@@ -0,0 +1,278 @@
+//! Conversion between message text and the bytes of a `short_message` or `message_payload`.
+//!
+//! The supported [`DataCoding`]s are:
+//!
+//! - [`DataCoding::McSpecific`] - `GSM 03.38` default alphabet and its extension table, one septet per octet (unpacked).
+//! - [`DataCoding::Ia5`] - `IA5`/`ASCII`.
+//! - [`DataCoding::Latin1`] - `ISO-8859-1`.
+//! - [`DataCoding::Ucs2`] - `UTF-16` big-endian. Characters outside the `BMP`, e.g. emojis, are encoded as surrogate pairs.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::values::DataCoding;
+
+/// Escape to the `GSM 03.38` extension table.
+const GSM7_ESCAPE: u8 = 0x1B;
+
+/// `GSM 03.38` default alphabet.
+const GSM7_BASIC: [char; 128] = [
+    '@', '£', '$', '¥', 'è', 'é', 'ù', 'ì', //
+    'ò', 'Ç', '\n', 'Ø', 'ø', '\r', 'Å', 'å', //
+    'Δ', '_', 'Φ', 'Γ', 'Λ', 'Ω', 'Π', 'Ψ', //
+    'Σ', 'Θ', 'Ξ', '\u{1B}', 'Æ', 'æ', 'ß', 'É', //
+    ' ', '!', '"', '#', '¤', '%', '&', '\'', //
+    '(', ')', '*', '+', ',', '-', '.', '/', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', '<', '=', '>', '?', //
+    '¡', 'A', 'B', 'C', 'D', 'E', 'F', 'G', //
+    'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', //
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', //
+    'X', 'Y', 'Z', 'Ä', 'Ö', 'Ñ', 'Ü', '§', //
+    '¿', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', 'ä', 'ö', 'ñ', 'ü', 'à', //
+];
+
+/// `GSM 03.38` extension table, as `(septet, char)` pairs following an escape.
+const GSM7_EXTENSION: [(u8, char); 10] = [
+    (0x0A, '\u{0C}'),
+    (0x14, '^'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x65, '€'),
+];
+
+/// Decodes the bytes of a `short_message` or `message_payload` encoded with `data_coding`.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{text::decode_text, values::DataCoding};
+/// assert_eq!(decode_text(&[0x00, 0x48, 0x00, 0x69], DataCoding::Ucs2).unwrap(), "Hi");
+/// assert_eq!(decode_text(&[0x1B, 0x65, 0x35], DataCoding::McSpecific).unwrap(), "€5");
+/// ```
+pub fn decode_text(bytes: &[u8], data_coding: DataCoding) -> Result<String, TextError> {
+    match data_coding {
+        DataCoding::McSpecific => decode_gsm7(bytes),
+        DataCoding::Ia5 => bytes
+            .iter()
+            .map(|byte| match byte {
+                0x00..=0x7F => Ok(*byte as char),
+                _ => Err(TextError::Malformed),
+            })
+            .collect(),
+        DataCoding::Latin1 => Ok(bytes.iter().map(|byte| *byte as char).collect()),
+        DataCoding::Ucs2 => {
+            if bytes.len() % 2 != 0 {
+                return Err(TextError::Malformed);
+            }
+
+            let units = bytes
+                .chunks_exact(2)
+                .map(|unit| u16::from_be_bytes([unit[0], unit[1]]));
+
+            char::decode_utf16(units)
+                .collect::<Result<String, _>>()
+                .map_err(|_| TextError::Malformed)
+        }
+        data_coding => Err(TextError::UnsupportedDataCoding { data_coding }),
+    }
+}
+
+/// Encodes `text` with `data_coding` into the bytes of a `short_message` or `message_payload`.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{text::{TextError, encode_text}, values::DataCoding};
+/// assert_eq!(encode_text("Hi", DataCoding::Ucs2).unwrap(), [0x00, 0x48, 0x00, 0x69]);
+///
+/// assert_eq!(
+///     encode_text("Grüße", DataCoding::Ia5),
+///     Err(TextError::UnrepresentableChar { char: 'ü' })
+/// );
+/// ```
+pub fn encode_text(text: &str, data_coding: DataCoding) -> Result<Vec<u8>, TextError> {
+    match data_coding {
+        DataCoding::McSpecific => encode_gsm7(text),
+        DataCoding::Ia5 => text
+            .chars()
+            .map(|char| match char {
+                '\0'..='\x7F' => Ok(char as u8),
+                _ => Err(TextError::UnrepresentableChar { char }),
+            })
+            .collect(),
+        DataCoding::Latin1 => text
+            .chars()
+            .map(|char| u8::try_from(char).map_err(|_| TextError::UnrepresentableChar { char }))
+            .collect(),
+        DataCoding::Ucs2 => Ok(text.encode_utf16().flat_map(u16::to_be_bytes).collect()),
+        data_coding => Err(TextError::UnsupportedDataCoding { data_coding }),
+    }
+}
+
+fn decode_gsm7(bytes: &[u8]) -> Result<String, TextError> {
+    let mut text = String::with_capacity(bytes.len());
+    let mut septets = bytes.iter().copied();
+
+    while let Some(septet) = septets.next() {
+        if septet > 0x7F {
+            return Err(TextError::Malformed);
+        }
+
+        if septet != GSM7_ESCAPE {
+            text.push(GSM7_BASIC[septet as usize]);
+
+            continue;
+        }
+
+        let extended = septets
+            .next()
+            .filter(|septet| *septet <= 0x7F)
+            .ok_or(TextError::Malformed)?;
+
+        // Unknown extensions are displayed as the default alphabet character
+        let char = GSM7_EXTENSION
+            .iter()
+            .find(|(septet, _)| *septet == extended)
+            .map(|(_, char)| *char)
+            .unwrap_or(GSM7_BASIC[extended as usize]);
+
+        text.push(char);
+    }
+
+    Ok(text)
+}
+
+fn encode_gsm7(text: &str) -> Result<Vec<u8>, TextError> {
+    let mut bytes = Vec::with_capacity(text.len());
+
+    for char in text.chars() {
+        if char != '\u{1B}' {
+            if let Some(septet) = GSM7_BASIC.iter().position(|basic| *basic == char) {
+                bytes.push(septet as u8);
+
+                continue;
+            }
+        }
+
+        let (septet, _) = GSM7_EXTENSION
+            .iter()
+            .find(|(_, extended)| *extended == char)
+            .ok_or(TextError::UnrepresentableChar { char })?;
+
+        bytes.extend_from_slice(&[GSM7_ESCAPE, *septet]);
+    }
+
+    Ok(bytes)
+}
+
+/// An error that can occur when converting message text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextError {
+    /// The [`DataCoding`] is not supported.
+    UnsupportedDataCoding { data_coding: DataCoding },
+    /// The character can not be represented in the [`DataCoding`].
+    UnrepresentableChar { char: char },
+    /// The bytes are not valid in the [`DataCoding`].
+    Malformed,
+}
+
+impl core::fmt::Display for TextError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TextError::UnsupportedDataCoding { data_coding } => {
+                write!(f, "Unsupported data coding: {data_coding:?}")
+            }
+            TextError::UnrepresentableChar { char } => {
+                write!(f, "Unrepresentable character: {char:?}")
+            }
+            TextError::Malformed => write!(f, "Malformed text"),
+        }
+    }
+}
+
+impl core::error::Error for TextError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gsm7() {
+        let text = "Hello @ £5 {ok} [€]\n";
+
+        let bytes = encode_text(text, DataCoding::McSpecific).unwrap();
+
+        assert_eq!(&bytes[..5], b"Hello");
+        assert_eq!(bytes[6], 0x00);
+        assert_eq!(&bytes[11..13], &[GSM7_ESCAPE, 0x28]);
+        assert_eq!(decode_text(&bytes, DataCoding::McSpecific).unwrap(), text);
+
+        assert_eq!(
+            encode_text("😀", DataCoding::McSpecific),
+            Err(TextError::UnrepresentableChar { char: '😀' })
+        );
+
+        // Dangling escape
+        assert_eq!(
+            decode_text(&[0x41, GSM7_ESCAPE], DataCoding::McSpecific),
+            Err(TextError::Malformed)
+        );
+    }
+
+    #[test]
+    fn ucs2() {
+        let text = "Grüße 😀";
+
+        let bytes = encode_text(text, DataCoding::Ucs2).unwrap();
+
+        // Surrogate pair
+        assert_eq!(&bytes[bytes.len() - 4..], &[0xD8, 0x3D, 0xDE, 0x00]);
+        assert_eq!(decode_text(&bytes, DataCoding::Ucs2).unwrap(), text);
+
+        assert_eq!(
+            decode_text(&[0x00], DataCoding::Ucs2),
+            Err(TextError::Malformed)
+        );
+        assert_eq!(
+            decode_text(&[0xD8, 0x3D], DataCoding::Ucs2),
+            Err(TextError::Malformed)
+        );
+    }
+
+    #[test]
+    fn latin1_and_ia5() {
+        let bytes = encode_text("Grüße", DataCoding::Latin1).unwrap();
+
+        assert_eq!(bytes, [b'G', b'r', 0xFC, 0xDF, b'e']);
+        assert_eq!(decode_text(&bytes, DataCoding::Latin1).unwrap(), "Grüße");
+
+        assert_eq!(
+            encode_text("€", DataCoding::Latin1),
+            Err(TextError::UnrepresentableChar { char: '€' })
+        );
+
+        assert_eq!(decode_text(b"Hi", DataCoding::Ia5).unwrap(), "Hi");
+        assert_eq!(
+            decode_text(&bytes, DataCoding::Ia5),
+            Err(TextError::Malformed)
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        assert_eq!(
+            encode_text("Hi", DataCoding::Jis),
+            Err(TextError::UnsupportedDataCoding {
+                data_coding: DataCoding::Jis
+            })
+        );
+    }
+}
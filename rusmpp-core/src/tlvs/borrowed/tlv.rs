@@ -54,6 +54,15 @@ impl<'a> Tlv<'a> {
         }
     }
 
+    /// Creates a [`Tlv`] without a value, encoded with a `value_length` of `0`.
+    pub const fn empty(tag: TlvTag) -> Self {
+        Self {
+            tag,
+            value_length: 0,
+            value: None,
+        }
+    }
+
     pub const fn tag(&self) -> TlvTag {
         self.tag
     }
@@ -65,6 +74,18 @@ impl<'a> Tlv<'a> {
     pub const fn value(&'_ self) -> Option<&'_ TlvValue<'_>> {
         self.value.as_ref()
     }
+
+    /// Returns `true` if the `TLV` has a `value_length` of `0`.
+    pub const fn is_empty(&self) -> bool {
+        self.value_length == 0
+    }
+
+    /// Copies the [`Tlv`] into an [`owned::Tlv`](crate::tlvs::owned::Tlv).
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_owned(&self) -> crate::tlvs::owned::Tlv {
+        self.into()
+    }
 }
 
 impl<'a> From<TlvValue<'a>> for Tlv<'a> {
@@ -72,3 +93,21 @@ impl<'a> From<TlvValue<'a>> for Tlv<'a> {
         Self::new(value)
     }
 }
+
+#[cfg(any(test, feature = "alloc"))]
+impl<'a> From<&'a crate::tlvs::owned::Tlv> for Tlv<'a> {
+    fn from(tlv: &'a crate::tlvs::owned::Tlv) -> Self {
+        Self {
+            tag: tlv.tag(),
+            value_length: tlv.value_length(),
+            value: tlv.value().map(TlvValue::from),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl PartialEq<crate::tlvs::owned::Tlv> for Tlv<'_> {
+    fn eq(&self, other: &crate::tlvs::owned::Tlv) -> bool {
+        *self == other.as_borrowed()
+    }
+}
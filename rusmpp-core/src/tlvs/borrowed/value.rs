@@ -337,6 +337,16 @@ impl<'a> DecodeWithKey<'a> for TlvValue<'a> {
     type Key = TlvTag;
 
     fn decode(key: Self::Key, src: &'a [u8], length: usize) -> Result<(Self, usize), DecodeError> {
+        Self::decode_value(key, src, length).map_err(|error| error.with_tlv(key.into(), src.len()))
+    }
+}
+
+impl<'a> TlvValue<'a> {
+    fn decode_value(
+        key: TlvTag,
+        src: &'a [u8],
+        length: usize,
+    ) -> Result<(Self, usize), DecodeError> {
         let (value, size) = match key {
             TlvTag::AdditionalStatusInfoText => {
                 Decode::decode(src).map_decoded(Self::AdditionalStatusInfoText)?
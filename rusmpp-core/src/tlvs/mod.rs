@@ -60,6 +60,85 @@
 //! all. The very absence of the TLV from the PDU is the
 //! means by which we set the values to NULL.
 
+/// Converts a `&TlvValue` between the owned and borrowed representations.
+///
+/// Owned values are borrowed, borrowed values are copied.
+#[cfg(any(test, feature = "alloc"))]
+macro_rules! convert_tlv_value {
+    ($value:expr, $from:ident => $to:ident) => {
+        match $value {
+            $from::AdditionalStatusInfoText(value) => $to::AdditionalStatusInfoText(value.into()),
+            $from::AlertOnMessageDelivery(value) => $to::AlertOnMessageDelivery(*value),
+            $from::BillingIdentification(value) => $to::BillingIdentification(value.into()),
+            $from::BroadcastAreaIdentifier(value) => $to::BroadcastAreaIdentifier(value.into()),
+            $from::BroadcastAreaSuccess(value) => $to::BroadcastAreaSuccess(*value),
+            $from::BroadcastContentTypeInfo(value) => $to::BroadcastContentTypeInfo(value.into()),
+            $from::BroadcastChannelIndicator(value) => $to::BroadcastChannelIndicator(*value),
+            $from::BroadcastContentType(value) => $to::BroadcastContentType(*value),
+            $from::BroadcastEndTime(value) => $to::BroadcastEndTime(value.into()),
+            $from::BroadcastErrorStatus(value) => $to::BroadcastErrorStatus(*value),
+            $from::BroadcastFrequencyInterval(value) => $to::BroadcastFrequencyInterval(*value),
+            $from::BroadcastMessageClass(value) => $to::BroadcastMessageClass(*value),
+            $from::BroadcastRepNum(value) => $to::BroadcastRepNum(*value),
+            $from::BroadcastServiceGroup(value) => $to::BroadcastServiceGroup(value.into()),
+            $from::CallbackNum(value) => $to::CallbackNum(value.into()),
+            $from::CallbackNumAtag(value) => $to::CallbackNumAtag(value.into()),
+            $from::CallbackNumPresInd(value) => $to::CallbackNumPresInd(*value),
+            $from::CongestionState(value) => $to::CongestionState(*value),
+            $from::DeliveryFailureReason(value) => $to::DeliveryFailureReason(*value),
+            $from::DestAddrNpCountry(value) => $to::DestAddrNpCountry(value.into()),
+            $from::DestAddrNpInformation(value) => $to::DestAddrNpInformation(value.into()),
+            $from::DestAddrNpResolution(value) => $to::DestAddrNpResolution(*value),
+            $from::DestAddrSubunit(value) => $to::DestAddrSubunit(*value),
+            $from::DestBearerType(value) => $to::DestBearerType(*value),
+            $from::DestNetworkId(value) => $to::DestNetworkId(value.into()),
+            $from::DestNetworkType(value) => $to::DestNetworkType(*value),
+            $from::DestNodeId(value) => $to::DestNodeId(value.into()),
+            $from::DestSubaddress(value) => $to::DestSubaddress(value.into()),
+            $from::DestTelematicsId(value) => $to::DestTelematicsId(*value),
+            $from::DestPort(value) => $to::DestPort(*value),
+            $from::DisplayTime(value) => $to::DisplayTime(*value),
+            $from::DpfResult(value) => $to::DpfResult(*value),
+            $from::ItsReplyType(value) => $to::ItsReplyType(*value),
+            $from::ItsSessionInfo(value) => $to::ItsSessionInfo(*value),
+            $from::LanguageIndicator(value) => $to::LanguageIndicator(*value),
+            $from::MessagePayload(value) => $to::MessagePayload(value.into()),
+            $from::MessageState(value) => $to::MessageState(*value),
+            $from::MoreMessagesToSend(value) => $to::MoreMessagesToSend(*value),
+            $from::MsAvailabilityStatus(value) => $to::MsAvailabilityStatus(*value),
+            $from::MsMsgWaitFacilities(value) => $to::MsMsgWaitFacilities(*value),
+            $from::MsValidity(value) => $to::MsValidity(value.clone()),
+            $from::NetworkErrorCode(value) => $to::NetworkErrorCode(value.clone()),
+            $from::NumberOfMessages(value) => $to::NumberOfMessages(*value),
+            $from::PayloadType(value) => $to::PayloadType(*value),
+            $from::PrivacyIndicator(value) => $to::PrivacyIndicator(*value),
+            $from::QosTimeToLive(value) => $to::QosTimeToLive(*value),
+            $from::ReceiptedMessageId(value) => $to::ReceiptedMessageId(value.into()),
+            $from::SarMsgRefNum(value) => $to::SarMsgRefNum(*value),
+            $from::SarSegmentSeqnum(value) => $to::SarSegmentSeqnum(*value),
+            $from::SarTotalSegments(value) => $to::SarTotalSegments(*value),
+            $from::ScInterfaceVersion(value) => $to::ScInterfaceVersion(*value),
+            $from::SetDpf(value) => $to::SetDpf(*value),
+            $from::SmsSignal(value) => $to::SmsSignal(*value),
+            $from::SourceAddrSubunit(value) => $to::SourceAddrSubunit(*value),
+            $from::SourceBearerType(value) => $to::SourceBearerType(*value),
+            $from::SourceNetworkId(value) => $to::SourceNetworkId(value.into()),
+            $from::SourceNetworkType(value) => $to::SourceNetworkType(*value),
+            $from::SourceNodeId(value) => $to::SourceNodeId(value.into()),
+            $from::SourcePort(value) => $to::SourcePort(*value),
+            $from::SourceSubaddress(value) => $to::SourceSubaddress(value.into()),
+            $from::SourceTelematicsId(value) => $to::SourceTelematicsId(*value),
+            $from::UserMessageReference(value) => $to::UserMessageReference(*value),
+            $from::UserResponseCode(value) => $to::UserResponseCode(*value),
+            $from::UssdServiceOp(value) => $to::UssdServiceOp(*value),
+            $from::Other { tag, value } => $to::Other {
+                tag: *tag,
+                value: value.into(),
+            },
+        }
+    };
+}
+
 mod tag;
 pub use tag::*;
 
@@ -0,0 +1,250 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::{
+    decode::{DecodeError, borrowed, owned},
+    encode::{Encode, Length},
+    tlvs::{TlvTag, owned::TlvValue},
+    types::owned::AnyOctetString,
+};
+
+/// A [`TlvValue`] whose [`TlvValue::Other`] bytes borrow from the decoded bytes until they are mutated.
+///
+/// Decoding an unknown `TLV` yields a [`CowTlvValue::Other`] pointing into the decoded bytes without copying them.
+/// [`CowTlvValue::other_value_mut`] copies the bytes on the first mutation. Known `TLV`s are decoded as a [`TlvValue`].
+///
+/// Useful for forwarding `TLV`s, where most unknown values are read and re-encoded untouched.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{
+/// #     decode::borrowed::DecodeWithKey,
+/// #     tlvs::{TlvTag, owned::{CowTlvValue, TlvValue}},
+/// #     types::owned::AnyOctetString,
+/// # };
+/// let bytes = [0x01, 0x02];
+///
+/// let (mut value, _) = CowTlvValue::decode(TlvTag::Other(0x1400), &bytes, bytes.len()).unwrap();
+///
+/// assert!(value.is_borrowed());
+/// assert_eq!(value.other_value(), Some(&bytes[..]));
+///
+/// if let Some(value) = value.other_value_mut() {
+///     value.push(0x03);
+/// }
+///
+/// assert!(!value.is_borrowed());
+/// assert_eq!(
+///     value.into_owned(),
+///     TlvValue::Other {
+///         tag: TlvTag::Other(0x1400),
+///         value: AnyOctetString::new([0x01, 0x02, 0x03]),
+///     }
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CowTlvValue<'a> {
+    /// A known `TLV`.
+    Known(TlvValue),
+    /// An unknown `TLV`, see [`TlvValue::Other`].
+    Other { tag: TlvTag, value: Cow<'a, [u8]> },
+}
+
+impl CowTlvValue<'_> {
+    pub const fn tag(&self) -> TlvTag {
+        match self {
+            CowTlvValue::Known(value) => value.tag(),
+            CowTlvValue::Other { tag, .. } => *tag,
+        }
+    }
+
+    /// Checks if the value still borrows from the decoded bytes.
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(
+            self,
+            CowTlvValue::Other {
+                value: Cow::Borrowed(_),
+                ..
+            }
+        )
+    }
+
+    /// Returns the raw value bytes of an unknown `TLV`.
+    ///
+    /// Returns [`None`] for known `TLV`s.
+    pub fn other_value(&self) -> Option<&[u8]> {
+        match self {
+            CowTlvValue::Other { value, .. } => Some(value),
+            CowTlvValue::Known(_) => None,
+        }
+    }
+
+    /// Returns the mutable raw value bytes of an unknown `TLV`, copying borrowed bytes first.
+    ///
+    /// Returns [`None`] for known `TLV`s.
+    pub fn other_value_mut(&mut self) -> Option<&mut Vec<u8>> {
+        match self {
+            CowTlvValue::Other { value, .. } => Some(value.to_mut()),
+            CowTlvValue::Known(_) => None,
+        }
+    }
+
+    /// Extracts the owned [`TlvValue`], copying borrowed bytes.
+    pub fn into_owned(self) -> TlvValue {
+        match self {
+            CowTlvValue::Known(value) => value,
+            CowTlvValue::Other { tag, value } => TlvValue::Other {
+                tag,
+                value: AnyOctetString::from(value.into_owned()),
+            },
+        }
+    }
+}
+
+impl From<TlvValue> for CowTlvValue<'_> {
+    fn from(value: TlvValue) -> Self {
+        match value {
+            TlvValue::Other { tag, value } => CowTlvValue::Other {
+                tag,
+                value: Cow::Owned(value.into_bytes()),
+            },
+            value => CowTlvValue::Known(value),
+        }
+    }
+}
+
+impl From<CowTlvValue<'_>> for TlvValue {
+    fn from(value: CowTlvValue<'_>) -> Self {
+        value.into_owned()
+    }
+}
+
+impl Length for CowTlvValue<'_> {
+    fn length(&self) -> usize {
+        match self {
+            CowTlvValue::Known(value) => value.length(),
+            CowTlvValue::Other { value, .. } => value.len(),
+        }
+    }
+}
+
+impl Encode for CowTlvValue<'_> {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        match self {
+            CowTlvValue::Known(value) => value.encode(dst),
+            CowTlvValue::Other { value, .. } => {
+                dst[..value.len()].copy_from_slice(value);
+
+                value.len()
+            }
+        }
+    }
+}
+
+impl<'a> borrowed::DecodeWithKey<'a> for CowTlvValue<'a> {
+    type Key = TlvTag;
+
+    fn decode(key: Self::Key, src: &'a [u8], length: usize) -> Result<(Self, usize), DecodeError> {
+        match key {
+            TlvTag::Other(_) => {
+                let value = src.get(..length).ok_or_else(DecodeError::unexpected_eof)?;
+
+                Ok((
+                    CowTlvValue::Other {
+                        tag: key,
+                        value: Cow::Borrowed(value),
+                    },
+                    length,
+                ))
+            }
+            _ => {
+                let (value, size) = <TlvValue as owned::DecodeWithKey>::decode(key, src, length)?;
+
+                Ok((CowTlvValue::Known(value), size))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decode::borrowed::DecodeWithKey, types::owned::COctetString, values::UserMessageReference,
+    };
+
+    use super::*;
+
+    #[test]
+    fn read_does_not_copy() {
+        let bytes = [0x01, 0x02, 0x03];
+
+        let (value, size) =
+            CowTlvValue::decode(TlvTag::Other(0x1400), &bytes, bytes.len()).unwrap();
+
+        assert_eq!(size, 3);
+        assert!(value.is_borrowed());
+
+        // Points into the decoded bytes
+        assert_eq!(value.other_value().unwrap().as_ptr(), bytes.as_ptr());
+
+        let mut buf = [0; 3];
+        let size = value.encode(&mut buf);
+
+        assert_eq!(&buf[..size], &bytes);
+    }
+
+    #[test]
+    fn mutate_copies() {
+        let bytes = [0x01, 0x02, 0x03];
+
+        let (mut value, _) =
+            CowTlvValue::decode(TlvTag::Other(0x1400), &bytes, bytes.len()).unwrap();
+
+        let other = value.other_value_mut().unwrap();
+
+        assert_ne!(other.as_ptr(), bytes.as_ptr());
+
+        other.truncate(1);
+
+        assert!(!value.is_borrowed());
+        assert_eq!(value.tag(), TlvTag::Other(0x1400));
+        assert_eq!(value.other_value(), Some(&[0x01][..]));
+        assert_eq!(
+            value.into_owned(),
+            TlvValue::Other {
+                tag: TlvTag::Other(0x1400),
+                value: AnyOctetString::new([0x01]),
+            }
+        );
+    }
+
+    #[test]
+    fn incomplete() {
+        let bytes = [0x01, 0x02];
+
+        assert!(CowTlvValue::decode(TlvTag::Other(0x1400), &bytes, 3).is_err());
+    }
+
+    #[test]
+    fn known_tlvs() {
+        let bytes = b"message-id\0";
+
+        let (mut value, _) =
+            CowTlvValue::decode(TlvTag::ReceiptedMessageId, bytes, bytes.len()).unwrap();
+
+        assert!(!value.is_borrowed());
+        assert!(value.other_value().is_none());
+        assert!(value.other_value_mut().is_none());
+        assert_eq!(
+            value.into_owned(),
+            TlvValue::ReceiptedMessageId(COctetString::new(bytes).unwrap())
+        );
+
+        let value = CowTlvValue::from(TlvValue::UserMessageReference(UserMessageReference::new(
+            16,
+        )));
+
+        assert_eq!(value.tag(), TlvTag::UserMessageReference);
+        assert_eq!(value.length(), 2);
+    }
+}
@@ -0,0 +1,238 @@
+use alloc::string::String;
+
+use crate::{
+    tlvs::{
+        TlvTag,
+        owned::{Tlv, TlvValue},
+    },
+    types::owned::AnyOctetString,
+};
+
+/// Constructors and extractors for custom (vendor-specific) `TLV`s.
+///
+/// Custom `TLV`s are [`TlvValue::Other`]s with a [`TlvTag::Other`] tag, see also [`TlvRegistry`](super::TlvRegistry).
+impl Tlv {
+    /// Creates a custom `TLV` holding `value` followed by a null terminator.
+    ///
+    /// Read it back using [`Tlv::extract_string`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::tlvs::owned::Tlv;
+    /// let tlv = Tlv::new_custom_string(0x1400, "ABC");
+    ///
+    /// assert_eq!(tlv.value_length(), 4);
+    /// assert_eq!(tlv.extract_string().as_deref(), Some("ABC"));
+    /// ```
+    pub fn new_custom_string(tag: u16, value: &str) -> Self {
+        let mut bytes = alloc::vec::Vec::with_capacity(value.len() + 1);
+
+        bytes.extend_from_slice(value.as_bytes());
+        bytes.push(0);
+
+        Self::new_custom(tag, AnyOctetString::from(bytes))
+    }
+
+    /// Creates a custom `TLV` holding `value` without a null terminator.
+    ///
+    /// Some vendor string `TLV`s are delimited by their length only. Read it back using [`Tlv::extract_string_no_null`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::tlvs::owned::Tlv;
+    /// let tlv = Tlv::new_custom_string_no_null(0x1400, "ABC");
+    ///
+    /// assert_eq!(tlv.value_length(), 3);
+    /// assert_eq!(tlv.extract_string_no_null().as_deref(), Some("ABC"));
+    /// assert_eq!(tlv.extract_string(), None);
+    /// ```
+    pub fn new_custom_string_no_null(tag: u16, value: &str) -> Self {
+        Self::new_custom(tag, AnyOctetString::new(value))
+    }
+
+    /// Returns the string of a custom `TLV` created by [`Tlv::new_custom_string`].
+    ///
+    /// Returns [`None`] if this is not a custom `TLV`, the value does not end with a null terminator
+    /// or is not valid `UTF-8`. For values padded after the terminator use [`Tlv::extract_cstring`].
+    pub fn extract_string(&self) -> Option<String> {
+        let bytes = self.custom_bytes()?.strip_suffix(&[0])?;
+
+        core::str::from_utf8(bytes).ok().map(String::from)
+    }
+
+    /// Returns the string of a custom `TLV` holding a fixed-width C-octet string.
+    ///
+    /// The string ends at the first null byte, the padding after it is ignored.
+    /// Returns [`None`] if this is not a custom `TLV`, the value contains no null byte or the string is not valid `UTF-8`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::tlvs::owned::Tlv;
+    /// let tlv = Tlv::new_custom_string_no_null(0x1400, "ABC\0XYZ");
+    ///
+    /// assert_eq!(tlv.extract_cstring().as_deref(), Some("ABC"));
+    /// assert_eq!(tlv.extract_string(), None);
+    /// ```
+    pub fn extract_cstring(&self) -> Option<String> {
+        let bytes = self.custom_bytes()?;
+        let end = bytes.iter().position(|byte| *byte == 0)?;
+
+        core::str::from_utf8(&bytes[..end]).ok().map(String::from)
+    }
+
+    /// Returns the string of a custom `TLV` created by [`Tlv::new_custom_string_no_null`].
+    ///
+    /// All bytes of the value are part of the string.
+    /// Returns [`None`] if this is not a custom `TLV` or the value is not valid `UTF-8`.
+    pub fn extract_string_no_null(&self) -> Option<String> {
+        core::str::from_utf8(self.custom_bytes()?)
+            .ok()
+            .map(String::from)
+    }
+
+    /// Creates a custom `TLV` holding the 3 low bytes of `value`, big-endian.
+    ///
+    /// The high byte of `value` is ignored. Read it back using [`Tlv::extract_u24`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::tlvs::owned::Tlv;
+    /// let tlv = Tlv::new_custom_u24(0x1400, 0x0A_0B_0C);
+    ///
+    /// assert_eq!(tlv.value_length(), 3);
+    /// assert_eq!(tlv.extract_u24(), Some(0x0A_0B_0C));
+    /// ```
+    pub fn new_custom_u24(tag: u16, value: u32) -> Self {
+        let [_, high, mid, low] = value.to_be_bytes();
+
+        Self::new_custom(tag, AnyOctetString::new([high, mid, low]))
+    }
+
+    /// Returns the big-endian 3-byte integer of a custom `TLV`.
+    ///
+    /// Returns [`None`] if this is not a custom `TLV` or the value is not exactly 3 bytes long.
+    pub fn extract_u24(&self) -> Option<u32> {
+        match self.custom_bytes()? {
+            &[high, mid, low] => Some(u32::from_be_bytes([0, high, mid, low])),
+            _ => None,
+        }
+    }
+
+    fn new_custom(tag: u16, value: AnyOctetString) -> Self {
+        Self::new(TlvValue::Other {
+            tag: TlvTag::Other(tag),
+            value,
+        })
+    }
+
+    /// Returns the value bytes of a custom `TLV`.
+    fn custom_bytes(&self) -> Option<&[u8]> {
+        match self.value() {
+            Some(TlvValue::Other {
+                tag: TlvTag::Other(_),
+                value,
+            }) => Some(value.bytes()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode::owned::Decode, encode::Encode};
+
+    use super::*;
+
+    fn round_trip(tlv: &Tlv) -> Tlv {
+        let mut buf = [0; 64];
+        let size = tlv.encode(&mut buf);
+
+        Tlv::decode(&buf[..size]).unwrap().0
+    }
+
+    #[test]
+    fn strings() {
+        let terminated = round_trip(&Tlv::new_custom_string(0x1400, "ABC"));
+
+        assert_eq!(terminated.tag(), TlvTag::Other(0x1400));
+        assert_eq!(terminated.value_length(), 4);
+        assert_eq!(terminated.extract_string().as_deref(), Some("ABC"));
+
+        let length_delimited = round_trip(&Tlv::new_custom_string_no_null(0x1400, "ABC"));
+
+        assert_eq!(length_delimited.value_length(), 3);
+        assert_eq!(
+            length_delimited.extract_string_no_null().as_deref(),
+            Some("ABC")
+        );
+
+        // The expected form must match
+        assert_eq!(length_delimited.extract_string(), None);
+        assert_eq!(
+            terminated.extract_string_no_null().as_deref(),
+            Some("ABC\0")
+        );
+
+        let empty = round_trip(&Tlv::new_custom_string(0x1400, ""));
+
+        assert_eq!(empty.extract_string().as_deref(), Some(""));
+    }
+
+    #[test]
+    fn cstring() {
+        let tlv = round_trip(&Tlv::new_custom(0x1400, AnyOctetString::new(b"ABC\0XYZ")));
+
+        assert_eq!(tlv.extract_cstring().as_deref(), Some("ABC"));
+
+        let tlv = round_trip(&Tlv::new_custom(0x1400, AnyOctetString::new(b"ABC\0\0\0")));
+
+        assert_eq!(tlv.extract_cstring().as_deref(), Some("ABC"));
+        assert_eq!(tlv.extract_string().as_deref(), Some("ABC\0\0"));
+
+        let tlv = Tlv::new_custom_string(0x1400, "ABC");
+
+        assert_eq!(tlv.extract_cstring(), tlv.extract_string());
+
+        // Missing terminator
+        let tlv = Tlv::new_custom_string_no_null(0x1400, "ABC");
+
+        assert_eq!(tlv.extract_cstring(), None);
+    }
+
+    #[test]
+    fn u24() {
+        for value in [0, 1, 0x00_12_34, 0xFF_FF_FF] {
+            let tlv = round_trip(&Tlv::new_custom_u24(0x1400, value));
+
+            assert_eq!(tlv.value_length(), 3);
+            assert_eq!(tlv.extract_u24(), Some(value));
+        }
+
+        assert_eq!(
+            Tlv::new_custom_u24(0x1400, 0x01_02_03_04).extract_u24(),
+            Some(0x02_03_04)
+        );
+
+        let tlv = Tlv::new_custom(0x1400, AnyOctetString::new([0x01, 0x02]));
+
+        assert_eq!(tlv.extract_u24(), None);
+    }
+
+    #[test]
+    fn not_custom() {
+        let tlv = Tlv::new(TlvValue::UserResponseCode(0));
+
+        assert_eq!(tlv.extract_string(), None);
+        assert_eq!(tlv.extract_string_no_null(), None);
+        assert_eq!(tlv.extract_cstring(), None);
+        assert_eq!(tlv.extract_u24(), None);
+
+        let tlv = Tlv::new_custom(0x1400, AnyOctetString::new([0xFF, 0x00]));
+
+        assert_eq!(tlv.extract_string(), None);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::tlvs::{TlvTag, owned::Tlv};
+
+/// Number of `TLV`s from which [`TlvIndex`] indexes the `TLV`s instead of searching linearly.
+pub const TLV_INDEX_THRESHOLD: usize = 16;
+
+/// Lazily built lookup index of the `TLV`s of a `PDU`, see [`TlvContainer::get_tlv`](crate::pdus::owned::TlvContainer::get_tlv).
+///
+/// Lists with more than [`TLV_INDEX_THRESHOLD`] `TLV`s are indexed by tag on the first lookup,
+/// shorter lists are searched linearly. The `PDU` clears the index whenever its `TLV`s are mutated.
+///
+/// Without the `std` feature lookups are always linear.
+///
+/// The index is not part of the `PDU`: it is not encoded, serialized, compared or hashed.
+#[derive(Default)]
+pub struct TlvIndex {
+    /// Position of the last occurrence of each tag, see [`get_tlv`](crate::tlvs::owned::get_tlv).
+    ///
+    /// Boxed to keep the `PDU`s small.
+    #[cfg(feature = "std")]
+    #[allow(clippy::box_collection)]
+    positions: std::sync::OnceLock<alloc::boxed::Box<std::collections::HashMap<TlvTag, usize>>>,
+}
+
+impl TlvIndex {
+    /// Creates a new empty [`TlvIndex`].
+    pub const fn new() -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            positions: std::sync::OnceLock::new(),
+        }
+    }
+
+    /// Returns the last [`Tlv`] with the given `tag` in `tlvs`, if any.
+    ///
+    /// `tlvs` must be the list the index was built from, see [`clear`](Self::clear).
+    pub(crate) fn get<'a>(&self, tlvs: &'a [Tlv], tag: TlvTag) -> Option<&'a Tlv> {
+        #[cfg(feature = "std")]
+        if tlvs.len() > TLV_INDEX_THRESHOLD {
+            let positions = self.positions.get_or_init(|| {
+                tlvs.iter()
+                    .enumerate()
+                    .map(|(position, tlv)| (tlv.tag(), position))
+                    .collect::<std::collections::HashMap<_, _>>()
+                    .into()
+            });
+
+            return positions.get(&tag).and_then(|position| tlvs.get(*position));
+        }
+
+        super::get_tlv(tlvs, tag)
+    }
+
+    /// Clears the index after the `TLV`s were mutated.
+    pub(crate) fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl core::fmt::Debug for TlvIndex {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TlvIndex").finish_non_exhaustive()
+    }
+}
+
+impl Clone for TlvIndex {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for TlvIndex {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TlvIndex {}
+
+impl PartialOrd for TlvIndex {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TlvIndex {
+    fn cmp(&self, _: &Self) -> core::cmp::Ordering {
+        core::cmp::Ordering::Equal
+    }
+}
+
+impl core::hash::Hash for TlvIndex {
+    fn hash<H: core::hash::Hasher>(&self, _: &mut H) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::{
+        tlvs::owned::{TlvValue, get_tlv},
+        types::owned::AnyOctetString,
+    };
+
+    use super::*;
+
+    fn tlvs(count: u16) -> Vec<Tlv> {
+        (0..count)
+            .map(|i| {
+                Tlv::new(TlvValue::Other {
+                    // Duplicate every tenth tag
+                    tag: TlvTag::Other(0x1400 + i - (i % 10 == 9) as u16),
+                    value: AnyOctetString::new(i.to_be_bytes()),
+                })
+            })
+            .chain([
+                Tlv::new(TlvValue::UserResponseCode(1)),
+                Tlv::new(TlvValue::UserResponseCode(2)),
+            ])
+            .collect()
+    }
+
+    #[test]
+    fn indexed_and_linear_agree() {
+        for count in [0, 1, TLV_INDEX_THRESHOLD as u16, 64, 300] {
+            let tlvs = tlvs(count);
+            let index = TlvIndex::new();
+
+            let tags = tlvs
+                .iter()
+                .map(Tlv::tag)
+                .chain([TlvTag::Other(0x0042), TlvTag::SourcePort]);
+
+            for tag in tags {
+                assert_eq!(index.get(&tlvs, tag), get_tlv(&tlvs, tag), "tag: {tag:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn clear() {
+        let mut tlvs = tlvs(64);
+        let mut index = TlvIndex::new();
+
+        assert_eq!(
+            index.get(&tlvs, TlvTag::UserResponseCode),
+            Some(&Tlv::new(TlvValue::UserResponseCode(2)))
+        );
+
+        tlvs.pop();
+        index.clear();
+
+        assert_eq!(
+            index.get(&tlvs, TlvTag::UserResponseCode),
+            Some(&Tlv::new(TlvValue::UserResponseCode(1)))
+        );
+    }
+}
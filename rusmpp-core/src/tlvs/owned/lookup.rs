@@ -0,0 +1,227 @@
+use alloc::vec::Vec;
+
+use crate::tlvs::{TlvTag, owned::Tlv};
+
+/// Returns the last [`Tlv`] with the given `tag` in `tlvs`, if any.
+///
+/// Lenient decoding keeps duplicate single-occurrence `TLV`s, see [`DecodeOptions::reject_duplicate_tlvs`](crate::decode::DecodeOptions::reject_duplicate_tlvs).
+/// The last occurrence wins.
+pub fn get_tlv(tlvs: &[Tlv], tag: TlvTag) -> Option<&Tlv> {
+    tlvs.iter().rev().find(|tlv| tlv.tag() == tag)
+}
+
+/// Replaces the first [`Tlv`] with the tag of `tlv` in `tlvs`, or appends `tlv` if there is none.
+///
+/// Further `TLV`s with the same tag are removed. Returns the replaced `TLV` returned by [`get_tlv`], if any.
+pub fn set_tlv(tlvs: &mut Vec<Tlv>, tlv: Tlv) -> Option<Tlv> {
+    let tag = tlv.tag();
+
+    let Some(position) = tlvs.iter().position(|existing| existing.tag() == tag) else {
+        tlvs.push(tlv);
+
+        return None;
+    };
+
+    let last = tlvs
+        .iter()
+        .rposition(|existing| existing.tag() == tag)
+        .unwrap_or(position);
+
+    let mut replaced = core::mem::replace(&mut tlvs[position], tlv);
+
+    if last != position {
+        replaced = tlvs.remove(last);
+    }
+
+    let mut index = 0;
+
+    tlvs.retain(|existing| {
+        let keep = index <= position || existing.tag() != tag;
+
+        index += 1;
+
+        keep
+    });
+
+    Some(replaced)
+}
+
+/// Removes all [`Tlv`]s with the given `tag` from `tlvs`.
+///
+/// Returns the removed `TLV` returned by [`get_tlv`], if any.
+pub fn remove_tlv(tlvs: &mut Vec<Tlv>, tag: TlvTag) -> Option<Tlv> {
+    let position = tlvs.iter().rposition(|existing| existing.tag() == tag)?;
+
+    let removed = tlvs.remove(position);
+
+    tlvs.retain(|existing| existing.tag() != tag);
+
+    Some(removed)
+}
+
+/// Returns the tag of the first single-occurrence [`Tlv`] that occurs more than once in `tlvs`, if any.
+///
+/// See [`TlvTag::is_repeatable`].
+pub fn find_duplicate_tlv(tlvs: &[Tlv]) -> Option<TlvTag> {
+    tlvs.iter()
+        .enumerate()
+        .map(|(position, tlv)| (position, tlv.tag()))
+        .filter(|(_, tag)| !tag.is_repeatable())
+        .find(|(position, tag)| tlvs[..*position].iter().any(|tlv| tlv.tag() == *tag))
+        .map(|(_, tag)| tag)
+}
+
+/// Removes the [`Tlv`]s equal to an earlier `TLV` in `tlvs`, keeping the first occurrences in their order.
+///
+/// Unlike [`set_tlv`], `TLV`s with the same tag but different values are kept.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::tlvs::owned::{Tlv, TlvValue, dedup_tlvs_by_value};
+/// let mut tlvs = vec![
+///     Tlv::new(TlvValue::UserResponseCode(1)),
+///     Tlv::new(TlvValue::SourcePort(2)),
+///     Tlv::new(TlvValue::UserResponseCode(1)),
+///     Tlv::new(TlvValue::UserResponseCode(3)),
+/// ];
+///
+/// dedup_tlvs_by_value(&mut tlvs);
+///
+/// assert_eq!(
+///     tlvs,
+///     [
+///         Tlv::new(TlvValue::UserResponseCode(1)),
+///         Tlv::new(TlvValue::SourcePort(2)),
+///         Tlv::new(TlvValue::UserResponseCode(3)),
+///     ]
+/// );
+/// ```
+pub fn dedup_tlvs_by_value(tlvs: &mut Vec<Tlv>) {
+    // Sorting positions by value groups equal TLVs without cloning them
+    let mut positions: Vec<usize> = (0..tlvs.len()).collect();
+
+    positions.sort_by(|a, b| tlvs[*a].cmp(&tlvs[*b]).then(a.cmp(b)));
+
+    let mut duplicate = alloc::vec![false; tlvs.len()];
+
+    for pair in positions.windows(2) {
+        if tlvs[pair[0]] == tlvs[pair[1]] {
+            duplicate[pair[1]] = true;
+        }
+    }
+
+    let mut duplicate = duplicate.into_iter();
+
+    tlvs.retain(|_| !duplicate.next().unwrap_or_default());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tlvs::owned::TlvValue, types::owned::AnyOctetString};
+
+    use super::*;
+
+    fn tlvs(count: u16) -> Vec<Tlv> {
+        (0..count)
+            .map(|i| {
+                Tlv::new(TlvValue::Other {
+                    // Duplicate every tenth tag
+                    tag: TlvTag::Other(0x1400 + i - (i % 10 == 9) as u16),
+                    value: AnyOctetString::new(i.to_be_bytes()),
+                })
+            })
+            .chain([
+                Tlv::new(TlvValue::UserResponseCode(1)),
+                Tlv::new(TlvValue::UserResponseCode(2)),
+            ])
+            .collect()
+    }
+
+    #[test]
+    fn remove() {
+        let mut list = tlvs(20);
+
+        let removed = remove_tlv(&mut list, TlvTag::UserResponseCode);
+
+        assert_eq!(removed, Some(Tlv::new(TlvValue::UserResponseCode(2))));
+        assert_eq!(list.len(), 20);
+        assert_eq!(get_tlv(&list, TlvTag::UserResponseCode), None);
+
+        assert_eq!(remove_tlv(&mut list, TlvTag::UserResponseCode), None);
+        assert_eq!(list.len(), 20);
+    }
+
+    #[test]
+    fn dedup_by_value() {
+        let mut list = alloc::vec![
+            Tlv::new(TlvValue::UserResponseCode(1)),
+            Tlv::new(TlvValue::SourcePort(2)),
+            Tlv::new(TlvValue::UserResponseCode(1)),
+            Tlv::new(TlvValue::UserResponseCode(3)),
+            Tlv::new(TlvValue::SourcePort(2)),
+        ];
+
+        dedup_tlvs_by_value(&mut list);
+
+        assert_eq!(
+            list,
+            [
+                Tlv::new(TlvValue::UserResponseCode(1)),
+                Tlv::new(TlvValue::SourcePort(2)),
+                Tlv::new(TlvValue::UserResponseCode(3)),
+            ]
+        );
+
+        // Same tags with different values are kept
+        let mut list = tlvs(300);
+        let expected = list.clone();
+
+        dedup_tlvs_by_value(&mut list);
+
+        assert_eq!(list, expected);
+    }
+
+    #[test]
+    fn duplicate() {
+        let tlvs = [
+            Tlv::new(TlvValue::UserResponseCode(1)),
+            Tlv::new(TlvValue::SourcePort(2)),
+            Tlv::new(TlvValue::UserResponseCode(3)),
+        ];
+
+        assert_eq!(find_duplicate_tlv(&tlvs), Some(TlvTag::UserResponseCode));
+        assert_eq!(find_duplicate_tlv(&tlvs[..2]), None);
+
+        // The last occurrence wins
+        assert_eq!(get_tlv(&tlvs, TlvTag::UserResponseCode), Some(&tlvs[2]));
+
+        // Repeatable tags
+        let tlvs = self::tlvs(64);
+
+        assert_eq!(find_duplicate_tlv(&tlvs[..64]), None);
+    }
+
+    #[test]
+    fn set() {
+        let mut tlvs = alloc::vec![
+            Tlv::new(TlvValue::UserResponseCode(1)),
+            Tlv::new(TlvValue::SourcePort(2)),
+            Tlv::new(TlvValue::UserResponseCode(3)),
+        ];
+
+        let replaced = set_tlv(&mut tlvs, Tlv::new(TlvValue::UserResponseCode(4)));
+
+        assert_eq!(replaced, Some(Tlv::new(TlvValue::UserResponseCode(3))));
+        assert_eq!(
+            tlvs,
+            [
+                Tlv::new(TlvValue::UserResponseCode(4)),
+                Tlv::new(TlvValue::SourcePort(2)),
+            ]
+        );
+
+        assert_eq!(set_tlv(&mut tlvs, Tlv::new(TlvValue::DestPort(5))), None);
+        assert_eq!(tlvs[2], Tlv::new(TlvValue::DestPort(5)));
+    }
+}
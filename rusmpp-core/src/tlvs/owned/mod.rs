@@ -1,5 +1,16 @@
+mod cow;
+mod custom;
+mod index;
+mod lookup;
+mod registry;
 mod tlv;
+mod tlvs;
 mod value;
 
+pub use cow::CowTlvValue;
+pub use index::{TLV_INDEX_THRESHOLD, TlvIndex};
+pub use lookup::{dedup_tlvs_by_value, find_duplicate_tlv, get_tlv, remove_tlv, set_tlv};
+pub use registry::{TlvDecoder, TlvRegistry};
 pub use tlv::*;
+pub use tlvs::Tlvs;
 pub use value::TlvValue;
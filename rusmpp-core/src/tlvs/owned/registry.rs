@@ -0,0 +1,194 @@
+use alloc::collections::BTreeMap;
+
+use crate::{
+    decode::DecodeError,
+    tlvs::{
+        TlvTag,
+        owned::{Tlv, TlvValue},
+    },
+};
+
+/// Decoder for the raw value bytes of a custom `TLV`.
+pub type TlvDecoder<V> = fn(&[u8]) -> Result<V, DecodeError>;
+
+/// Registry of decoders for custom (vendor-specific) `TLV` tags.
+///
+/// `TLV`s with unknown tags are decoded as [`TlvValue::Other`].
+/// The registry turns their raw bytes into a typed value `V` using the decoder registered for the tag.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{
+/// #     tlvs::{TlvTag, owned::{Tlv, TlvRegistry, TlvValue}},
+/// #     types::owned::AnyOctetString,
+/// # };
+/// #[derive(Debug, PartialEq)]
+/// struct VendorPriority(u8);
+///
+/// let mut registry = TlvRegistry::new();
+///
+/// registry.register(0x1400, |bytes| Ok(VendorPriority(bytes.first().copied().unwrap_or(0))));
+///
+/// let tlv = Tlv::new(TlvValue::Other {
+///     tag: TlvTag::Other(0x1400),
+///     value: AnyOctetString::new([7]),
+/// });
+///
+/// assert_eq!(registry.decode(&tlv).unwrap().unwrap(), VendorPriority(7));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TlvRegistry<V> {
+    decoders: BTreeMap<u16, TlvDecoder<V>>,
+}
+
+impl<V> Default for TlvRegistry<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> TlvRegistry<V> {
+    /// Creates a new empty [`TlvRegistry`].
+    pub const fn new() -> Self {
+        Self {
+            decoders: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a `decoder` for `tag`.
+    ///
+    /// Returns the previously registered decoder, if any.
+    pub fn register(&mut self, tag: u16, decoder: TlvDecoder<V>) -> Option<TlvDecoder<V>> {
+        self.decoders.insert(tag, decoder)
+    }
+
+    /// Removes the decoder registered for `tag`.
+    pub fn unregister(&mut self, tag: u16) -> Option<TlvDecoder<V>> {
+        self.decoders.remove(&tag)
+    }
+
+    /// Checks if a decoder is registered for `tag`.
+    pub fn is_registered(&self, tag: u16) -> bool {
+        self.decoders.contains_key(&tag)
+    }
+
+    /// Decodes the value of `tlv` using the decoder registered for its tag.
+    ///
+    /// Returns [`None`] if `tlv` is not a [`TlvValue::Other`] or no decoder is registered for its tag.
+    pub fn decode(&self, tlv: &Tlv) -> Option<Result<V, DecodeError>> {
+        match tlv.value() {
+            Some(TlvValue::Other {
+                tag: TlvTag::Other(tag),
+                value,
+            }) => self.decoders.get(tag).map(|decoder| decoder(value.bytes())),
+            _ => None,
+        }
+    }
+
+    /// Finds the first `TLV` with `tag` in `tlvs` and decodes its value.
+    ///
+    /// See [`TlvRegistry::decode`].
+    pub fn find(&self, tlvs: &[Tlv], tag: u16) -> Option<Result<V, DecodeError>> {
+        tlvs.iter()
+            .find(|tlv| tlv.tag() == TlvTag::Other(tag))
+            .and_then(|tlv| self.decode(tlv))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decode::{DecodeErrorKind, owned::Decode},
+        encode::Encode,
+        types::owned::AnyOctetString,
+    };
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct VendorCharge {
+        currency: u8,
+        amount: u32,
+    }
+
+    fn decode_vendor_charge(bytes: &[u8]) -> Result<VendorCharge, DecodeError> {
+        let [currency, a, b, c, d] = bytes else {
+            return Err(DecodeError::unexpected_eof());
+        };
+
+        Ok(VendorCharge {
+            currency: *currency,
+            amount: u32::from_be_bytes([*a, *b, *c, *d]),
+        })
+    }
+
+    #[test]
+    fn decode_registered_tag() {
+        let mut registry = TlvRegistry::new();
+
+        assert!(registry.register(0x1401, decode_vendor_charge).is_none());
+        assert!(registry.is_registered(0x1401));
+
+        let tlv = Tlv::new(TlvValue::Other {
+            tag: TlvTag::Other(0x1401),
+            value: AnyOctetString::new([1, 0, 0, 0x01, 0x2C]),
+        });
+
+        // Decode the tlv from the wire to make sure it goes through the Other path
+        let mut buf = [0u8; 16];
+        let size = tlv.encode(&mut buf);
+        let (decoded, _) = Tlv::decode(&buf[..size]).unwrap();
+
+        assert_eq!(
+            registry.decode(&decoded).and_then(Result::ok),
+            Some(VendorCharge {
+                currency: 1,
+                amount: 300
+            })
+        );
+
+        assert_eq!(
+            registry.find(&[decoded], 0x1401).and_then(Result::ok),
+            Some(VendorCharge {
+                currency: 1,
+                amount: 300
+            })
+        );
+    }
+
+    #[test]
+    fn decode_unregistered_or_known_tag() {
+        let mut registry = TlvRegistry::new();
+
+        registry.register(0x1401, decode_vendor_charge);
+
+        let other = Tlv::new(TlvValue::Other {
+            tag: TlvTag::Other(0x1402),
+            value: AnyOctetString::new([1, 2, 3]),
+        });
+
+        assert!(registry.decode(&other).is_none());
+
+        let known = Tlv::new(TlvValue::UserResponseCode(1));
+
+        assert!(registry.decode(&known).is_none());
+        assert!(registry.find(&[other, known], 0x1401).is_none());
+    }
+
+    #[test]
+    fn decode_error() {
+        let mut registry = TlvRegistry::new();
+
+        registry.register(0x1401, decode_vendor_charge);
+
+        let tlv = Tlv::new(TlvValue::Other {
+            tag: TlvTag::Other(0x1401),
+            value: AnyOctetString::new([1, 2]),
+        });
+
+        let error = registry.decode(&tlv).unwrap().unwrap_err();
+
+        assert!(matches!(error.kind(), DecodeErrorKind::UnexpectedEof));
+    }
+}
@@ -1,7 +1,12 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
+    decode::{
+        DecodeContext, DecodeError, DecodeErrorExt,
+        owned::{Decode, DecodeExt, DecodeWithContext},
+    },
     encode::Length,
+    fields::SmppField,
     tlvs::{owned::TlvValue, tag::TlvTag},
 };
 
@@ -31,15 +36,18 @@ pub use query_broadcast_response::*;
 
 /// See module level documentation.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = skip, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
 pub struct Tlv {
     tag: TlvTag,
     value_length: u16,
-    #[rusmpp(key = tag, length = value_length)]
     value: Option<TlvValue>,
+    /// `value_length` of the decoded `TLV` if its value was capped, not encoded.
+    #[rusmpp(skip)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    uncapped_value_length: Option<u16>,
 }
 
 impl Tlv {
@@ -52,6 +60,19 @@ impl Tlv {
             tag,
             value_length,
             value: Some(value),
+            uncapped_value_length: None,
+        }
+    }
+
+    /// Creates a [`Tlv`] without a value, encoded with a `value_length` of `0`.
+    ///
+    /// E.g. the `alert_on_message_delivery` `TLV` is an indicator that is typically sent without a value.
+    pub const fn empty(tag: TlvTag) -> Self {
+        Self {
+            tag,
+            value_length: 0,
+            value: None,
+            uncapped_value_length: None,
         }
     }
 
@@ -66,6 +87,73 @@ impl Tlv {
     pub const fn value(&self) -> Option<&TlvValue> {
         self.value.as_ref()
     }
+
+    /// Returns the `value_length` of the decoded `TLV` if its value was capped, see [`DecodeOptions::other_tlv_value_limit`](crate::decode::DecodeOptions::other_tlv_value_limit).
+    ///
+    /// The [`value_length`](Self::value_length) of a capped `TLV` is the length of the kept value,
+    /// so that the `TLV` is encoded consistently.
+    pub const fn uncapped_value_length(&self) -> Option<u16> {
+        self.uncapped_value_length
+    }
+
+    /// Returns `true` if the `TLV` has a `value_length` of `0`.
+    ///
+    /// Zero-length `TLV`s are decoded without a [`value`](Self::value), see [`Tlv::empty`].
+    pub const fn is_empty(&self) -> bool {
+        self.value_length == 0
+    }
+
+    /// Borrows the [`Tlv`] as a [`borrowed::Tlv`](crate::tlvs::borrowed::Tlv) without copying its value.
+    pub fn as_borrowed(&self) -> crate::tlvs::borrowed::Tlv<'_> {
+        self.into()
+    }
+}
+
+impl Decode for Tlv {
+    fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+        Self::decode_with_context(src, src.len(), &mut DecodeContext::default())
+    }
+}
+
+impl DecodeWithContext for Tlv {
+    /// Decodes a [`Tlv`] delimited by its `value_length`, `length` is not used.
+    fn decode_with_context(
+        src: &[u8],
+        _length: usize,
+        context: &mut DecodeContext,
+    ) -> Result<(Self, usize), DecodeError> {
+        let size = 0;
+
+        let (tag, size) = DecodeExt::decode_move(src, size).map_as_source(SmppField::tag)?;
+
+        let (value_length, size) =
+            DecodeExt::decode_move(src, size).map_as_source(SmppField::value_length)?;
+
+        let (value, size) = match value_length {
+            0 => (None, size),
+            length => TlvValue::decode_with_context(tag, &src[size..], length as usize, context)
+                .map(|(value, size_)| (Some(value), size + size_))
+                .map_as_source(SmppField::value)?,
+        };
+
+        // Only the values of unknown TLVs are capped
+        let (value_length, uncapped_value_length) = match &value {
+            Some(other @ TlvValue::Other { .. }) if other.length() < value_length as usize => {
+                (other.length() as u16, Some(value_length))
+            }
+            _ => (value_length, None),
+        };
+
+        Ok((
+            Self {
+                tag,
+                value_length,
+                value,
+                uncapped_value_length,
+            },
+            size,
+        ))
+    }
 }
 
 impl From<TlvValue> for Tlv {
@@ -73,3 +161,241 @@ impl From<TlvValue> for Tlv {
         Self::new(value)
     }
 }
+
+impl From<&crate::tlvs::borrowed::Tlv<'_>> for Tlv {
+    fn from(tlv: &crate::tlvs::borrowed::Tlv<'_>) -> Self {
+        Self {
+            tag: tlv.tag(),
+            value_length: tlv.value_length(),
+            value: tlv.value().map(TlvValue::from),
+            uncapped_value_length: None,
+        }
+    }
+}
+
+impl PartialEq<crate::tlvs::borrowed::Tlv<'_>> for Tlv {
+    fn eq(&self, other: &crate::tlvs::borrowed::Tlv<'_>) -> bool {
+        self.as_borrowed() == *other
+    }
+}
+
+#[cfg(feature = "std")]
+impl Tlv {
+    /// Writes the encoded [`Tlv`] into `w`.
+    ///
+    /// The [`TlvValue::MessagePayload`] bytes are written directly without an intermediate buffer.
+    pub(crate) fn encode_to_writer<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        use crate::encode::write_encoded;
+
+        let tag = u16::from(self.tag).to_be_bytes();
+        let value_length = self.value_length.to_be_bytes();
+
+        w.write_all(&[tag[0], tag[1], value_length[0], value_length[1]])?;
+
+        match &self.value {
+            Some(TlvValue::MessagePayload(payload)) => w.write_all(payload.value.bytes()),
+            Some(value) => write_encoded(value, w),
+            None => Ok(()),
+        }
+    }
+}
+
+impl crate::validate::Validate for Tlv {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        use crate::encode::Length;
+
+        crate::validate::ValidationError::length_match(
+            crate::fields::SmppField::value_length,
+            self.value_length as usize,
+            self.value.length(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decode::{borrowed::Decode as _, owned::Decode as _},
+        encode::Encode,
+        types::owned::{AnyOctetString, COctetString, OctetString},
+        values::{owned::*, *},
+    };
+
+    use super::*;
+
+    fn tlvs() -> alloc::vec::Vec<Tlv> {
+        alloc::vec![
+            Tlv::new(TlvValue::ReceiptedMessageId(
+                COctetString::new(b"message-id\0").unwrap()
+            )),
+            Tlv::new(TlvValue::CallbackNum(OctetString::new(b"12345").unwrap())),
+            Tlv::new(TlvValue::DestSubaddress(Subaddress::new(
+                SubaddressTag::UserSpecified,
+                OctetString::new(b"sub").unwrap(),
+            ))),
+            Tlv::new(TlvValue::MessagePayload(MessagePayload::new(
+                AnyOctetString::new(b"Hello"),
+            ))),
+            Tlv::new(TlvValue::BroadcastAreaIdentifier(
+                BroadcastAreaIdentifier::new(
+                    BroadcastAreaFormat::AliasName,
+                    AnyOctetString::new(b"area"),
+                )
+            )),
+            Tlv::new(TlvValue::MsValidity(MsValidity::new(
+                MsValidityBehavior::StoreIndefinitely,
+                None,
+            ))),
+            Tlv::new(TlvValue::UserMessageReference(UserMessageReference::new(
+                16
+            ))),
+            Tlv::new(TlvValue::Other {
+                tag: TlvTag::Other(0x1400),
+                value: AnyOctetString::new([0x01, 0x02]),
+            }),
+        ]
+    }
+
+    #[test]
+    fn owned_to_borrowed_to_owned() {
+        for tlv in tlvs() {
+            let borrowed = tlv.as_borrowed();
+
+            assert_eq!(borrowed.tag(), tlv.tag());
+            assert_eq!(borrowed.value_length(), tlv.value_length());
+            assert_eq!(borrowed.to_owned(), tlv);
+        }
+    }
+
+    #[test]
+    fn decode_borrowed_then_promote() {
+        for tlv in tlvs() {
+            let mut buf = [0; 64];
+            let size = tlv.encode(&mut buf);
+
+            let (borrowed, _) = crate::tlvs::borrowed::Tlv::decode(&buf[..size]).unwrap();
+            let (owned, _) = Tlv::decode(&buf[..size]).unwrap();
+
+            assert_eq!(borrowed.to_owned(), owned);
+            assert_eq!(owned.as_borrowed(), borrowed);
+        }
+    }
+
+    #[test]
+    fn owned_borrowed_eq() {
+        for tlv in tlvs() {
+            let mut buf = [0; 64];
+            let size = tlv.encode(&mut buf);
+
+            let (borrowed, _) = crate::tlvs::borrowed::Tlv::decode(&buf[..size]).unwrap();
+
+            assert_eq!(tlv, borrowed);
+            assert_eq!(borrowed, tlv);
+        }
+
+        let tlv = Tlv::new(TlvValue::UserMessageReference(UserMessageReference::new(
+            16,
+        )));
+        let other = crate::tlvs::borrowed::Tlv::new(
+            crate::tlvs::borrowed::TlvValue::UserMessageReference(UserMessageReference::new(17)),
+        );
+
+        assert_ne!(tlv, other);
+        assert_ne!(other, tlv);
+    }
+
+    #[test]
+    fn empty() {
+        let bytes = [0x13, 0x0C, 0x00, 0x00];
+
+        let (tlv, size) = Tlv::decode(&bytes).unwrap();
+
+        assert_eq!(size, 4);
+        assert_eq!(tlv.tag(), TlvTag::AlertOnMessageDelivery);
+        assert!(tlv.is_empty());
+        assert_eq!(tlv.value(), None);
+        assert_eq!(tlv, Tlv::empty(TlvTag::AlertOnMessageDelivery));
+
+        let mut buf = [0; 4];
+
+        assert_eq!(tlv.encode(&mut buf), 4);
+        assert_eq!(buf, bytes);
+
+        let (borrowed, _) = crate::tlvs::borrowed::Tlv::decode(&bytes).unwrap();
+
+        assert!(borrowed.is_empty());
+        assert_eq!(
+            borrowed,
+            crate::tlvs::borrowed::Tlv::empty(TlvTag::AlertOnMessageDelivery)
+        );
+
+        // Unknown tags
+        let (tlv, _) = Tlv::decode(&[0x14, 0x00, 0x00, 0x00]).unwrap();
+
+        assert_eq!(tlv, Tlv::empty(TlvTag::Other(0x1400)));
+
+        assert!(
+            !Tlv::new(TlvValue::AlertOnMessageDelivery(
+                AlertOnMessageDelivery::UseMobileDefaultAlert
+            ))
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn empty_is_present() {
+        use crate::pdus::owned::{PduBody, SubmitSm, TlvContainer};
+
+        let mut body = SubmitSm::default().encode_body();
+
+        body.extend_from_slice(&[0x13, 0x0C, 0x00, 0x00]);
+
+        let submit_sm = SubmitSm::decode_body(&body).unwrap();
+
+        let tlv = submit_sm.get_tlv(TlvTag::AlertOnMessageDelivery).unwrap();
+
+        assert!(tlv.is_empty());
+        assert_eq!(tlv.value(), None);
+        assert_eq!(submit_sm.get_tlv(TlvTag::SmsSignal), None);
+    }
+
+    #[test]
+    fn malformed_value_reports_tag() {
+        use crate::{
+            decode::{DecodeErrorKind, DecodeErrorTlv},
+            pdus::owned::{DeliverSm, PduBody},
+        };
+
+        // network_error_code with a value_length of 3 but only 2 bytes
+        let bytes = [0x04, 0x23, 0x00, 0x03, 0x03, 0x00];
+
+        let error = Tlv::decode(&bytes).unwrap_err();
+
+        assert!(matches!(error.kind(), DecodeErrorKind::UnexpectedEof));
+        assert_eq!(
+            error.tlv(),
+            Some(DecodeErrorTlv {
+                tag: 0x0423,
+                remaining: 2
+            })
+        );
+
+        let error = crate::tlvs::borrowed::Tlv::decode(&bytes).unwrap_err();
+
+        assert_eq!(error.tlv().map(|tlv| tlv.tag), Some(0x0423));
+
+        let mut body = DeliverSm::default().encode_body();
+
+        body.extend_from_slice(&[0x02, 0x0A, 0x00, 0x02, 0x00, 0x01]);
+        body.extend_from_slice(&bytes);
+
+        let error = DeliverSm::decode_body(&body).unwrap_err();
+
+        assert_eq!(error.tlv().map(|tlv| tlv.tag), Some(0x0423));
+
+        // Errors outside of TLV values have no tag
+        let error = Tlv::decode(&[0x04, 0x23, 0x00]).unwrap_err();
+
+        assert_eq!(error.tlv(), None);
+    }
+}
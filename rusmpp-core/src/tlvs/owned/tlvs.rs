@@ -0,0 +1,199 @@
+use alloc::vec::Vec;
+
+use crate::{
+    decode::{DecodeError, owned::DecodeWithLength},
+    encode::{Encode, Length},
+    tlvs::{
+        TlvTag,
+        owned::{Tlv, get_tlv, remove_tlv, set_tlv},
+    },
+};
+
+/// The optional parameters block of a `PDU`, encoded and decoded on its own.
+///
+/// The block is the sequence of [`Tlv`]s following the mandatory fields of a `PDU`, up to the `command_length`.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{encode::Encode, tlvs::{TlvTag, owned::{Tlv, TlvValue, Tlvs}}};
+/// let bytes = [
+///     // user_message_reference
+///     0x02, 0x04, 0x00, 0x02, 0x00, 0x01,
+///     // source_port
+///     0x02, 0x0A, 0x00, 0x02, 0x00, 0x02,
+/// ];
+///
+/// let mut tlvs = Tlvs::decode(&bytes).unwrap();
+///
+/// assert_eq!(tlvs.len(), 2);
+/// assert_eq!(tlvs.get(TlvTag::SourcePort), Some(&Tlv::new(TlvValue::SourcePort(2))));
+///
+/// tlvs.remove(TlvTag::SourcePort);
+///
+/// assert_eq!(tlvs.to_bytes(), &bytes[..6]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub struct Tlvs {
+    tlvs: Vec<Tlv>,
+}
+
+impl Tlvs {
+    pub const fn new(tlvs: Vec<Tlv>) -> Self {
+        Self { tlvs }
+    }
+
+    /// Decodes all `bytes` as a sequence of [`Tlv`]s.
+    ///
+    /// Fails if the last `TLV` is incomplete.
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let (tlvs, _) = Vec::<Tlv>::decode(bytes, bytes.len())?;
+
+        Ok(Self { tlvs })
+    }
+
+    /// Encodes the [`Tlv`]s into a new [`Vec`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = alloc::vec![0; self.length()];
+
+        self.encode(&mut bytes);
+
+        bytes
+    }
+
+    pub fn tlvs(&self) -> &[Tlv] {
+        &self.tlvs
+    }
+
+    pub fn into_tlvs(self) -> Vec<Tlv> {
+        self.tlvs
+    }
+
+    pub fn len(&self) -> usize {
+        self.tlvs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tlvs.is_empty()
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, Tlv> {
+        self.tlvs.iter()
+    }
+
+    /// Returns the first [`Tlv`] with the given `tag`, see [`get_tlv`].
+    pub fn get(&self, tag: TlvTag) -> Option<&Tlv> {
+        get_tlv(&self.tlvs, tag)
+    }
+
+    pub fn push(&mut self, tlv: impl Into<Tlv>) {
+        self.tlvs.push(tlv.into());
+    }
+
+    /// Replaces the [`Tlv`] with the same tag or appends it, see [`set_tlv`].
+    pub fn set(&mut self, tlv: impl Into<Tlv>) -> Option<Tlv> {
+        set_tlv(&mut self.tlvs, tlv.into())
+    }
+
+    /// Removes all [`Tlv`]s with the given `tag`, see [`remove_tlv`].
+    pub fn remove(&mut self, tag: TlvTag) -> Option<Tlv> {
+        remove_tlv(&mut self.tlvs, tag)
+    }
+}
+
+impl Length for Tlvs {
+    fn length(&self) -> usize {
+        self.tlvs.length()
+    }
+}
+
+impl Encode for Tlvs {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        self.tlvs.encode(dst)
+    }
+}
+
+impl From<Vec<Tlv>> for Tlvs {
+    fn from(tlvs: Vec<Tlv>) -> Self {
+        Self::new(tlvs)
+    }
+}
+
+impl From<Tlvs> for Vec<Tlv> {
+    fn from(tlvs: Tlvs) -> Self {
+        tlvs.tlvs
+    }
+}
+
+impl FromIterator<Tlv> for Tlvs {
+    fn from_iter<I: IntoIterator<Item = Tlv>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Tlvs {
+    type Item = Tlv;
+    type IntoIter = alloc::vec::IntoIter<Tlv>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tlvs.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Tlvs {
+    type Item = &'a Tlv;
+    type IntoIter = core::slice::Iter<'a, Tlv>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tlvs.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tlvs::owned::TlvValue,
+        types::owned::{AnyOctetString, COctetString},
+    };
+
+    use super::*;
+
+    #[test]
+    fn standalone_block() {
+        let bytes = [
+            // receipted_message_id
+            0x00, 0x1E, 0x00, 0x05, 0x30, 0x30, 0x30, 0x31, 0x00, //
+            // alert_on_message_delivery without a value
+            0x13, 0x0C, 0x00, 0x00, //
+            // vendor specific
+            0x14, 0x00, 0x00, 0x02, 0xAB, 0xCD,
+        ];
+
+        let tlvs = Tlvs::decode(&bytes).unwrap();
+
+        assert_eq!(
+            tlvs.tlvs(),
+            [
+                Tlv::new(TlvValue::ReceiptedMessageId(
+                    COctetString::new(b"0001\0").unwrap()
+                )),
+                Tlv::empty(TlvTag::AlertOnMessageDelivery),
+                Tlv::new(TlvValue::Other {
+                    tag: TlvTag::Other(0x1400),
+                    value: AnyOctetString::new([0xAB, 0xCD]),
+                }),
+            ]
+        );
+
+        assert_eq!(tlvs.length(), bytes.len());
+        assert_eq!(tlvs.to_bytes(), bytes);
+
+        assert_eq!(Tlvs::decode(&[]).unwrap(), Tlvs::default());
+
+        // The last TLV is truncated
+        assert!(Tlvs::decode(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     CommandStatus,
     decode::{
-        DecodeError, DecodeResultExt,
+        DecodeContext, DecodeError, DecodeResultExt,
         owned::{Decode, DecodeWithKey, DecodeWithLength},
     },
     encode::{Encode, Length},
@@ -338,6 +338,39 @@ impl DecodeWithKey for TlvValue {
     type Key = TlvTag;
 
     fn decode(key: Self::Key, src: &[u8], length: usize) -> Result<(Self, usize), DecodeError> {
+        Self::decode_with_context(key, src, length, &mut DecodeContext::default())
+    }
+}
+
+impl TlvValue {
+    /// Decodes the value of the `TLV` with the tag `key`, see [`DecodeOptions::lenient_tlvs`](crate::decode::DecodeOptions::lenient_tlvs).
+    pub(crate) fn decode_with_context(
+        key: TlvTag,
+        src: &[u8],
+        length: usize,
+        context: &mut DecodeContext,
+    ) -> Result<(Self, usize), DecodeError> {
+        match Self::decode_value(key, src, length, context) {
+            Ok(decoded) => Ok(decoded),
+            Err(_) if context.options().lenient_tlvs && src.len() >= length => Ok((
+                TlvValue::Other {
+                    tag: key,
+                    value: AnyOctetString::new(&src[..length]),
+                },
+                length,
+            )),
+            Err(error) => Err(error.with_tlv(key.into(), src.len())),
+        }
+    }
+}
+
+impl TlvValue {
+    fn decode_value(
+        key: TlvTag,
+        src: &[u8],
+        length: usize,
+        context: &mut DecodeContext,
+    ) -> Result<(Self, usize), DecodeError> {
         let (value, size) = match key {
             TlvTag::AdditionalStatusInfoText => {
                 Decode::decode(src).map_decoded(Self::AdditionalStatusInfoText)?
@@ -476,7 +509,7 @@ impl DecodeWithKey for TlvValue {
             TlvTag::UserResponseCode => Decode::decode(src).map_decoded(Self::UserResponseCode)?,
             TlvTag::UssdServiceOp => Decode::decode(src).map_decoded(Self::UssdServiceOp)?,
             TlvTag::Other(other) => {
-                DecodeWithLength::decode(src, length).map_decoded(|value| TlvValue::Other {
+                Self::decode_other(src, length, context).map_decoded(|value| TlvValue::Other {
                     tag: TlvTag::Other(other),
                     value,
                 })?
@@ -486,3 +519,52 @@ impl DecodeWithKey for TlvValue {
         Ok((value, size))
     }
 }
+
+impl TlvValue {
+    /// Decodes the value of an unknown `TLV`, see [`DecodeOptions::other_tlv_value_limit`](crate::decode::DecodeOptions::other_tlv_value_limit).
+    ///
+    /// Reuses a buffer pooled in the `context` by a [`Decoder`](crate::decode::Decoder), if available.
+    fn decode_other(
+        src: &[u8],
+        length: usize,
+        context: &mut DecodeContext,
+    ) -> Result<(AnyOctetString, usize), DecodeError> {
+        if src.len() < length {
+            return Err(DecodeError::unexpected_eof());
+        }
+
+        let copied = context
+            .options()
+            .other_tlv_value_limit
+            .map_or(length, |limit| length.min(limit));
+
+        if copied < length {
+            return Ok((AnyOctetString::new(&src[..copied]), length));
+        }
+
+        if let Some(mut buffer) = context.take_buffer(length) {
+            buffer.clear();
+            buffer.extend_from_slice(&src[..length]);
+
+            return Ok((AnyOctetString::from(buffer), length));
+        }
+
+        DecodeWithLength::decode(src, length)
+    }
+}
+
+impl From<&crate::tlvs::borrowed::TlvValue<'_>> for TlvValue {
+    fn from(value: &crate::tlvs::borrowed::TlvValue<'_>) -> Self {
+        use crate::tlvs::borrowed::TlvValue as Borrowed;
+
+        convert_tlv_value!(value, Borrowed => TlvValue)
+    }
+}
+
+impl<'a> From<&'a TlvValue> for crate::tlvs::borrowed::TlvValue<'a> {
+    fn from(value: &'a TlvValue) -> Self {
+        use crate::tlvs::borrowed::TlvValue as Borrowed;
+
+        convert_tlv_value!(value, TlvValue => Borrowed)
+    }
+}
@@ -4,6 +4,7 @@ use rusmpp_macros::Rusmpp;
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
 #[repr(u16)]
 #[rusmpp(test = skip)]
+#[cfg_attr(test, derive(strum_macros::EnumCount, strum_macros::EnumIter))]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
@@ -429,3 +430,235 @@ pub enum TlvTag {
     /// or logging purposes.
     Other(u16),
 }
+
+impl TlvTag {
+    /// Returns an iterator over all known `TLV` tags, i.e. every tag except [`TlvTag::Other`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::tlvs::TlvTag;
+    /// assert!(TlvTag::all().any(|tag| tag == TlvTag::MessagePayload));
+    /// assert!(TlvTag::all().all(|tag| !matches!(tag, TlvTag::Other(_))));
+    /// ```
+    pub fn all() -> impl Iterator<Item = TlvTag> {
+        KNOWN.into_iter()
+    }
+
+    /// Checks if a `PDU` may carry more than one `TLV` with this tag.
+    ///
+    /// e.g. a `broadcast_sm` carries one `broadcast_area_identifier` per area.
+    /// Unknown ([`TlvTag::Other`]) tags are vendor specific and always repeatable.
+    pub const fn is_repeatable(self) -> bool {
+        matches!(
+            self,
+            TlvTag::BroadcastAreaIdentifier | TlvTag::BroadcastAreaSuccess | TlvTag::Other(_)
+        )
+    }
+
+    /// Returns the canonical parameter name of the tag, e.g. `message_payload`.
+    ///
+    /// Returns [`None`] for [`TlvTag::Other`].
+    pub const fn name(self) -> Option<&'static str> {
+        match self {
+            TlvTag::DestAddrSubunit => Some("dest_addr_subunit"),
+            TlvTag::DestNetworkType => Some("dest_network_type"),
+            TlvTag::DestBearerType => Some("dest_bearer_type"),
+            TlvTag::DestTelematicsId => Some("dest_telematics_id"),
+            TlvTag::SourceAddrSubunit => Some("source_addr_subunit"),
+            TlvTag::SourceNetworkType => Some("source_network_type"),
+            TlvTag::SourceBearerType => Some("source_bearer_type"),
+            TlvTag::SourceTelematicsId => Some("source_telematics_id"),
+            TlvTag::QosTimeToLive => Some("qos_time_to_live"),
+            TlvTag::PayloadType => Some("payload_type"),
+            TlvTag::AdditionalStatusInfoText => Some("additional_status_info_text"),
+            TlvTag::ReceiptedMessageId => Some("receipted_message_id"),
+            TlvTag::MsMsgWaitFacilities => Some("ms_msg_wait_facilities"),
+            TlvTag::PrivacyIndicator => Some("privacy_indicator"),
+            TlvTag::SourceSubaddress => Some("source_subaddress"),
+            TlvTag::DestSubaddress => Some("dest_subaddress"),
+            TlvTag::UserMessageReference => Some("user_message_reference"),
+            TlvTag::UserResponseCode => Some("user_response_code"),
+            TlvTag::SourcePort => Some("source_port"),
+            TlvTag::DestPort => Some("dest_port"),
+            TlvTag::SarMsgRefNum => Some("sar_msg_ref_num"),
+            TlvTag::LanguageIndicator => Some("language_indicator"),
+            TlvTag::SarTotalSegments => Some("sar_total_segments"),
+            TlvTag::SarSegmentSeqnum => Some("sar_segment_seqnum"),
+            TlvTag::ScInterfaceVersion => Some("sc_interface_version"),
+            TlvTag::CallbackNumPresInd => Some("callback_num_pres_ind"),
+            TlvTag::CallbackNumAtag => Some("callback_num_atag"),
+            TlvTag::NumberOfMessages => Some("number_of_messages"),
+            TlvTag::CallbackNum => Some("callback_num"),
+            TlvTag::DpfResult => Some("dpf_result"),
+            TlvTag::SetDpf => Some("set_dpf"),
+            TlvTag::MsAvailabilityStatus => Some("ms_availability_status"),
+            TlvTag::NetworkErrorCode => Some("network_error_code"),
+            TlvTag::MessagePayload => Some("message_payload"),
+            TlvTag::DeliveryFailureReason => Some("delivery_failure_reason"),
+            TlvTag::MoreMessagesToSend => Some("more_messages_to_send"),
+            TlvTag::MessageState => Some("message_state"),
+            TlvTag::CongestionState => Some("congestion_state"),
+            TlvTag::UssdServiceOp => Some("ussd_service_op"),
+            TlvTag::BroadcastChannelIndicator => Some("broadcast_channel_indicator"),
+            TlvTag::BroadcastContentType => Some("broadcast_content_type"),
+            TlvTag::BroadcastContentTypeInfo => Some("broadcast_content_type_info"),
+            TlvTag::BroadcastMessageClass => Some("broadcast_message_class"),
+            TlvTag::BroadcastRepNum => Some("broadcast_rep_num"),
+            TlvTag::BroadcastFrequencyInterval => Some("broadcast_frequency_interval"),
+            TlvTag::BroadcastAreaIdentifier => Some("broadcast_area_identifier"),
+            TlvTag::BroadcastErrorStatus => Some("broadcast_error_status"),
+            TlvTag::BroadcastAreaSuccess => Some("broadcast_area_success"),
+            TlvTag::BroadcastEndTime => Some("broadcast_end_time"),
+            TlvTag::BroadcastServiceGroup => Some("broadcast_service_group"),
+            TlvTag::BillingIdentification => Some("billing_identification"),
+            TlvTag::SourceNetworkId => Some("source_network_id"),
+            TlvTag::DestNetworkId => Some("dest_network_id"),
+            TlvTag::SourceNodeId => Some("source_node_id"),
+            TlvTag::DestNodeId => Some("dest_node_id"),
+            TlvTag::DestAddrNpResolution => Some("dest_addr_np_resolution"),
+            TlvTag::DestAddrNpInformation => Some("dest_addr_np_information"),
+            TlvTag::DestAddrNpCountry => Some("dest_addr_np_country"),
+            TlvTag::DisplayTime => Some("display_time"),
+            TlvTag::SmsSignal => Some("sms_signal"),
+            TlvTag::MsValidity => Some("ms_validity"),
+            TlvTag::AlertOnMessageDelivery => Some("alert_on_message_delivery"),
+            TlvTag::ItsReplyType => Some("its_reply_type"),
+            TlvTag::ItsSessionInfo => Some("its_session_info"),
+            TlvTag::Other(_) => None,
+        }
+    }
+
+    /// Returns the known tag with the canonical parameter `name`, e.g. `message_payload`.
+    ///
+    /// Names are matched case-insensitively.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::tlvs::TlvTag;
+    /// assert_eq!(TlvTag::from_name("receipted_message_id"), Some(TlvTag::ReceiptedMessageId));
+    /// assert_eq!(TlvTag::from_name("vendor_tlv"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<TlvTag> {
+        TlvTag::all().find(|tag| {
+            tag.name()
+                .is_some_and(|known| known.eq_ignore_ascii_case(name))
+        })
+    }
+}
+
+/// All known `TLV` tags in declaration order.
+const KNOWN: [TlvTag; 64] = [
+    TlvTag::DestAddrSubunit,
+    TlvTag::DestNetworkType,
+    TlvTag::DestBearerType,
+    TlvTag::DestTelematicsId,
+    TlvTag::SourceAddrSubunit,
+    TlvTag::SourceNetworkType,
+    TlvTag::SourceBearerType,
+    TlvTag::SourceTelematicsId,
+    TlvTag::QosTimeToLive,
+    TlvTag::PayloadType,
+    TlvTag::AdditionalStatusInfoText,
+    TlvTag::ReceiptedMessageId,
+    TlvTag::MsMsgWaitFacilities,
+    TlvTag::PrivacyIndicator,
+    TlvTag::SourceSubaddress,
+    TlvTag::DestSubaddress,
+    TlvTag::UserMessageReference,
+    TlvTag::UserResponseCode,
+    TlvTag::SourcePort,
+    TlvTag::DestPort,
+    TlvTag::SarMsgRefNum,
+    TlvTag::LanguageIndicator,
+    TlvTag::SarTotalSegments,
+    TlvTag::SarSegmentSeqnum,
+    TlvTag::ScInterfaceVersion,
+    TlvTag::CallbackNumPresInd,
+    TlvTag::CallbackNumAtag,
+    TlvTag::NumberOfMessages,
+    TlvTag::CallbackNum,
+    TlvTag::DpfResult,
+    TlvTag::SetDpf,
+    TlvTag::MsAvailabilityStatus,
+    TlvTag::NetworkErrorCode,
+    TlvTag::MessagePayload,
+    TlvTag::DeliveryFailureReason,
+    TlvTag::MoreMessagesToSend,
+    TlvTag::MessageState,
+    TlvTag::CongestionState,
+    TlvTag::UssdServiceOp,
+    TlvTag::BroadcastChannelIndicator,
+    TlvTag::BroadcastContentType,
+    TlvTag::BroadcastContentTypeInfo,
+    TlvTag::BroadcastMessageClass,
+    TlvTag::BroadcastRepNum,
+    TlvTag::BroadcastFrequencyInterval,
+    TlvTag::BroadcastAreaIdentifier,
+    TlvTag::BroadcastErrorStatus,
+    TlvTag::BroadcastAreaSuccess,
+    TlvTag::BroadcastEndTime,
+    TlvTag::BroadcastServiceGroup,
+    TlvTag::BillingIdentification,
+    TlvTag::SourceNetworkId,
+    TlvTag::DestNetworkId,
+    TlvTag::SourceNodeId,
+    TlvTag::DestNodeId,
+    TlvTag::DestAddrNpResolution,
+    TlvTag::DestAddrNpInformation,
+    TlvTag::DestAddrNpCountry,
+    TlvTag::DisplayTime,
+    TlvTag::SmsSignal,
+    TlvTag::MsValidity,
+    TlvTag::AlertOnMessageDelivery,
+    TlvTag::ItsReplyType,
+    TlvTag::ItsSessionInfo,
+];
+
+#[cfg(test)]
+mod tests {
+    use strum::{EnumCount, IntoEnumIterator};
+
+    use super::*;
+
+    #[test]
+    fn all_known_tags() {
+        // Every variant but `Other`
+        assert_eq!(TlvTag::all().count(), TlvTag::COUNT - 1);
+        assert!(TlvTag::all().all(|tag| !matches!(tag, TlvTag::Other(_))));
+
+        assert!(
+            TlvTag::iter()
+                .filter(|tag| !matches!(tag, TlvTag::Other(_)))
+                .eq(TlvTag::all())
+        );
+    }
+
+    #[test]
+    fn from_name() {
+        assert_eq!(
+            TlvTag::from_name("receipted_message_id"),
+            Some(TlvTag::ReceiptedMessageId)
+        );
+        assert_eq!(
+            u16::from(TlvTag::from_name("receipted_message_id").unwrap()),
+            0x001E
+        );
+        assert_eq!(
+            TlvTag::from_name("MESSAGE_PAYLOAD"),
+            Some(TlvTag::MessagePayload)
+        );
+
+        assert_eq!(TlvTag::from_name("unknown_parameter"), None);
+        assert_eq!(TlvTag::from_name(""), None);
+    }
+
+    #[test]
+    fn all_known_tags_round_trip() {
+        for tag in TlvTag::all() {
+            assert_eq!(TlvTag::from(u16::from(tag)), tag);
+            assert_eq!(TlvTag::from_name(tag.name().unwrap()), Some(tag));
+        }
+    }
+}
@@ -94,19 +94,19 @@ impl Encoder<&Command> for CommandCodec {
     type Error = EncodeError;
 
     fn encode(&mut self, command: &Command, dst: &mut BytesMut) -> Result<(), Self::Error> {
-        let command_length = 4 + command.length();
+        let length = command.length();
+        let command_length = 4 + length;
 
         dst.reserve(command_length);
         dst.put_u32(command_length as u32);
 
-        // TODO: Can we encode directly into dst?
-        let mut buf = alloc::vec![0; command.length()];
-        let _ = command.encode(buf.as_mut_slice());
+        let start = dst.len();
+        dst.resize(start + length, 0);
 
-        dst.put_slice(&buf);
+        let _ = command.encode(&mut dst[start..]);
 
         debug!(target: "rusmpp::codec::encode", command=?command, "Encoding");
-        debug!(target: "rusmpp::codec::encode", encoded=?crate::formatter::Formatter(&buf), encoded_length=command.length(), command_length, "Encoded");
+        debug!(target: "rusmpp::codec::encode", encoded=?crate::formatter::Formatter(&dst[start..]), encoded_length=length, command_length, "Encoded");
 
         Ok(())
     }
@@ -11,7 +11,7 @@ use tokio_util::codec::{Framed, FramedRead, FramedWrite};
 
 use crate::{
     CommandStatus,
-    command::owned::Command,
+    command::owned::{Command, encode_many},
     encode::{Encode, Length},
     pdus::owned::*,
     tests::owned::test_commands,
@@ -50,6 +50,25 @@ async fn encode_decode() {
     assert_eq!(client_commands, commands);
 }
 
+#[tokio::test]
+async fn encode_many_decode() {
+    let commands = test_commands();
+
+    let mut buf = Vec::new();
+
+    encode_many(&commands, &mut buf);
+
+    let mut framed_reader = FramedRead::new(buf.as_slice(), CommandCodec::new());
+
+    let mut decoded_commands = Vec::new();
+
+    while let Some(command) = framed_reader.next().await {
+        decoded_commands.push(command.expect("Failed to decode PDU"));
+    }
+
+    assert_eq!(decoded_commands, commands);
+}
+
 #[tokio::test]
 async fn max_length() {
     let max_length = 16;
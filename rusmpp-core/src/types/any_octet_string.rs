@@ -1 +1,26 @@
 //! No fixed size [`OctetString`](struct@crate::types::octet_string).
+
+/// An Error that can occur when creating an `AnyOctetString` from a hex string.
+#[derive(Debug)]
+pub enum Error {
+    /// The hex string has an odd number of characters.
+    OddLength { length: usize },
+    /// The hex string contains a non-hex character at `index`.
+    InvalidHexCharacter { character: char, index: usize },
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::OddLength { length } => write!(f, "Odd hex length. length: {length}"),
+            Error::InvalidHexCharacter { character, index } => {
+                write!(
+                    f,
+                    "Invalid hex character. character: {character:?}, index: {index}"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
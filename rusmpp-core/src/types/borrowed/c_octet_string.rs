@@ -188,7 +188,22 @@ impl<const MIN: usize, const MAX: usize> Default for COctetString<'_, MIN, MAX>
 
 impl<const MIN: usize, const MAX: usize> core::fmt::Display for COctetString<'_, MIN, MAX> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str(self.as_str())
+        for chunk in self.bytes[..self.bytes.len() - 1].utf8_chunks() {
+            f.write_str(chunk.valid())?;
+
+            if !chunk.invalid().is_empty() {
+                f.write_str("\u{FFFD}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// See [`COctetString::as_str`].
+impl<const MIN: usize, const MAX: usize> AsRef<str> for COctetString<'_, MIN, MAX> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
     }
 }
 
@@ -386,6 +401,28 @@ mod tests {
             let string = COctetString::<1, 6>::new(bytes).unwrap();
             assert_eq!(string.as_str(), "Hello");
         }
+
+        #[test]
+        fn as_ref() {
+            let message_id = COctetString::<1, 65>::new(b"0123456789ABCDEF\0").unwrap();
+            let str: &str = message_id.as_ref();
+            assert_eq!(str, "0123456789ABCDEF");
+        }
+
+        #[test]
+        fn display() {
+            let message_id = COctetString::<1, 65>::new(b"0123456789ABCDEF\0").unwrap();
+            assert_eq!(
+                std::format!("message_id: {message_id}"),
+                "message_id: 0123456789ABCDEF"
+            );
+        }
+
+        #[test]
+        fn display_lossy() {
+            let string = COctetString::<1, 6>::new_unchecked(b"H\xFFi\0");
+            assert_eq!(std::format!("{string}"), "H\u{FFFD}i");
+        }
     }
 
     mod decode {
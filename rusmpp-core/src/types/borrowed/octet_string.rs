@@ -76,6 +76,13 @@ impl<'a, const MIN: usize, const MAX: usize> OctetString<'a, MIN, MAX> {
         self.bytes.is_empty()
     }
 
+    /// Create a new [`OctetString`] from a sequence of bytes without checking the length.
+    #[inline]
+    #[cfg(any(test, feature = "alloc"))]
+    pub(crate) const fn new_unchecked(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
     pub const fn new(bytes: &'a [u8]) -> Result<Self, Error> {
         Self::_ASSERT_MIN_LESS_THAN_OR_EQUAL_TO_MAX;
 
@@ -7,6 +7,7 @@ mod empty_or_full_c_octet_string;
 pub use empty_or_full_c_octet_string::Error as EmptyOrFullCOctetStringError;
 
 mod any_octet_string;
+pub use any_octet_string::Error as AnyOctetStringError;
 
 mod octet_string;
 pub use octet_string::Error as OctetStringError;
@@ -3,6 +3,7 @@ use alloc::{string::String, string::ToString, vec::Vec};
 use crate::{
     decode::{DecodeError, owned::DecodeWithLength},
     encode::{Encode, Length},
+    types::any_octet_string::Error,
 };
 
 /// No fixed size [`OctetString`](struct@crate::types::owned::octet_string::OctetString).
@@ -67,6 +68,48 @@ impl AnyOctetString {
     pub fn into_bytes(self) -> Vec<u8> {
         self.bytes
     }
+
+    /// Create a new [`AnyOctetString`] from a hex string.
+    ///
+    /// Accepts both upper and lower case hex digits. E.g. `"0A1b2C3d"`.
+    pub fn from_hex(hex: &str) -> Result<Self, Error> {
+        let hex = hex.as_bytes();
+
+        if hex.len() % 2 != 0 {
+            return Err(Error::OddLength { length: hex.len() });
+        }
+
+        let nibble = |index: usize| match hex[index] {
+            b @ b'0'..=b'9' => Ok(b - b'0'),
+            b @ b'a'..=b'f' => Ok(b - b'a' + 10),
+            b @ b'A'..=b'F' => Ok(b - b'A' + 10),
+            b => Err(Error::InvalidHexCharacter {
+                character: b as char,
+                index,
+            }),
+        };
+
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|index| Ok((nibble(index)? << 4) | nibble(index + 1)?))
+            .collect::<Result<Vec<u8>, Error>>()?;
+
+        Ok(Self { bytes })
+    }
+
+    /// Convert an [`AnyOctetString`] to a lower case hex [`String`].
+    pub fn to_hex(&self) -> String {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+
+        let mut hex = String::with_capacity(self.bytes.len() * 2);
+
+        for byte in self.bytes.iter() {
+            hex.push(HEX[(byte >> 4) as usize] as char);
+            hex.push(HEX[(byte & 0x0F) as usize] as char);
+        }
+
+        hex
+    }
 }
 
 impl From<Vec<u8>> for AnyOctetString {
@@ -116,6 +159,18 @@ impl AsRef<[u8]> for AnyOctetString {
     }
 }
 
+impl From<&crate::types::borrowed::AnyOctetString<'_>> for AnyOctetString {
+    fn from(any_octet_string: &crate::types::borrowed::AnyOctetString<'_>) -> Self {
+        Self::new(any_octet_string.bytes())
+    }
+}
+
+impl<'a> From<&'a AnyOctetString> for crate::types::borrowed::AnyOctetString<'a> {
+    fn from(any_octet_string: &'a AnyOctetString) -> Self {
+        Self::new(&any_octet_string.bytes)
+    }
+}
+
 impl Length for AnyOctetString {
     fn length(&self) -> usize {
         self.bytes.len()
@@ -181,6 +236,45 @@ mod tests {
         }
     }
 
+    mod hex {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let string = AnyOctetString::from_hex("0A1b2C3d").unwrap();
+            assert_eq!(string.bytes(), &[0x0A, 0x1B, 0x2C, 0x3D]);
+            assert_eq!(string.to_hex(), "0a1b2c3d");
+
+            let string = AnyOctetString::from_hex(&string.to_hex()).unwrap();
+            assert_eq!(string.bytes(), &[0x0A, 0x1B, 0x2C, 0x3D]);
+        }
+
+        #[test]
+        fn empty() {
+            let string = AnyOctetString::from_hex("").unwrap();
+            assert!(string.is_empty());
+            assert_eq!(string.to_hex(), "");
+        }
+
+        #[test]
+        fn odd_length() {
+            let error = AnyOctetString::from_hex("0A1").unwrap_err();
+            assert!(matches!(error, Error::OddLength { length: 3 }));
+        }
+
+        #[test]
+        fn invalid_character() {
+            let error = AnyOctetString::from_hex("0A1G").unwrap_err();
+            assert!(matches!(
+                error,
+                Error::InvalidHexCharacter {
+                    character: 'G',
+                    index: 3
+                }
+            ));
+        }
+    }
+
     mod decode {
         use crate::decode::DecodeErrorKind;
 
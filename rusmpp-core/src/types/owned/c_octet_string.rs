@@ -259,6 +259,50 @@ impl<const MIN: usize, const MAX: usize> AsRef<[u8]> for COctetString<MIN, MAX>
     }
 }
 
+/// See [`COctetString::as_str`].
+impl<const MIN: usize, const MAX: usize> AsRef<str> for COctetString<MIN, MAX> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Appends the NULL terminator to the [`OctetString`](super::octet_string::OctetString).
+///
+/// Fails if the [`OctetString`](super::octet_string::OctetString) contains a NULL octet or non ASCII octets.
+impl<const MIN: usize, const MAX: usize, const O_MIN: usize, const O_MAX: usize>
+    TryFrom<super::octet_string::OctetString<O_MIN, O_MAX>> for COctetString<MIN, MAX>
+{
+    type Error = Error;
+
+    fn try_from(
+        octet_string: super::octet_string::OctetString<O_MIN, O_MAX>,
+    ) -> Result<Self, Self::Error> {
+        let mut bytes = octet_string.into_bytes();
+
+        bytes.push(0);
+
+        Self::new(bytes)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> From<&crate::types::borrowed::COctetString<'_, MIN, MAX>>
+    for COctetString<MIN, MAX>
+{
+    fn from(c_octet_string: &crate::types::borrowed::COctetString<'_, MIN, MAX>) -> Self {
+        Self {
+            bytes: c_octet_string.bytes().to_vec(),
+        }
+    }
+}
+
+impl<'a, const MIN: usize, const MAX: usize> From<&'a COctetString<MIN, MAX>>
+    for crate::types::borrowed::COctetString<'a, MIN, MAX>
+{
+    fn from(c_octet_string: &'a COctetString<MIN, MAX>) -> Self {
+        Self::new_unchecked(&c_octet_string.bytes)
+    }
+}
+
 impl<const MIN: usize, const MAX: usize> Length for COctetString<MIN, MAX> {
     fn length(&self) -> usize {
         self.bytes.len()
@@ -531,6 +575,8 @@ mod tests {
     }
 
     mod as_str {
+        use core::str::FromStr;
+
         use super::*;
 
         #[test]
@@ -540,6 +586,62 @@ mod tests {
             assert_eq!(string.as_str(), "Hello");
             assert_eq!(string.to_string(), "Hello");
         }
+
+        #[test]
+        fn as_ref() {
+            let message_id = COctetString::<1, 65>::from_str("0123456789ABCDEF").unwrap();
+            let str: &str = message_id.as_ref();
+            assert_eq!(str, "0123456789ABCDEF");
+        }
+
+        #[test]
+        fn display() {
+            let message_id = COctetString::<1, 65>::from_str("0123456789ABCDEF").unwrap();
+            assert_eq!(
+                std::format!("message_id: {message_id}"),
+                "message_id: 0123456789ABCDEF"
+            );
+
+            let empty = COctetString::<1, 65>::empty();
+            assert_eq!(empty.to_string(), "");
+        }
+
+        #[test]
+        fn display_lossy() {
+            let string = COctetString::<1, 6>::new_unchecked(b"H\xFFi\0");
+            assert_eq!(string.to_string(), "H\u{FFFD}i");
+        }
+    }
+
+    mod try_from {
+        use crate::types::owned::OctetString;
+
+        use super::*;
+
+        #[test]
+        fn from_octet_string() {
+            let octet_string = OctetString::<0, 5>::new(b"Hello").unwrap();
+            let c_octet_string = COctetString::<1, 6>::try_from(octet_string).unwrap();
+            assert_eq!(c_octet_string.bytes(), b"Hello\0");
+
+            let octet_string = OctetString::<0, 5>::empty();
+            let c_octet_string = COctetString::<1, 6>::try_from(octet_string).unwrap();
+            assert!(c_octet_string.is_empty());
+        }
+
+        #[test]
+        fn from_octet_string_null_byte_found() {
+            let octet_string = OctetString::<0, 5>::new(b"He\0lo").unwrap();
+            let error = COctetString::<1, 6>::try_from(octet_string).unwrap_err();
+            assert!(matches!(error, Error::NullByteFound));
+        }
+
+        #[test]
+        fn from_octet_string_too_many_bytes() {
+            let octet_string = OctetString::<0, 5>::new(b"Hello").unwrap();
+            let error = COctetString::<1, 5>::try_from(octet_string).unwrap_err();
+            assert!(matches!(error, Error::TooManyBytes { actual: 6, max: 5 }));
+        }
     }
 
     mod decode {
@@ -175,6 +175,39 @@ impl<const MIN: usize, const MAX: usize> From<OctetString<MIN, MAX>>
     }
 }
 
+/// Strips the NULL terminator of the [`COctetString`](super::c_octet_string::COctetString).
+impl<const MIN: usize, const MAX: usize, const C_MIN: usize, const C_MAX: usize>
+    TryFrom<super::c_octet_string::COctetString<C_MIN, C_MAX>> for OctetString<MIN, MAX>
+{
+    type Error = Error;
+
+    fn try_from(
+        c_octet_string: super::c_octet_string::COctetString<C_MIN, C_MAX>,
+    ) -> Result<Self, Self::Error> {
+        let bytes = c_octet_string.bytes();
+
+        Self::new(&bytes[..bytes.len() - 1])
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> From<&crate::types::borrowed::OctetString<'_, MIN, MAX>>
+    for OctetString<MIN, MAX>
+{
+    fn from(octet_string: &crate::types::borrowed::OctetString<'_, MIN, MAX>) -> Self {
+        Self {
+            bytes: octet_string.bytes().to_vec(),
+        }
+    }
+}
+
+impl<'a, const MIN: usize, const MAX: usize> From<&'a OctetString<MIN, MAX>>
+    for crate::types::borrowed::OctetString<'a, MIN, MAX>
+{
+    fn from(octet_string: &'a OctetString<MIN, MAX>) -> Self {
+        Self::new_unchecked(&octet_string.bytes)
+    }
+}
+
 impl<const MIN: usize, const MAX: usize> Length for OctetString<MIN, MAX> {
     fn length(&self) -> usize {
         self.bytes.len()
@@ -301,6 +334,32 @@ mod tests {
         }
     }
 
+    mod try_from {
+        use core::str::FromStr;
+
+        use crate::types::owned::COctetString;
+
+        use super::*;
+
+        #[test]
+        fn from_c_octet_string() {
+            let c_octet_string = COctetString::<1, 6>::from_str("Hello").unwrap();
+            let octet_string = OctetString::<0, 5>::try_from(c_octet_string).unwrap();
+            assert_eq!(octet_string.bytes(), b"Hello");
+
+            let c_octet_string = COctetString::<1, 6>::empty();
+            let octet_string = OctetString::<0, 5>::try_from(c_octet_string).unwrap();
+            assert!(octet_string.is_empty());
+        }
+
+        #[test]
+        fn from_c_octet_string_too_many_bytes() {
+            let c_octet_string = COctetString::<1, 7>::from_str("Hello!").unwrap();
+            let error = OctetString::<0, 5>::try_from(c_octet_string).unwrap_err();
+            assert!(matches!(error, Error::TooManyBytes { actual: 6, max: 5 }));
+        }
+    }
+
     mod decode {
         use crate::decode::DecodeErrorKind;
 
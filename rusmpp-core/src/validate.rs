@@ -0,0 +1,237 @@
+//! Validation of `SMPP` values before sending them.
+//!
+//! Encoding never fails, even if the encoded value would be rejected by the peer. E.g. a `submit_sm` without a destination address.
+//! [`Validate`] catches such errors before the value is sent.
+
+use crate::{CommandId, fields::SmppField};
+
+/// Trait for validating `SMPP` values.
+///
+/// Checks mandatory fields and the consistency of length fields and the values they describe.
+pub trait Validate {
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.as_ref().map(Validate::validate).unwrap_or(Ok(()))
+    }
+}
+
+impl<T: Validate> Validate for [T] {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.iter().try_for_each(Validate::validate)
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T: Validate> Validate for alloc::vec::Vec<T> {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.as_slice().validate()
+    }
+}
+
+/// An error that can occur when validating `SMPP` values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// A mandatory field is empty.
+    EmptyField { field: SmppField },
+    /// A length field does not match the length of the value it describes.
+    LengthMismatch {
+        field: SmppField,
+        expected: usize,
+        actual: usize,
+    },
+    /// Two fields that must not be set together are both set.
+    ///
+    /// E.g. `short_message` and `message_payload`.
+    MutuallyExclusive { field: SmppField, other: SmppField },
+    /// The [`CommandId`] of a command does not match its `PDU`.
+    CommandIdMismatch { id: CommandId, pdu: CommandId },
+    /// An address with an alphanumeric [`Ton`](crate::values::Ton) is longer than 11 characters
+    /// or contains characters other than letters, digits, space, `-`, `_`, `.`, `&` and `+`.
+    ///
+    /// See [`validate_alphanumeric_address`].
+    InvalidAlphanumericAddress { field: SmppField },
+    /// An address contains non-ASCII characters.
+    ///
+    /// See [`validate_ascii_address`].
+    NotAsciiAddress { field: SmppField },
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl ValidationError {
+    /// Returns [`ValidationError::EmptyField`] if `is_empty` is true.
+    #[inline]
+    pub(crate) const fn non_empty(field: SmppField, is_empty: bool) -> Result<(), Self> {
+        if is_empty {
+            return Err(Self::EmptyField { field });
+        }
+
+        Ok(())
+    }
+
+    /// Returns [`ValidationError::LengthMismatch`] if `expected` and `actual` differ.
+    #[inline]
+    pub(crate) const fn length_match(
+        field: SmppField,
+        expected: usize,
+        actual: usize,
+    ) -> Result<(), Self> {
+        if expected != actual {
+            return Err(Self::LengthMismatch {
+                field,
+                expected,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ValidationError::EmptyField { field } => {
+                write!(f, "Mandatory field is empty. field: {field:?}")
+            }
+            ValidationError::LengthMismatch {
+                field,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "Length mismatch. field: {field:?}, expected: {expected}, actual: {actual}"
+                )
+            }
+            ValidationError::MutuallyExclusive { field, other } => {
+                write!(
+                    f,
+                    "Mutually exclusive fields are both set. field: {field:?}, other: {other:?}"
+                )
+            }
+            ValidationError::CommandIdMismatch { id, pdu } => {
+                write!(f, "Command id mismatch. id: {id:?}, pdu: {pdu:?}")
+            }
+            ValidationError::InvalidAlphanumericAddress { field } => {
+                write!(f, "Invalid alphanumeric address. field: {field:?}")
+            }
+            ValidationError::NotAsciiAddress { field } => {
+                write!(f, "Address is not ASCII. field: {field:?}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+/// Maximum number of characters of an alphanumeric address, e.g. a sender ID.
+pub const MAX_ALPHANUMERIC_ADDRESS_LENGTH: usize = 11;
+
+/// Validates `addr` if `ton` is [`Ton::Alphanumeric`](crate::values::Ton::Alphanumeric).
+///
+/// Carriers reject alphanumeric sender IDs longer than [`MAX_ALPHANUMERIC_ADDRESS_LENGTH`] characters
+/// or containing characters other than letters, digits, space, `-`, `_`, `.`, `&` and `+`.
+/// Addresses with other `TON`s are not checked.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{fields::SmppField, validate::validate_alphanumeric_address, values::Ton};
+/// assert!(validate_alphanumeric_address(SmppField::source_addr, Ton::Alphanumeric, "Rusmpp").is_ok());
+/// assert!(validate_alphanumeric_address(SmppField::source_addr, Ton::Alphanumeric, "Rusmpp Sender").is_err());
+/// assert!(validate_alphanumeric_address(SmppField::source_addr, Ton::International, "Rusmpp Sender").is_ok());
+/// ```
+pub fn validate_alphanumeric_address(
+    field: SmppField,
+    ton: crate::values::Ton,
+    addr: &str,
+) -> Result<(), ValidationError> {
+    if ton != crate::values::Ton::Alphanumeric {
+        return Ok(());
+    }
+
+    let valid = addr.len() <= MAX_ALPHANUMERIC_ADDRESS_LENGTH
+        && addr.chars().all(|char| {
+            char.is_ascii_alphanumeric() || matches!(char, ' ' | '-' | '_' | '.' | '&' | '+')
+        });
+
+    if !valid {
+        return Err(ValidationError::InvalidAlphanumericAddress { field });
+    }
+
+    Ok(())
+}
+
+/// Validates that `addr` contains only ASCII characters.
+///
+/// Addresses are digits or, for sender IDs, alphanumeric. Non-ASCII characters point to an encoding bug and are rejected by the MC.
+/// [`COctetString::new`](crate::types::owned::COctetString::new) and decoding reject them,
+/// bypassing these, e.g. deserializing with `serde-deserialize-unchecked`, does not.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{fields::SmppField, validate::validate_ascii_address};
+/// assert!(validate_ascii_address(SmppField::destination_addr, b"491701234567").is_ok());
+/// assert!(validate_ascii_address(SmppField::destination_addr, "49170123456٧".as_bytes()).is_err());
+/// ```
+pub fn validate_ascii_address(field: SmppField, addr: &[u8]) -> Result<(), ValidationError> {
+    if !addr.is_ascii() {
+        return Err(ValidationError::NotAsciiAddress { field });
+    }
+
+    Ok(())
+}
+
+/// Validates the `sm_length`, `short_message` and `message_payload` consistency of message `PDU`s.
+#[cfg(any(test, feature = "alloc"))]
+pub(crate) fn validate_short_message(
+    sm_length: u8,
+    short_message: &[u8],
+    tlvs: &[crate::tlvs::owned::Tlv],
+) -> Result<(), ValidationError> {
+    ValidationError::length_match(
+        SmppField::sm_length,
+        sm_length as usize,
+        short_message.len(),
+    )?;
+
+    let has_message_payload = tlvs
+        .iter()
+        .any(|tlv| tlv.tag() == crate::tlvs::TlvTag::MessagePayload);
+
+    if has_message_payload && !short_message.is_empty() {
+        return Err(ValidationError::MutuallyExclusive {
+            field: SmppField::short_message,
+            other: SmppField::message_payload,
+        });
+    }
+
+    tlvs.validate()
+}
+
+/// Returns the tags of the `TLV`s in `tlvs` that are not in `allowed`, if any.
+///
+/// Unknown ([`TlvTag::Other`](crate::tlvs::TlvTag::Other)) tags are vendor specific and always allowed.
+#[cfg(any(test, feature = "alloc"))]
+pub(crate) fn allowed_tlvs(
+    tlvs: &[crate::tlvs::owned::Tlv],
+    allowed: &[crate::tlvs::TlvTag],
+) -> Result<(), alloc::vec::Vec<crate::tlvs::TlvTag>> {
+    let disallowed: alloc::vec::Vec<_> = tlvs
+        .iter()
+        .map(crate::tlvs::owned::Tlv::tag)
+        .filter(|tag| !matches!(tag, crate::tlvs::TlvTag::Other(_)) && !allowed.contains(tag))
+        .collect();
+
+    if disallowed.is_empty() {
+        return Ok(());
+    }
+
+    Err(disallowed)
+}
@@ -0,0 +1,234 @@
+use crate::{
+    fields::SmppField,
+    types::{COctetStringError, owned::COctetString},
+    validate::ValidationError,
+    values::{Npi, Ton, owned::SmeAddress},
+};
+
+/// An address with its type of number and numbering plan indicator.
+///
+/// Groups the `TON`/`NPI`/address triples of the `PDU`s,
+/// e.g. the `source_addr_ton`, `source_addr_npi` and `source_addr` of a `submit_sm`.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{
+/// #     pdus::owned::SubmitSm,
+/// #     types::owned::COctetString,
+/// #     values::{Npi, Ton, owned::Address},
+/// # };
+/// let address = Address::new(Ton::International, Npi::Isdn, COctetString::new(b"491701234567\0").unwrap());
+///
+/// let mut submit_sm = SubmitSm::default();
+///
+/// submit_sm.set_destination_address(address.clone());
+///
+/// assert_eq!(submit_sm.dest_addr_ton, Ton::International);
+/// assert_eq!(submit_sm.destination_address(), address);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub struct Address {
+    /// Type of Number.
+    pub ton: Ton,
+    /// Numbering Plan Indicator.
+    pub npi: Npi,
+    /// The address.
+    pub addr: COctetString<1, 21>,
+}
+
+impl Address {
+    pub const fn new(ton: Ton, npi: Npi, addr: COctetString<1, 21>) -> Self {
+        Self { ton, npi, addr }
+    }
+
+    pub fn builder() -> AddressBuilder {
+        AddressBuilder::new()
+    }
+}
+
+/// Builder of an [`Address`].
+///
+/// Defaults to an international [`Ton`] and the `ISDN (E.164)` [`Npi`].
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::values::{Npi, Ton, owned::AddressBuilder};
+/// let address = AddressBuilder::e164("+15551234567").build().unwrap();
+///
+/// assert_eq!(address.ton, Ton::International);
+/// assert_eq!(address.npi, Npi::Isdn);
+/// assert_eq!(address.addr.as_str(), "15551234567");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressBuilder {
+    ton: Ton,
+    npi: Npi,
+    addr: alloc::string::String,
+}
+
+impl Default for AddressBuilder {
+    fn default() -> Self {
+        Self {
+            ton: Ton::International,
+            npi: Npi::Isdn,
+            addr: alloc::string::String::new(),
+        }
+    }
+}
+
+impl AddressBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`AddressBuilder`] for an `E.164` number, stripping its leading `+`.
+    pub fn e164(number: &str) -> Self {
+        Self::new().addr(number.strip_prefix('+').unwrap_or(number))
+    }
+
+    pub fn ton(mut self, ton: Ton) -> Self {
+        self.ton = ton;
+        self
+    }
+
+    pub fn npi(mut self, npi: Npi) -> Self {
+        self.npi = npi;
+        self
+    }
+
+    pub fn addr(mut self, addr: &str) -> Self {
+        self.addr = addr.into();
+        self
+    }
+
+    /// Builds the [`Address`].
+    ///
+    /// Fails if the address is not ASCII or longer than 20 characters.
+    pub fn build(self) -> Result<Address, COctetStringError> {
+        Ok(Address::new(self.ton, self.npi, self.addr.parse()?))
+    }
+}
+
+impl Address {
+    /// Validates that the address is ASCII, see [`validate_ascii_address`](crate::validate::validate_ascii_address),
+    /// and alphanumeric addresses as sender IDs, see [`validate_alphanumeric_address`](crate::validate::validate_alphanumeric_address).
+    ///
+    /// Errors report the `field` the address is set to, e.g. [`SmppField::source_addr`].
+    pub fn validate(&self, field: SmppField) -> Result<(), ValidationError> {
+        crate::validate::validate_ascii_address(field, self.addr.bytes())?;
+
+        crate::validate::validate_alphanumeric_address(field, self.ton, self.addr.as_str())
+    }
+}
+
+impl From<SmeAddress> for Address {
+    fn from(address: SmeAddress) -> Self {
+        Self::new(
+            address.dest_addr_ton,
+            address.dest_addr_npi,
+            address.destination_addr,
+        )
+    }
+}
+
+impl From<Address> for SmeAddress {
+    fn from(address: Address) -> Self {
+        SmeAddress::new(address.ton, address.npi, address.addr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alphanumeric() {
+        let sender =
+            |addr: &str| Address::new(Ton::Alphanumeric, Npi::Unknown, addr.parse().unwrap());
+
+        assert!(
+            sender("Rusmpp-1234")
+                .validate(SmppField::source_addr)
+                .is_ok()
+        );
+        assert!(sender("A&B Shop").validate(SmppField::source_addr).is_ok());
+
+        for addr in ["Rusmpp-12345", "Rusmpp!", "Rusmpp#"] {
+            assert_eq!(
+                sender(addr).validate(SmppField::source_addr),
+                Err(ValidationError::InvalidAlphanumericAddress {
+                    field: SmppField::source_addr
+                }),
+                "{addr}"
+            );
+        }
+
+        // The error reports the given field
+        assert_eq!(
+            sender("Rusmpp-12345").validate(SmppField::esme_addr),
+            Err(ValidationError::InvalidAlphanumericAddress {
+                field: SmppField::esme_addr
+            })
+        );
+
+        // Only alphanumeric addresses are checked
+        let address = Address::new(
+            Ton::International,
+            Npi::Isdn,
+            "123456789012345".parse().unwrap(),
+        );
+
+        assert!(address.validate(SmppField::destination_addr).is_ok());
+    }
+
+    #[test]
+    fn e164() {
+        let address = AddressBuilder::e164("+15551234567").build().unwrap();
+
+        assert_eq!(address.ton, Ton::International);
+        assert_eq!(address.npi, Npi::Isdn);
+        assert_eq!(address.addr.as_str(), "15551234567");
+
+        // Without a leading `+`
+        assert_eq!(
+            AddressBuilder::e164("15551234567").build().unwrap(),
+            address
+        );
+
+        let address = Address::builder()
+            .ton(Ton::National)
+            .npi(Npi::National)
+            .addr("05551234567")
+            .build()
+            .unwrap();
+
+        assert_eq!(address.ton, Ton::National);
+        assert_eq!(address.npi, Npi::National);
+        assert_eq!(address.addr.as_str(), "05551234567");
+
+        assert!(matches!(
+            AddressBuilder::e164("+155512345678901234567").build(),
+            Err(COctetStringError::TooManyBytes { .. })
+        ));
+    }
+
+    #[test]
+    fn sme_address() {
+        let address = Address::new(
+            Ton::International,
+            Npi::Isdn,
+            COctetString::new(b"491701234567\0").unwrap(),
+        );
+
+        let sme_address = SmeAddress::from(address.clone());
+
+        assert_eq!(sme_address.dest_addr_ton, Ton::International);
+        assert_eq!(sme_address.destination_addr.as_str(), "491701234567");
+        assert_eq!(Address::from(sme_address), address);
+    }
+}
@@ -22,6 +22,18 @@ impl BroadcastAreaIdentifier {
     }
 }
 
+impl From<&super::borrowed::BroadcastAreaIdentifier<'_>> for BroadcastAreaIdentifier {
+    fn from(value: &super::borrowed::BroadcastAreaIdentifier<'_>) -> Self {
+        Self::new(value.format, (&value.area).into())
+    }
+}
+
+impl<'a> From<&'a BroadcastAreaIdentifier> for super::borrowed::BroadcastAreaIdentifier<'a> {
+    fn from(value: &'a BroadcastAreaIdentifier) -> Self {
+        Self::new(value.format, (&value.area).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,31 @@
+use rusmpp_macros::Rusmpp;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod owned;
+
+/// Encoding of the digits of a `callback_num`.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub enum DigitModeIndicator {
+    /// Telephony Binary Coded Decimal.
+    #[default]
+    Tbcd = 0,
+    /// `ASCII` digits.
+    Ascii = 1,
+    Other(u8),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode() {
+        crate::tests::owned::encode_decode_test_instances::<DigitModeIndicator>();
+        crate::tests::borrowed::encode_decode_test_instances::<DigitModeIndicator>();
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    types::owned::OctetString,
+    values::{Npi, Ton, callback_num::DigitModeIndicator},
+};
+
+/// The structured value of a `callback_num` `TLV`.
+///
+/// The `TLV` value consists of the digit mode indicator, the `TON`, the `NPI` and the number digits.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::{
+/// #     types::owned::OctetString,
+/// #     values::{DigitModeIndicator, Npi, Ton, owned::CallbackNum},
+/// # };
+/// let callback_num = CallbackNum::new(
+///     DigitModeIndicator::Ascii,
+///     Ton::International,
+///     Npi::Isdn,
+///     OctetString::new(b"491701234567").unwrap(),
+/// );
+///
+/// let value = OctetString::<4, 19>::from(&callback_num);
+///
+/// assert_eq!(&value.bytes()[..3], &[0x01, 0x01, 0x01]);
+/// assert_eq!(CallbackNum::from(&value), callback_num);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub struct CallbackNum {
+    pub digit_mode_indicator: DigitModeIndicator,
+    /// Type of Number.
+    pub ton: Ton,
+    /// Numbering Plan Indicator.
+    pub npi: Npi,
+    /// The number digits.
+    pub digits: OctetString<1, 16>,
+}
+
+impl CallbackNum {
+    pub const fn new(
+        digit_mode_indicator: DigitModeIndicator,
+        ton: Ton,
+        npi: Npi,
+        digits: OctetString<1, 16>,
+    ) -> Self {
+        Self {
+            digit_mode_indicator,
+            ton,
+            npi,
+            digits,
+        }
+    }
+}
+
+impl From<&OctetString<4, 19>> for CallbackNum {
+    fn from(value: &OctetString<4, 19>) -> Self {
+        let bytes = value.bytes();
+
+        // The value is at least 4 bytes long
+        Self {
+            digit_mode_indicator: DigitModeIndicator::from(bytes[0]),
+            ton: Ton::from(bytes[1]),
+            npi: Npi::from(bytes[2]),
+            digits: OctetString::new(&bytes[3..]).unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&CallbackNum> for OctetString<4, 19> {
+    fn from(value: &CallbackNum) -> Self {
+        let mut bytes = alloc::vec::Vec::with_capacity(3 + value.digits.bytes().len());
+
+        bytes.extend_from_slice(&[
+            value.digit_mode_indicator.into(),
+            value.ton.into(),
+            value.npi.into(),
+        ]);
+        bytes.extend_from_slice(value.digits.bytes());
+
+        // 1 to 16 digits following the 3 header bytes always fit
+        OctetString::new(bytes).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_octet_string() {
+        let value = OctetString::<4, 19>::new([0x01, 0x02, 0x09, b'1', b'2', b'3']).unwrap();
+
+        let callback_num = CallbackNum::from(&value);
+
+        assert_eq!(callback_num.digit_mode_indicator, DigitModeIndicator::Ascii);
+        assert_eq!(callback_num.ton, Ton::National);
+        assert_eq!(callback_num.npi, Npi::Private);
+        assert_eq!(callback_num.digits.bytes(), b"123");
+
+        assert_eq!(OctetString::from(&callback_num), value);
+    }
+}
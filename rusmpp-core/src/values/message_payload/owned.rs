@@ -30,6 +30,18 @@ impl From<MessagePayload> for AnyOctetString {
     }
 }
 
+impl From<&super::borrowed::MessagePayload<'_>> for MessagePayload {
+    fn from(value: &super::borrowed::MessagePayload<'_>) -> Self {
+        Self::new((&value.value).into())
+    }
+}
+
+impl<'a> From<&'a MessagePayload> for super::borrowed::MessagePayload<'a> {
+    fn from(value: &'a MessagePayload) -> Self {
+        Self::new((&value.value).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
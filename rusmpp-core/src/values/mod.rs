@@ -18,6 +18,8 @@ pub mod parts {
 mod addr_subunit;
 pub use addr_subunit::AddrSubunit;
 
+mod address;
+
 mod alert_on_msg_delivery;
 pub use alert_on_msg_delivery::AlertOnMessageDelivery;
 
@@ -42,6 +44,9 @@ pub use broadcast_frequency_interval::{BroadcastFrequencyInterval, UnitOfTime};
 mod broadcast_message_class;
 pub use broadcast_message_class::BroadcastMessageClass;
 
+mod callback_num;
+pub use callback_num::DigitModeIndicator;
+
 mod callback_num_pres_ind;
 pub use callback_num_pres_ind::{CallbackNumPresInd, Presentation, Screening};
 
@@ -131,6 +136,9 @@ pub use service_type::GenericServiceType;
 mod set_dpf;
 pub use set_dpf::SetDpf;
 
+mod smpp_time;
+pub use smpp_time::{AbsoluteTime, RelativeTime, SmppTime, SmppTimeError};
+
 mod sub_address;
 pub use sub_address::SubaddressTag;
 
@@ -186,7 +194,9 @@ pub mod owned {
         pub use super::super::unsuccess_sme::owned::UnsuccessSmeParts;
     }
 
+    pub use super::address::owned::{Address, AddressBuilder};
     pub use super::broadcast_area_identifier::owned::BroadcastAreaIdentifier;
+    pub use super::callback_num::owned::CallbackNum;
     pub use super::dest_address::owned::{DestAddress, DistributionListName, SmeAddress};
     pub use super::message_payload::owned::MessagePayload;
     pub use super::service_type::owned::ServiceType;
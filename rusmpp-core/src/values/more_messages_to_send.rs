@@ -12,6 +12,25 @@ pub enum MoreMessagesToSend {
     Other(u8),
 }
 
+impl MoreMessagesToSend {
+    /// Returns `true` if more messages are to follow.
+    ///
+    /// Reserved values are treated as no more messages to follow.
+    pub const fn is_more_messages_to_follow(self) -> bool {
+        matches!(self, MoreMessagesToSend::MoreMessagesToFollow)
+    }
+}
+
+impl From<bool> for MoreMessagesToSend {
+    fn from(value: bool) -> Self {
+        if value {
+            MoreMessagesToSend::MoreMessagesToFollow
+        } else {
+            MoreMessagesToSend::NoMoreMessagesToFollow
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -21,4 +40,16 @@ mod tests {
         crate::tests::owned::encode_decode_test_instances::<MoreMessagesToSend>();
         crate::tests::borrowed::encode_decode_test_instances::<MoreMessagesToSend>();
     }
+
+    #[test]
+    fn from_bool() {
+        for value in [true, false] {
+            assert_eq!(
+                MoreMessagesToSend::from(value).is_more_messages_to_follow(),
+                value
+            );
+        }
+
+        assert!(!MoreMessagesToSend::Other(2).is_more_messages_to_follow());
+    }
 }
@@ -14,6 +14,41 @@ impl PriorityFlag {
     pub fn new(value: u8) -> Self {
         Self { value }
     }
+
+    /// Returns the `GSM` `SMS` priority level, [`None`] if the value is out of its range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rusmpp_core::values::{GsmCbs, GsmSms, PriorityFlag};
+    /// let priority_flag = PriorityFlag::new(4);
+    ///
+    /// assert_eq!(priority_flag.as_gsm_sms(), None);
+    /// assert_eq!(priority_flag.as_gsm_cbs(), Some(GsmCbs::PriorityBackground));
+    /// ```
+    pub fn as_gsm_sms(self) -> Option<GsmSms> {
+        (self.value <= 3).then(|| GsmSms::from(self.value))
+    }
+
+    /// Returns the `GSM` `CBS` priority level, [`None`] if the value is out of its range.
+    pub fn as_gsm_cbs(self) -> Option<GsmCbs> {
+        (self.value <= 4).then(|| GsmCbs::from(self.value))
+    }
+
+    /// Returns the `ANSI-136` priority level, [`None`] if the value is out of its range.
+    pub fn as_ansi136(self) -> Option<Ansi136> {
+        (self.value <= 3).then(|| Ansi136::from(self.value))
+    }
+
+    /// Returns the `IS-95` priority level, [`None`] if the value is out of its range.
+    pub fn as_is95(self) -> Option<Is95> {
+        (self.value <= 3).then(|| Is95::from(self.value))
+    }
+
+    /// Returns the `ANSI-41` `CBS` priority level, [`None`] if the value is out of its range.
+    pub fn as_ansi41_cbs(self) -> Option<Ansi41Cbs> {
+        (self.value <= 3).then(|| Ansi41Cbs::from(self.value))
+    }
 }
 
 impl From<PriorityFlagType> for PriorityFlag {
@@ -339,6 +374,78 @@ mod tests {
         assert_eq!(gsm_sms, GsmSms::None);
     }
 
+    #[test]
+    fn levels() {
+        let gsm_sms = [
+            GsmSms::None,
+            GsmSms::Priority1,
+            GsmSms::Priority2,
+            GsmSms::Priority3,
+        ];
+
+        for (value, level) in (0..).zip(gsm_sms) {
+            assert_eq!(PriorityFlag::from(level), PriorityFlag::new(value));
+            assert_eq!(PriorityFlag::new(value).as_gsm_sms(), Some(level));
+        }
+
+        let gsm_cbs = [
+            GsmCbs::Normal,
+            GsmCbs::ImmediateBroadcast,
+            GsmCbs::HighPriority,
+            GsmCbs::Reserved,
+            GsmCbs::PriorityBackground,
+        ];
+
+        for (value, level) in (0..).zip(gsm_cbs) {
+            assert_eq!(PriorityFlag::from(level), PriorityFlag::new(value));
+            assert_eq!(PriorityFlag::new(value).as_gsm_cbs(), Some(level));
+        }
+
+        let ansi136 = [
+            Ansi136::Bulk,
+            Ansi136::Normal,
+            Ansi136::Urgent,
+            Ansi136::VeryUrgent,
+        ];
+
+        for (value, level) in (0..).zip(ansi136) {
+            assert_eq!(PriorityFlag::from(level), PriorityFlag::new(value));
+            assert_eq!(PriorityFlag::new(value).as_ansi136(), Some(level));
+        }
+
+        let is95 = [
+            Is95::Normal,
+            Is95::Interactive,
+            Is95::Urgent,
+            Is95::Emergency,
+        ];
+
+        for (value, level) in (0..).zip(is95) {
+            assert_eq!(PriorityFlag::from(level), PriorityFlag::new(value));
+            assert_eq!(PriorityFlag::new(value).as_is95(), Some(level));
+        }
+
+        let ansi41_cbs = [
+            Ansi41Cbs::Normal,
+            Ansi41Cbs::Interactive,
+            Ansi41Cbs::Urgent,
+            Ansi41Cbs::Emergency,
+        ];
+
+        for (value, level) in (0..).zip(ansi41_cbs) {
+            assert_eq!(PriorityFlag::from(level), PriorityFlag::new(value));
+            assert_eq!(PriorityFlag::new(value).as_ansi41_cbs(), Some(level));
+        }
+
+        let out_of_range = PriorityFlag::new(5);
+
+        assert_eq!(out_of_range.as_gsm_sms(), None);
+        assert_eq!(out_of_range.as_gsm_cbs(), None);
+        assert_eq!(out_of_range.as_ansi136(), None);
+        assert_eq!(out_of_range.as_is95(), None);
+        assert_eq!(out_of_range.as_ansi41_cbs(), None);
+    }
+
     #[test]
     fn encode_decode() {
         crate::tests::owned::encode_decode_test_instances::<PriorityFlag>();
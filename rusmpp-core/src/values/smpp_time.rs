@@ -0,0 +1,332 @@
+/// Time format of the `schedule_delivery_time` and `validity_period` fields.
+///
+/// Encoded as a 16-character string `YYMMDDhhmmsstnnp`:
+///
+/// - Absolute time: `YY` year (`00`-`99`), `MM` month (`01`-`12`), `DD` day (`01`-`31`), `hh` hour (`00`-`23`),
+///   `mm` minute (`00`-`59`), `ss` second (`00`-`59`), `t` tenths of a second (`0`-`9`),
+///   `nn` difference to UTC in quarter hours (`00`-`48`) and `p` `+` or `-` for the direction of the difference.
+/// - Relative time: `YY` years, `MM` months, `DD` days, `hh` hours, `mm` minutes and `ss` seconds from now,
+///   followed by `000R`.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::values::{RelativeTime, SmppTime};
+/// let time: SmppTime = "000002120000000R".parse().unwrap();
+///
+/// assert_eq!(
+///     time,
+///     SmppTime::Relative(RelativeTime {
+///         days: 2,
+///         hours: 12,
+///         ..Default::default()
+///     })
+/// );
+///
+/// assert_eq!(time.to_bytes().unwrap(), *b"000002120000000R");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub enum SmppTime {
+    Absolute(AbsoluteTime),
+    Relative(RelativeTime),
+}
+
+/// Absolute [`SmppTime`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub struct AbsoluteTime {
+    /// Year of the century, `0`-`99`.
+    pub year: u8,
+    /// `1`-`12`.
+    pub month: u8,
+    /// `1`-`31`.
+    pub day: u8,
+    /// `0`-`23`.
+    pub hour: u8,
+    /// `0`-`59`.
+    pub minute: u8,
+    /// `0`-`59`.
+    pub second: u8,
+    /// Tenths of a second, `0`-`9`.
+    pub tenths: u8,
+    /// Difference to UTC in quarter hours, `-48`-`48`.
+    pub utc_offset: i8,
+}
+
+/// Relative [`SmppTime`], each field `0`-`99`.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub struct RelativeTime {
+    pub years: u8,
+    pub months: u8,
+    pub days: u8,
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: u8,
+}
+
+impl SmppTime {
+    /// The length of an encoded [`SmppTime`] without the null terminator.
+    pub const LENGTH: usize = 16;
+
+    /// Parses an [`SmppTime`] from its 16 characters, without the null terminator.
+    pub fn parse(bytes: &[u8]) -> Result<Self, SmppTimeError> {
+        let bytes: &[u8; Self::LENGTH] =
+            bytes.try_into().map_err(|_| SmppTimeError::InvalidLength {
+                actual: bytes.len(),
+            })?;
+
+        let pair = |index: usize| -> Result<u8, SmppTimeError> {
+            let digit = |byte: u8| match byte {
+                b'0'..=b'9' => Ok(byte - b'0'),
+                _ => Err(SmppTimeError::NotDigit),
+            };
+
+            Ok(digit(bytes[index])? * 10 + digit(bytes[index + 1])?)
+        };
+
+        let [yy, mm, dd, hh, mi, ss] = [0, 2, 4, 6, 8, 10].map(pair);
+        let [yy, mm, dd, hh, mi, ss] = [yy?, mm?, dd?, hh?, mi?, ss?];
+
+        let tenths = match bytes[12] {
+            byte @ b'0'..=b'9' => byte - b'0',
+            _ => return Err(SmppTimeError::NotDigit),
+        };
+
+        let offset = pair(13)?;
+
+        let time = match bytes[15] {
+            b'R' if tenths == 0 && offset == 0 => SmppTime::Relative(RelativeTime {
+                years: yy,
+                months: mm,
+                days: dd,
+                hours: hh,
+                minutes: mi,
+                seconds: ss,
+            }),
+            sign @ (b'+' | b'-') => SmppTime::Absolute(AbsoluteTime {
+                year: yy,
+                month: mm,
+                day: dd,
+                hour: hh,
+                minute: mi,
+                second: ss,
+                tenths,
+                utc_offset: match sign {
+                    b'+' => offset as i8,
+                    _ => -(offset as i8),
+                },
+            }),
+            _ => return Err(SmppTimeError::InvalidSuffix),
+        };
+
+        time.validate()?;
+
+        Ok(time)
+    }
+
+    /// Encodes the [`SmppTime`] into its 16 characters, without the null terminator.
+    ///
+    /// Fails if a field is out of its range.
+    pub fn to_bytes(&self) -> Result<[u8; Self::LENGTH], SmppTimeError> {
+        self.validate()?;
+
+        let (pairs, tenths, offset, suffix) = match self {
+            SmppTime::Absolute(time) => (
+                [
+                    time.year,
+                    time.month,
+                    time.day,
+                    time.hour,
+                    time.minute,
+                    time.second,
+                ],
+                time.tenths,
+                time.utc_offset.unsigned_abs(),
+                if time.utc_offset < 0 { b'-' } else { b'+' },
+            ),
+            SmppTime::Relative(time) => (
+                [
+                    time.years,
+                    time.months,
+                    time.days,
+                    time.hours,
+                    time.minutes,
+                    time.seconds,
+                ],
+                0,
+                0,
+                b'R',
+            ),
+        };
+
+        let mut bytes = [0; Self::LENGTH];
+
+        for (index, pair) in pairs.into_iter().chain([offset]).enumerate() {
+            // The offset follows the tenths
+            let index = if index < 6 { index * 2 } else { 13 };
+
+            bytes[index] = b'0' + pair / 10;
+            bytes[index + 1] = b'0' + pair % 10;
+        }
+
+        bytes[12] = b'0' + tenths;
+        bytes[15] = suffix;
+
+        Ok(bytes)
+    }
+
+    /// Checks that all fields are within their range.
+    pub fn validate(&self) -> Result<(), SmppTimeError> {
+        let valid = match self {
+            SmppTime::Absolute(time) => {
+                time.year <= 99
+                    && (1..=12).contains(&time.month)
+                    && (1..=31).contains(&time.day)
+                    && time.hour <= 23
+                    && time.minute <= 59
+                    && time.second <= 59
+                    && time.tenths <= 9
+                    && (-48..=48).contains(&time.utc_offset)
+            }
+            SmppTime::Relative(time) => [
+                time.years,
+                time.months,
+                time.days,
+                time.hours,
+                time.minutes,
+                time.seconds,
+            ]
+            .iter()
+            .all(|value| *value <= 99),
+        };
+
+        if !valid {
+            return Err(SmppTimeError::OutOfRange);
+        }
+
+        Ok(())
+    }
+}
+
+impl core::str::FromStr for SmppTime {
+    type Err = SmppTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.as_bytes())
+    }
+}
+
+/// An error that can occur when parsing or encoding an [`SmppTime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmppTimeError {
+    /// The time is not exactly 16 characters long.
+    InvalidLength { actual: usize },
+    /// A character that must be a digit is not.
+    NotDigit,
+    /// The last character is neither `+`, `-` nor `R`, or a relative time does not end with `000R`.
+    InvalidSuffix,
+    /// A field is out of its range.
+    OutOfRange,
+}
+
+impl core::fmt::Display for SmppTimeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SmppTimeError::InvalidLength { actual } => {
+                write!(f, "Invalid length. actual: {actual}, expected: 16")
+            }
+            SmppTimeError::NotDigit => write!(f, "Not a digit"),
+            SmppTimeError::InvalidSuffix => write!(f, "Invalid suffix"),
+            SmppTimeError::OutOfRange => write!(f, "Out of range"),
+        }
+    }
+}
+
+impl core::error::Error for SmppTimeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn absolute() {
+        let time = SmppTime::parse(b"231001120000004+").unwrap();
+
+        assert_eq!(
+            time,
+            SmppTime::Absolute(AbsoluteTime {
+                year: 23,
+                month: 10,
+                day: 1,
+                hour: 12,
+                minute: 0,
+                second: 0,
+                tenths: 0,
+                utc_offset: 4,
+            })
+        );
+        assert_eq!(&time.to_bytes().unwrap(), b"231001120000004+");
+
+        let time = SmppTime::parse(b"991231235959948-").unwrap();
+
+        let SmppTime::Absolute(absolute) = time else {
+            panic!("Expected an absolute time, got {time:?}");
+        };
+
+        assert_eq!(absolute.tenths, 9);
+        assert_eq!(absolute.utc_offset, -48);
+        assert_eq!(&time.to_bytes().unwrap(), b"991231235959948-");
+    }
+
+    #[test]
+    fn relative() {
+        let time = SmppTime::Relative(RelativeTime {
+            hours: 1,
+            minutes: 30,
+            ..Default::default()
+        });
+
+        assert_eq!(&time.to_bytes().unwrap(), b"000000013000000R");
+        assert_eq!(SmppTime::parse(b"000000013000000R"), Ok(time));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            SmppTime::parse(b"2310011200"),
+            Err(SmppTimeError::InvalidLength { actual: 10 })
+        );
+        assert_eq!(
+            SmppTime::parse(b"2023-10-01T12:00"),
+            Err(SmppTimeError::NotDigit)
+        );
+        assert_eq!(
+            SmppTime::parse(b"231001120000004X"),
+            Err(SmppTimeError::InvalidSuffix)
+        );
+        assert_eq!(
+            SmppTime::parse(b"000000013000100R"),
+            Err(SmppTimeError::InvalidSuffix)
+        );
+        assert_eq!(
+            SmppTime::parse(b"231301120000004+"),
+            Err(SmppTimeError::OutOfRange)
+        );
+        assert_eq!(
+            SmppTime::parse(b"231001120000049+"),
+            Err(SmppTimeError::OutOfRange)
+        );
+
+        let time = SmppTime::Relative(RelativeTime {
+            days: 100,
+            ..Default::default()
+        });
+
+        assert_eq!(time.to_bytes(), Err(SmppTimeError::OutOfRange));
+    }
+}
@@ -21,6 +21,18 @@ impl Subaddress {
     }
 }
 
+impl From<&super::borrowed::Subaddress<'_>> for Subaddress {
+    fn from(value: &super::borrowed::Subaddress<'_>) -> Self {
+        Self::new(value.tag, (&value.addr).into())
+    }
+}
+
+impl<'a> From<&'a Subaddress> for super::borrowed::Subaddress<'a> {
+    fn from(value: &'a Subaddress) -> Self {
+        Self::new(value.tag, (&value.addr).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,61 @@
+//! Counts the allocations of [`CowTlvValue`] with a global allocator, which requires `unsafe` outside of the crate.
+
+#![cfg(feature = "alloc")]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use rusmpp_core::{
+    decode::borrowed::DecodeWithKey,
+    encode::{Encode, Length},
+    tlvs::{TlvTag, owned::CowTlvValue},
+};
+
+thread_local! {
+    /// Allocations of the current thread, other test threads do not interfere.
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+#[test]
+fn read_does_not_allocate_mutate_does() {
+    let bytes = [0xAB; 1024];
+    let mut buf = [0; 1024];
+
+    let before = allocations();
+
+    let (mut value, _) = CowTlvValue::decode(TlvTag::Other(0x1400), &bytes, bytes.len()).unwrap();
+
+    assert_eq!(value.other_value(), Some(&bytes[..]));
+    assert_eq!(value.encode(&mut buf), value.length());
+    assert_eq!(buf, bytes);
+
+    assert_eq!(allocations(), before);
+
+    value.other_value_mut().unwrap()[0] = 0x00;
+
+    assert!(allocations() > before);
+    assert!(!value.is_borrowed());
+}
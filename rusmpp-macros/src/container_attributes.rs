@@ -1,3 +1,5 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
 use syn::Ident;
 
 /// `#[rusmpp(decode = skip|owned|borrowed|all)]`
@@ -35,26 +37,113 @@ impl Default for DecodeAttributes {
     }
 }
 
-/// `#[rusmpp(test = skip)]`
+/// `#[rusmpp(test = skip|roundtrip|roundtrip(should_panic = "message"))]`
 #[derive(Default)]
 pub enum TestAttributes {
     Skip,
     #[default]
     Implement,
+    /// Generate an encode/decode round-trip property test using the `arbitrary` implementation.
+    RoundTrip {
+        /// The test is expected to panic with this message, e.g. for types that are broken on purpose.
+        should_panic: Option<syn::LitStr>,
+    },
 }
 
 impl TestAttributes {
     pub fn extract(meta: syn::meta::ParseNestedMeta<'_>) -> syn::Result<Self> {
-        let ident: Ident = meta.value()?.parse()?;
+        let input = meta.value()?;
+        let ident: Ident = input.parse()?;
 
         match ident.to_string().as_str() {
             "skip" => Ok(Self::Skip),
+            "roundtrip" => Ok(Self::RoundTrip {
+                should_panic: Self::extract_should_panic(input)?,
+            }),
             other => Err(meta.error(format!(
-                "unknown decode attribute: {}, expected skip",
+                "unknown test attribute: {}, expected skip or roundtrip",
                 other
             ))),
         }
     }
+
+    /// Optional `(should_panic = "message")` after `roundtrip`.
+    fn extract_should_panic(
+        input: syn::parse::ParseStream<'_>,
+    ) -> syn::Result<Option<syn::LitStr>> {
+        if !input.peek(syn::token::Paren) {
+            return Ok(None);
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+
+        let ident: Ident = content.parse()?;
+
+        if ident != "should_panic" {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "unknown roundtrip attribute, expected should_panic",
+            ));
+        }
+
+        content.parse::<syn::Token![=]>()?;
+
+        Ok(Some(content.parse()?))
+    }
+
+    /// Round-trip property test for `name`, in a module of its own.
+    pub fn quote_round_trip(
+        input: &syn::DeriveInput,
+        should_panic: Option<&syn::LitStr>,
+    ) -> syn::Result<TokenStream> {
+        let name = &input.ident;
+
+        if !input.generics.params.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &input.generics,
+                "test = roundtrip does not support generic types",
+            ));
+        }
+
+        let module = format_ident!("{}_round_trip", snake_case(&name.to_string()));
+
+        let should_panic = should_panic.map(|message| {
+            quote! {
+                #[should_panic(expected = #message)]
+            }
+        });
+
+        Ok(quote! {
+            #[cfg(all(test, feature = "arbitrary"))]
+            mod #module {
+                #[test]
+                #should_panic
+                fn round_trip() {
+                    crate::tests::owned::encode_decode_arbitrary::<super::#name>();
+                }
+            }
+        })
+    }
+}
+
+/// `SubmitSm` -> `submit_sm`
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+
+    for (index, char) in name.chars().enumerate() {
+        if char.is_ascii_uppercase() {
+            if index != 0 {
+                snake.push('_');
+            }
+
+            snake.push(char.to_ascii_lowercase());
+        } else {
+            snake.push(char);
+        }
+    }
+
+    snake
 }
 
 /// `#[rusmpp(from_into = skip)]`
@@ -82,3 +171,48 @@ impl FromIntoAttributes {
         matches!(self, Self::Implement)
     }
 }
+
+/// `#[rusmpp(crate = "path")]`
+///
+/// Path to `rusmpp-core` used by the generated code. Default is `crate`.
+#[derive(Default)]
+pub struct CrateAttributes {
+    path: Option<syn::Path>,
+}
+
+impl CrateAttributes {
+    pub fn extract(meta: syn::meta::ParseNestedMeta<'_>) -> syn::Result<Self> {
+        let lit: syn::LitStr = meta.value()?.parse()?;
+
+        Ok(Self {
+            path: Some(lit.parse()?),
+        })
+    }
+
+    /// Returns true if the code is generated for a crate other than `rusmpp-core`.
+    pub const fn is_external(&self) -> bool {
+        self.path.is_some()
+    }
+
+    pub fn path(&self) -> TokenStream {
+        match &self.path {
+            Some(path) => quote! { #path },
+            None => quote! { crate },
+        }
+    }
+
+    /// `SmppField` of the field `name`.
+    ///
+    /// Fields of external structs are not known to `SmppField` and use `SmppField::Other`.
+    pub fn field(&self, name: &Ident) -> TokenStream {
+        let krate = self.path();
+
+        if self.is_external() {
+            let name = name.to_string();
+
+            return quote! { #krate::fields::SmppField::Other(#name) };
+        }
+
+        quote! { #krate::fields::SmppField::#name }
+    }
+}
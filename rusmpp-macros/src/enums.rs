@@ -3,7 +3,7 @@ use quote::quote;
 use syn::{DataEnum, DeriveInput, Fields, Ident, parse};
 
 use crate::{
-    container_attributes::{DecodeAttributes, FromIntoAttributes, TestAttributes},
+    container_attributes::{CrateAttributes, DecodeAttributes, FromIntoAttributes, TestAttributes},
     repr::{Repr, ReprType},
 };
 
@@ -15,6 +15,7 @@ pub fn derive_rusmpp_for_enum(input: &DeriveInput) -> syn::Result<TokenStream> {
         enum_attrs.from_into_attrs,
         &enum_attrs.decode_attrs,
         &enum_attrs.test_attrs,
+        &enum_attrs.crate_attrs,
     ))
 }
 
@@ -24,6 +25,7 @@ struct EnumAttributes {
     from_into_attrs: FromIntoAttributes,
     decode_attrs: DecodeAttributes,
     test_attrs: TestAttributes,
+    crate_attrs: CrateAttributes,
 }
 
 impl EnumAttributes {
@@ -32,6 +34,7 @@ impl EnumAttributes {
         let mut from_into_attrs = FromIntoAttributes::default();
         let mut decode_attrs = DecodeAttributes::default();
         let mut test_attrs = TestAttributes::default();
+        let mut crate_attrs = CrateAttributes::default();
 
         for attr in &input.attrs {
             if attr.path().is_ident("repr") {
@@ -63,6 +66,8 @@ impl EnumAttributes {
                         test_attrs = TestAttributes::extract(meta)?;
                     } else if meta.path.is_ident("from_into") {
                         from_into_attrs = FromIntoAttributes::extract(meta)?;
+                    } else if meta.path.is_ident("crate") {
+                        crate_attrs = CrateAttributes::extract(meta)?;
                     }
 
                     Ok(())
@@ -82,6 +87,7 @@ impl EnumAttributes {
             from_into_attrs,
             decode_attrs,
             test_attrs,
+            crate_attrs,
         })
     }
 }
@@ -95,6 +101,7 @@ pub fn derive_tlv_value_for_enum(
 
     // Collect match arms
     let mut tag_arms = Vec::new();
+    let mut tags = Vec::new();
     let mut value_arms = Vec::new();
     let mut has_other_variant = false;
 
@@ -107,6 +114,8 @@ pub fn derive_tlv_value_for_enum(
                     #ident::#v_ident(_) => TlvTag::#v_ident,
                 });
 
+                tags.push(quote! { TlvTag::#v_ident });
+
                 value_arms.push(quote! {
                     #ident::#v_ident(value) => TlvValue::#v_ident(value),
                 });
@@ -167,6 +176,9 @@ pub fn derive_tlv_value_for_enum(
 
     Ok(quote! {
         impl #impl_generics #ident #ty_generics #where_clause {
+            /// Tags of the known `TLV`s of this group.
+            pub const TAGS: &'static [TlvTag] = &[#(#tags),*];
+
             pub const fn tag(&self) -> TlvTag {
                 match self {
                     #(#tag_arms)*
@@ -12,7 +12,7 @@ mod parts;
 mod repr;
 mod structs;
 
-/// Implements `Length`, `Encode`, `Decode` and `TestInstance` with one default value for structs and enums.
+/// Implements `Length`, `Encode`, `EncodeFields`, `Decode` and `TestInstance` with one default value for structs and enums.
 /// And creates parts structs for structs with `new` and `raw` methods and adds `into_parts` method to the original struct.
 ///
 /// # Enums
@@ -22,7 +22,9 @@ mod structs;
 /// - `#[repr(u8)]`, `#[repr(u16)]`, or `#[repr(u32)]`: Use the `From<u8>`, `From<u16>`, or `From<u32>`/`Into<u8>`, `Into<u16>`, or `Into<u32>` representation for decoding.
 /// - `#[rusmpp(decode = skip|owned|borrowed|all)]`: Control which `Decode` implementations to generate. Default is `all`.
 /// - `#[rusmpp(test = skip)]`: Skip impl `TestInstance` for the enum.
+/// - `#[rusmpp(test = roundtrip)]`: Instead of `TestInstance`, generate an encode/decode round-trip property test from the `arbitrary` implementation of the enum.
 /// - `#[rusmpp(from_into = skip)]`: Skip implementing `From<repr>` and `From<Enum>` for the enum.
+/// - `#[rusmpp(crate = "path")]`: Path to `rusmpp-core` used by the generated code. Setting a path skips the `TestInstance` impl and the `test = roundtrip` test, which are private to `rusmpp-core`. See [Custom types](#custom-types).
 ///
 /// # Structs
 ///
@@ -31,17 +33,39 @@ mod structs;
 /// - `#[rusmpp(repr = "u8")]`: Use the `From<u8>`/`Into<u8>` representation for decoding.
 /// - `#[rusmpp(decode = skip|owned|borrowed|all)]`: Control which `Decode` implementations to generate. Default is `all`.
 /// - `#[rusmpp(test = skip)]`: Skip impl `TestInstance` for the struct.
+/// - `#[rusmpp(test = roundtrip)]`: Instead of `TestInstance`, generate an encode/decode round-trip property test from the `arbitrary` implementation of the struct. The test runs with the `arbitrary` feature.
+/// - `#[rusmpp(test = roundtrip(should_panic = "message"))]`: Same as `test = roundtrip`, but the test is expected to panic with `message`, e.g. for types that are broken on purpose.
+/// - `#[rusmpp(crate = "path")]`: Path to `rusmpp-core` used by the generated code. Setting a path skips the `TestInstance` impl and the `test = roundtrip` test, which are private to `rusmpp-core`. See [Custom types](#custom-types).
 ///
 /// ## Field attributes
 ///
+/// - `#[rusmpp(skip)]`: Skip encoding and decoding the field, e.g. metadata that is not part of the wire format. Decoding requires a `new` constructor like `skip_decode`.
 /// - `#[rusmpp(skip_decode)]`: Skip decoding the field (requires a corresponding `new` constructor that does not take the skipped field as an argument).
 /// - `#[rusmpp(length = "unchecked")]`: Decode without length checks.
 /// - `#[rusmpp(length = "checked")]`: Decode using `length_checked_decode`.
 /// - `#[rusmpp(length = ident)]`: Use the value of another field (`ident`) as the length for decoding.
+/// - `#[rusmpp(length = expr)]`: Use an expression of other fields as the length for decoding, e.g. `length = u16::saturating_sub(value_length, 2)`. The expression is evaluated as written: plain arithmetic like `value_length - 2` overflows on malformed input, use saturating operations instead.
 /// - `#[rusmpp(key = ident, length = "unchecked")]`: Decode using a key and unchecked length.
-/// - `#[rusmpp(key = ident, length = ident)]`: Decode using a key and the value of another field (`ident`) as the length.
+/// - `#[rusmpp(key = ident, length = ident)]`: Decode using a key and the value of another field (`ident`) or an expression as the length.
+/// - `#[rusmpp(key = (ident, ident), ...)]`: Decode using a composite key of several fields. The `DecodeWithKey::Key` of the field is a tuple, e.g. `(u16, u8)`.
 /// - `#[rusmpp(count = ident)]`: Decode a vector of values, where `ident` is the number of elements to decode.
 ///
+/// ## `TLV` fields
+///
+/// Structs with fields holding `TLV`s, i.e. `Vec<Tlv>` or `Option<Tlv>`, also implement `DecodeWithContext` for owned decoding,
+/// passing the `DecodeContext` to these fields. Their `DecodeWithLength` decodes with the default context.
+/// `TLV` fields must use `length = "unchecked"` or `length = "checked"`.
+///
+/// # Custom types
+///
+/// Outside of `rusmpp-core`, e.g. for vendor-specific `PDU`s, set `#[rusmpp(crate = "::rusmpp_core")]`.
+///
+/// - `TestInstance` is not implemented and `test = roundtrip` generates no test, whatever the `test` attribute says.
+/// - Decoding errors refer to the fields as `SmppField::Other("field_name")`.
+///
+/// Custom `PDU`s are sent and received as `Pdu::Other`, see `PduBody` in `rusmpp-core`.
+/// There is no dedicated `Vendor` variant, see `Pdu::vendor` for why.
+///
 /// # Examples
 ///
 /// See `tests/expand`.
@@ -3,7 +3,7 @@ use quote::quote;
 use syn::{DeriveInput, Ident};
 
 use crate::container_attributes::{
-    DecodeAttributes, DecodeImplementation, FromIntoAttributes, TestAttributes,
+    CrateAttributes, DecodeAttributes, DecodeImplementation, FromIntoAttributes, TestAttributes,
 };
 
 pub struct Repr {
@@ -35,63 +35,78 @@ impl ReprType {
 }
 
 impl Repr {
-    fn quote_length_impl(&self, name: &Ident) -> TokenStream {
+    fn quote_length_impl(&self, name: &Ident, crate_attrs: &CrateAttributes) -> TokenStream {
+        let krate = crate_attrs.path();
         let repr_ident = &self.ident;
 
         quote! {
-            impl crate::encode::Length for #name {
+            impl #krate::encode::Length for #name {
                 fn length(&self) -> usize {
-                    #repr_ident::from(*self).length()
+                    #krate::encode::Length::length(&#repr_ident::from(*self))
                 }
             }
         }
     }
 
-    fn quote_encode_impl(&self, name: &Ident) -> TokenStream {
+    fn quote_encode_impl(&self, name: &Ident, crate_attrs: &CrateAttributes) -> TokenStream {
+        let krate = crate_attrs.path();
         let repr_ident = &self.ident;
 
         quote! {
-            impl crate::encode::Encode for #name {
+            impl #krate::encode::Encode for #name {
                 fn encode(&self, dst: &mut [u8]) -> usize {
-                    #repr_ident::from(*self).encode(dst)
+                    #krate::encode::Encode::encode(&#repr_ident::from(*self), dst)
                 }
             }
         }
     }
 
-    fn quote_owned_decode_impl(&self, name: &Ident) -> TokenStream {
+    fn quote_owned_decode_impl(&self, name: &Ident, crate_attrs: &CrateAttributes) -> TokenStream {
+        let krate = crate_attrs.path();
         let repr_ident = &self.ident;
 
         quote! {
-            impl crate::decode::owned::Decode for #name {
-                fn decode(src: &[u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
-                    #repr_ident::decode(src).map(|(this, size)| (Self::from(this), size))
+            impl #krate::decode::owned::Decode for #name {
+                fn decode(src: &[u8]) -> Result<(Self, usize), #krate::decode::DecodeError> {
+                    <#repr_ident as #krate::decode::owned::Decode>::decode(src).map(|(this, size)| (Self::from(this), size))
                 }
             }
         }
     }
 
-    fn quote_borrowed_decode_impl(&self, name: &Ident) -> TokenStream {
+    fn quote_borrowed_decode_impl(
+        &self,
+        name: &Ident,
+        crate_attrs: &CrateAttributes,
+    ) -> TokenStream {
+        let krate = crate_attrs.path();
         let repr_ident = &self.ident;
 
         quote! {
-            impl<'a> crate::decode::borrowed::Decode<'a> for #name {
-                fn decode(src: &'a [u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
-                    #repr_ident::decode(src).map(|(this, size)| (Self::from(this), size))
+            impl<'a> #krate::decode::borrowed::Decode<'a> for #name {
+                fn decode(src: &'a [u8]) -> Result<(Self, usize), #krate::decode::DecodeError> {
+                    <#repr_ident as #krate::decode::borrowed::Decode<'a>>::decode(src).map(|(this, size)| (Self::from(this), size))
                 }
             }
         }
     }
 
-    fn quote_decode_impl(&self, name: &Ident, decode_attrs: &DecodeAttributes) -> TokenStream {
+    fn quote_decode_impl(
+        &self,
+        name: &Ident,
+        decode_attrs: &DecodeAttributes,
+        crate_attrs: &CrateAttributes,
+    ) -> TokenStream {
         match decode_attrs {
             DecodeAttributes::Skip => quote! {},
             DecodeAttributes::Implement(impl_type) => match impl_type {
-                DecodeImplementation::Owned => self.quote_owned_decode_impl(name),
-                DecodeImplementation::Borrowed => self.quote_borrowed_decode_impl(name),
+                DecodeImplementation::Owned => self.quote_owned_decode_impl(name, crate_attrs),
+                DecodeImplementation::Borrowed => {
+                    self.quote_borrowed_decode_impl(name, crate_attrs)
+                }
                 DecodeImplementation::All => {
-                    let owned = self.quote_owned_decode_impl(name);
-                    let borrowed = self.quote_borrowed_decode_impl(name);
+                    let owned = self.quote_owned_decode_impl(name, crate_attrs);
+                    let borrowed = self.quote_borrowed_decode_impl(name, crate_attrs);
 
                     quote! {
                         #owned
@@ -102,10 +117,26 @@ impl Repr {
         }
     }
 
-    fn quote_test_impl(&self, name: &Ident, test_attrs: &TestAttributes) -> TokenStream {
+    fn quote_test_impl(
+        &self,
+        input: &DeriveInput,
+        test_attrs: &TestAttributes,
+        crate_attrs: &CrateAttributes,
+    ) -> TokenStream {
+        // `TestInstance` is private to `rusmpp-core`
+        if crate_attrs.is_external() {
+            return quote! {};
+        }
+
         match test_attrs {
             TestAttributes::Skip => quote! {},
+            TestAttributes::RoundTrip { should_panic } => {
+                TestAttributes::quote_round_trip(input, should_panic.as_ref())
+                    .unwrap_or_else(syn::Error::into_compile_error)
+            }
             TestAttributes::Implement => {
+                let name = &input.ident;
+
                 quote! {
                     #[cfg(test)]
                     impl crate::tests::TestInstance for #name {
@@ -178,14 +209,15 @@ impl Repr {
         from_into_attrs: FromIntoAttributes,
         decode_attrs: &DecodeAttributes,
         test_attrs: &TestAttributes,
+        crate_attrs: &CrateAttributes,
     ) -> TokenStream {
         let _ = from_into_attrs;
         let name = &input.ident;
 
-        let length_impl = self.quote_length_impl(name);
-        let encode_impl = self.quote_encode_impl(name);
-        let decode_impl = self.quote_decode_impl(name, decode_attrs);
-        let test_impl = self.quote_test_impl(name, test_attrs);
+        let length_impl = self.quote_length_impl(name, crate_attrs);
+        let encode_impl = self.quote_encode_impl(name, crate_attrs);
+        let decode_impl = self.quote_decode_impl(name, decode_attrs, crate_attrs);
+        let test_impl = self.quote_test_impl(input, test_attrs, crate_attrs);
 
         let from_into_impl = if from_into_attrs.is_implement() {
             self.quote_from_into_impl(input)
@@ -4,7 +4,7 @@ use syn::{DeriveInput, Field, FieldsNamed, Ident, Lit};
 
 use crate::{
     container_attributes::{
-        DecodeAttributes, DecodeImplementation, FromIntoAttributes, TestAttributes,
+        CrateAttributes, DecodeAttributes, DecodeImplementation, FromIntoAttributes, TestAttributes,
     },
     parts,
     repr::{Repr, ReprType},
@@ -24,6 +24,7 @@ pub fn derive_rusmpp_for_struct(
             FromIntoAttributes::Skip,
             &struct_attrs.decode_attrs,
             &struct_attrs.test_attrs,
+            &struct_attrs.crate_attrs,
         );
 
         let expanded = quote! {
@@ -34,15 +35,19 @@ pub fn derive_rusmpp_for_struct(
         return Ok(expanded);
     }
 
-    let length = quote_length(input, fields_named);
-    let encode = quote_encode(input, fields_named);
-    let decode = quote_decode(input, fields_named, &struct_attrs.decode_attrs)?;
-    let test = quote_test(input, &struct_attrs.test_attrs);
+    let crate_attrs = &struct_attrs.crate_attrs;
+
+    let length = quote_length(input, fields_named, crate_attrs);
+    let encode = quote_encode(input, fields_named, crate_attrs);
+    let encode_fields = quote_encode_fields(input, fields_named, crate_attrs);
+    let decode = quote_decode(input, fields_named, &struct_attrs.decode_attrs, crate_attrs)?;
+    let test = quote_test(input, &struct_attrs.test_attrs, crate_attrs)?;
 
     let expanded = quote! {
         #parts
         #length
         #encode
+        #encode_fields
         #decode
         #test
     };
@@ -50,21 +55,27 @@ pub fn derive_rusmpp_for_struct(
     Ok(expanded)
 }
 
-fn quote_length(input: &DeriveInput, fields_named: &FieldsNamed) -> TokenStream {
+fn quote_length(
+    input: &DeriveInput,
+    fields_named: &FieldsNamed,
+    crate_attrs: &CrateAttributes,
+) -> TokenStream {
+    let krate = crate_attrs.path();
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
 
     let field_idents = fields_named
         .named
         .iter()
+        .filter(|f| !is_skipped_field(f))
         .map(|f| f.ident.as_ref().expect("Named fields must have idents"));
 
     quote! {
-        impl #impl_generics crate::encode::Length for #name #ty_generics #where_clause {
+        impl #impl_generics #krate::encode::Length for #name #ty_generics #where_clause {
             fn length(&self) -> usize {
                 let mut length = 0;
                 #(
-                    length += crate::encode::Length::length(&self.#field_idents);
+                    length += #krate::encode::Length::length(&self.#field_idents);
                 )*
                 length
             }
@@ -72,21 +83,81 @@ fn quote_length(input: &DeriveInput, fields_named: &FieldsNamed) -> TokenStream
     }
 }
 
-fn quote_encode(input: &DeriveInput, fields_named: &FieldsNamed) -> TokenStream {
+fn quote_encode(
+    input: &DeriveInput,
+    fields_named: &FieldsNamed,
+    crate_attrs: &CrateAttributes,
+) -> TokenStream {
+    let krate = crate_attrs.path();
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
 
     let field_idents = fields_named
         .named
         .iter()
+        .filter(|f| !is_skipped_field(f))
         .map(|f| f.ident.as_ref().expect("Named fields must have idents"));
 
     quote! {
-        impl #impl_generics crate::encode::Encode for #name #ty_generics #where_clause {
+        impl #impl_generics #krate::encode::Encode for #name #ty_generics #where_clause {
             fn encode(&self, dst: &mut [u8]) -> usize {
                 let size = 0;
                 #(
-                    let size = crate::encode::EncodeExt::encode_move(&self.#field_idents, dst, size);
+                    let size = #krate::encode::EncodeExt::encode_move(&self.#field_idents, dst, size);
+                )*
+                size
+            }
+        }
+    }
+}
+
+fn quote_encode_fields(
+    input: &DeriveInput,
+    fields_named: &FieldsNamed,
+    crate_attrs: &CrateAttributes,
+) -> TokenStream {
+    let krate = crate_attrs.path();
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
+
+    let field_idents = fields_named
+        .named
+        .iter()
+        .filter(|f| !is_skipped_field(f))
+        .map(|f| f.ident.as_ref().expect("Named fields must have idents"))
+        .collect::<Vec<_>>();
+
+    let field_names = field_idents.iter().map(|ident| ident.to_string());
+
+    // TLVs are optional
+    let mandatory_field_idents = fields_named
+        .named
+        .iter()
+        .filter(|f| !is_skipped_field(f) && !is_tlv_field(f))
+        .map(|f| f.ident.as_ref().expect("Named fields must have idents"))
+        .collect::<Vec<_>>();
+
+    let mandatory_field_names = mandatory_field_idents.iter().map(|ident| ident.to_string());
+
+    quote! {
+        impl #impl_generics #krate::encode::EncodeFields for #name #ty_generics #where_clause {
+            fn field(&self, name: &str) -> Option<&dyn #krate::encode::Encode> {
+                match name {
+                    #(
+                        #field_names => Some(&self.#field_idents),
+                    )*
+                    _ => None,
+                }
+            }
+
+            fn mandatory_fields(&self) -> &'static [&'static str] {
+                &[#(#mandatory_field_names),*]
+            }
+
+            fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+                let size = 0;
+                #(
+                    let size = #krate::encode::EncodeExt::encode_move(&self.#mandatory_field_idents, dst, size);
                 )*
                 size
             }
@@ -94,10 +165,45 @@ fn quote_encode(input: &DeriveInput, fields_named: &FieldsNamed) -> TokenStream
     }
 }
 
+/// Returns true if the field is not encoded, see `#[rusmpp(skip)]`.
+///
+/// Invalid attributes are reported by the decode implementation.
+fn is_skipped_field(field: &Field) -> bool {
+    FieldAttributes::extract(field).is_ok_and(|attrs| attrs.skip)
+}
+
+/// Returns true if the field holds `TLV`s, e.g. `Option<Tlv>`, `Vec<Tlv>` or `heapless::Vec<Tlv<'a>, N>`.
+fn is_tlv_field(field: &Field) -> bool {
+    let last_segment = |ty: &syn::Type| match ty {
+        syn::Type::Path(path) => path.path.segments.last().cloned(),
+        _ => None,
+    };
+
+    let Some(segment) = last_segment(&field.ty) else {
+        return false;
+    };
+
+    if segment.ident != "Option" && segment.ident != "Vec" {
+        return false;
+    }
+
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return false;
+    };
+
+    args.args.iter().any(|arg| match arg {
+        syn::GenericArgument::Type(ty) => {
+            last_segment(ty).is_some_and(|segment| segment.ident == "Tlv")
+        }
+        _ => false,
+    })
+}
+
 fn quote_decode(
     input: &DeriveInput,
     fields_named: &FieldsNamed,
     decode_attrs: &DecodeAttributes,
+    crate_attrs: &CrateAttributes,
 ) -> syn::Result<TokenStream> {
     match decode_attrs {
         DecodeAttributes::Skip => Ok(quote! {}),
@@ -116,47 +222,55 @@ fn quote_decode(
 
             let decode_type = fields.decode_type();
 
-            match impl_type {
-                DecodeImplementation::Owned => match decode_type {
-                    DecodeType::Decode => Ok(quote_owned_decode(input, &fields)),
+            let owned_decode = || -> syn::Result<TokenStream> {
+                if fields.has_tlvs() {
+                    return quote_owned_decode_with_context(input, &fields, crate_attrs);
+                }
+
+                match decode_type {
+                    DecodeType::Decode => Ok(quote_owned_decode(input, &fields, crate_attrs)),
                     DecodeType::DecodeWithLength => {
-                        Ok(quote_owned_decode_with_length(input, &fields))
+                        Ok(quote_owned_decode_with_length(input, &fields, crate_attrs))
                     }
-                },
+                }
+            };
+
+            match impl_type {
+                DecodeImplementation::Owned => owned_decode(),
                 DecodeImplementation::Borrowed => match decode_type {
-                    DecodeType::Decode => Ok(quote_borrowed_decode(input, &fields)),
-                    DecodeType::DecodeWithLength => {
-                        Ok(quote_borrowed_decode_with_length(input, &fields))
-                    }
-                },
-                DecodeImplementation::All => match decode_type {
-                    DecodeType::Decode => {
-                        let quote_borrowed_decode = quote_borrowed_decode(input, &fields);
-                        let quote_owned_decode = quote_owned_decode(input, &fields);
-
-                        Ok(quote! {
-                            #quote_borrowed_decode
-                            #quote_owned_decode
-                        })
-                    }
-                    DecodeType::DecodeWithLength => {
-                        let quote_borrowed_decode =
-                            quote_borrowed_decode_with_length(input, &fields);
-                        let quote_owned_decode = quote_owned_decode_with_length(input, &fields);
-
-                        Ok(quote! {
-                            #quote_borrowed_decode
-                            #quote_owned_decode
-                        })
-                    }
+                    DecodeType::Decode => Ok(quote_borrowed_decode(input, &fields, crate_attrs)),
+                    DecodeType::DecodeWithLength => Ok(quote_borrowed_decode_with_length(
+                        input,
+                        &fields,
+                        crate_attrs,
+                    )),
                 },
+                DecodeImplementation::All => {
+                    let quote_borrowed_decode = match decode_type {
+                        DecodeType::Decode => quote_borrowed_decode(input, &fields, crate_attrs),
+                        DecodeType::DecodeWithLength => {
+                            quote_borrowed_decode_with_length(input, &fields, crate_attrs)
+                        }
+                    };
+                    let quote_owned_decode = owned_decode()?;
+
+                    Ok(quote! {
+                        #quote_borrowed_decode
+                        #quote_owned_decode
+                    })
+                }
             }
         }
     }
 }
 
 // XXX: Duplicated code with quote_owned_decode
-fn quote_borrowed_decode(input: &DeriveInput, fields: &ValidFields) -> TokenStream {
+fn quote_borrowed_decode(
+    input: &DeriveInput,
+    fields: &ValidFields,
+    crate_attrs: &CrateAttributes,
+) -> TokenStream {
+    let krate = crate_attrs.path();
     let name = &input.ident;
 
     let (_, ty_generics, where_clause) = &input.generics.split_for_impl();
@@ -176,15 +290,22 @@ fn quote_borrowed_decode(input: &DeriveInput, fields: &ValidFields) -> TokenStre
         input.generics.split_for_impl().0.to_token_stream()
     };
 
-    let skipped_field_exists = fields.fields.iter().any(|f| f.attrs.skip());
-    let fields_names = fields.fields.iter().filter(|f| !f.attrs.skip()).map(|f| {
-        f.field
-            .ident
-            .as_ref()
-            .expect("Named fields must have idents")
-    });
-
-    let fields = fields.fields.iter().map(|f| f.quote_borrowed_decode());
+    let skipped_field_exists = fields.fields.iter().any(|f| f.attrs.skip_decode());
+    let fields_names = fields
+        .fields
+        .iter()
+        .filter(|f| !f.attrs.skip_decode())
+        .map(|f| {
+            f.field
+                .ident
+                .as_ref()
+                .expect("Named fields must have idents")
+        });
+
+    let fields = fields
+        .fields
+        .iter()
+        .map(|f| f.quote_borrowed_decode(crate_attrs));
 
     let constructor = if skipped_field_exists {
         quote! {
@@ -201,8 +322,8 @@ fn quote_borrowed_decode(input: &DeriveInput, fields: &ValidFields) -> TokenStre
     };
 
     quote! {
-        impl #impl_generics crate::decode::borrowed::Decode<'a> for #name #ty_generics #where_clause {
-            fn decode(src: &'a [u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
+        impl #impl_generics #krate::decode::borrowed::Decode<'a> for #name #ty_generics #where_clause {
+            fn decode(src: &'a [u8]) -> Result<(Self, usize), #krate::decode::DecodeError> {
                 let size = 0;
                 #(
                     #fields
@@ -214,19 +335,31 @@ fn quote_borrowed_decode(input: &DeriveInput, fields: &ValidFields) -> TokenStre
     }
 }
 
-fn quote_owned_decode(input: &DeriveInput, fields: &ValidFields) -> TokenStream {
+fn quote_owned_decode(
+    input: &DeriveInput,
+    fields: &ValidFields,
+    crate_attrs: &CrateAttributes,
+) -> TokenStream {
+    let krate = crate_attrs.path();
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
 
-    let skipped_field_exists = fields.fields.iter().any(|f| f.attrs.skip());
-    let fields_names = fields.fields.iter().filter(|f| !f.attrs.skip()).map(|f| {
-        f.field
-            .ident
-            .as_ref()
-            .expect("Named fields must have idents")
-    });
-
-    let fields = fields.fields.iter().map(|f| f.quote_owned_decode());
+    let skipped_field_exists = fields.fields.iter().any(|f| f.attrs.skip_decode());
+    let fields_names = fields
+        .fields
+        .iter()
+        .filter(|f| !f.attrs.skip_decode())
+        .map(|f| {
+            f.field
+                .ident
+                .as_ref()
+                .expect("Named fields must have idents")
+        });
+
+    let fields = fields
+        .fields
+        .iter()
+        .map(|f| f.quote_owned_decode(crate_attrs));
 
     let constructor = if skipped_field_exists {
         quote! {
@@ -243,8 +376,8 @@ fn quote_owned_decode(input: &DeriveInput, fields: &ValidFields) -> TokenStream
     };
 
     quote! {
-        impl #impl_generics crate::decode::owned::Decode for #name #ty_generics #where_clause {
-            fn decode(src: &[u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
+        impl #impl_generics #krate::decode::owned::Decode for #name #ty_generics #where_clause {
+            fn decode(src: &[u8]) -> Result<(Self, usize), #krate::decode::DecodeError> {
                 let size = 0;
                 #(
                     #fields
@@ -257,7 +390,12 @@ fn quote_owned_decode(input: &DeriveInput, fields: &ValidFields) -> TokenStream
 }
 
 // XXX: Skipped fields are not used here
-fn quote_borrowed_decode_with_length(input: &DeriveInput, fields: &ValidFields) -> TokenStream {
+fn quote_borrowed_decode_with_length(
+    input: &DeriveInput,
+    fields: &ValidFields,
+    crate_attrs: &CrateAttributes,
+) -> TokenStream {
+    let krate = crate_attrs.path();
     let name = &input.ident;
 
     let (_, ty_generics, where_clause) = &input.generics.split_for_impl();
@@ -277,18 +415,25 @@ fn quote_borrowed_decode_with_length(input: &DeriveInput, fields: &ValidFields)
         input.generics.split_for_impl().0.to_token_stream()
     };
 
-    let fields_names = fields.fields.iter().filter(|f| !f.attrs.skip()).map(|f| {
-        f.field
-            .ident
-            .as_ref()
-            .expect("Named fields must have idents")
-    });
-
-    let fields = fields.fields.iter().map(|f| f.quote_borrowed_decode());
+    let fields_names = fields
+        .fields
+        .iter()
+        .filter(|f| !f.attrs.skip_decode())
+        .map(|f| {
+            f.field
+                .ident
+                .as_ref()
+                .expect("Named fields must have idents")
+        });
+
+    let fields = fields
+        .fields
+        .iter()
+        .map(|f| f.quote_borrowed_decode(crate_attrs));
 
     quote! {
-        impl #impl_generics crate::decode::borrowed::DecodeWithLength<'a> for #name #ty_generics #where_clause {
-            fn decode(src: &'a [u8], length: usize) -> Result<(Self, usize), crate::decode::DecodeError> {
+        impl #impl_generics #krate::decode::borrowed::DecodeWithLength<'a> for #name #ty_generics #where_clause {
+            fn decode(src: &'a [u8], length: usize) -> Result<(Self, usize), #krate::decode::DecodeError> {
                 let size = 0;
                 #(
                     #fields
@@ -303,22 +448,34 @@ fn quote_borrowed_decode_with_length(input: &DeriveInput, fields: &ValidFields)
 }
 
 // XXX: Skipped fields are not used here
-fn quote_owned_decode_with_length(input: &DeriveInput, fields: &ValidFields) -> TokenStream {
+fn quote_owned_decode_with_length(
+    input: &DeriveInput,
+    fields: &ValidFields,
+    crate_attrs: &CrateAttributes,
+) -> TokenStream {
+    let krate = crate_attrs.path();
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
 
-    let fields_names = fields.fields.iter().filter(|f| !f.attrs.skip()).map(|f| {
-        f.field
-            .ident
-            .as_ref()
-            .expect("Named fields must have idents")
-    });
-
-    let fields = fields.fields.iter().map(|f| f.quote_owned_decode());
+    let fields_names = fields
+        .fields
+        .iter()
+        .filter(|f| !f.attrs.skip_decode())
+        .map(|f| {
+            f.field
+                .ident
+                .as_ref()
+                .expect("Named fields must have idents")
+        });
+
+    let fields = fields
+        .fields
+        .iter()
+        .map(|f| f.quote_owned_decode(crate_attrs));
 
     quote! {
-        impl #impl_generics crate::decode::owned::DecodeWithLength for #name #ty_generics #where_clause {
-            fn decode(src: &[u8], length: usize) -> Result<(Self, usize), crate::decode::DecodeError> {
+        impl #impl_generics #krate::decode::owned::DecodeWithLength for #name #ty_generics #where_clause {
+            fn decode(src: &[u8], length: usize) -> Result<(Self, usize), #krate::decode::DecodeError> {
                 let size = 0;
                 #(
                     #fields
@@ -332,11 +489,69 @@ fn quote_owned_decode_with_length(input: &DeriveInput, fields: &ValidFields) ->
     }
 }
 
+/// `DecodeWithContext` for structs holding `TLV`s, `DecodeWithLength` decodes with the default context.
+fn quote_owned_decode_with_context(
+    input: &DeriveInput,
+    fields: &ValidFields,
+    crate_attrs: &CrateAttributes,
+) -> syn::Result<TokenStream> {
+    let krate = crate_attrs.path();
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
+
+    let fields_names = fields
+        .fields
+        .iter()
+        .filter(|f| !f.attrs.skip_decode())
+        .map(|f| {
+            f.field
+                .ident
+                .as_ref()
+                .expect("Named fields must have idents")
+        });
+
+    let fields = fields
+        .fields
+        .iter()
+        .map(|f| f.quote_owned_decode_with_context(crate_attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics #krate::decode::owned::DecodeWithLength for #name #ty_generics #where_clause {
+            fn decode(src: &[u8], length: usize) -> Result<(Self, usize), #krate::decode::DecodeError> {
+                #krate::decode::owned::DecodeWithContext::decode_with_context(
+                    src,
+                    length,
+                    &mut #krate::decode::DecodeContext::default(),
+                )
+            }
+        }
+
+        impl #impl_generics #krate::decode::owned::DecodeWithContext for #name #ty_generics #where_clause {
+            fn decode_with_context(
+                src: &[u8],
+                length: usize,
+                context: &mut #krate::decode::DecodeContext,
+            ) -> Result<(Self, usize), #krate::decode::DecodeError> {
+                let size = 0;
+                #(
+                    #fields
+                )*
+
+                Ok((Self {
+                    #(#fields_names),*
+                 }, size))
+            }
+        }
+    })
+}
+
 struct StructAttributes {
     /// `#[rusmpp(repr = "u8")]`
     repr: Option<Repr>,
     decode_attrs: DecodeAttributes,
     test_attrs: TestAttributes,
+    crate_attrs: CrateAttributes,
 }
 
 impl StructAttributes {
@@ -344,6 +559,7 @@ impl StructAttributes {
         let mut repr: Option<Repr> = None;
         let mut decode_attrs = DecodeAttributes::default();
         let mut test_attrs = TestAttributes::default();
+        let mut crate_attrs = CrateAttributes::default();
 
         for attr in &input.attrs {
             if !attr.path().is_ident("rusmpp") {
@@ -374,6 +590,8 @@ impl StructAttributes {
                     decode_attrs = DecodeAttributes::extract(meta)?;
                 } else if meta.path.is_ident("test") {
                     test_attrs = TestAttributes::extract(meta)?;
+                } else if meta.path.is_ident("crate") {
+                    crate_attrs = CrateAttributes::extract(meta)?;
                 }
 
                 Ok(())
@@ -384,19 +602,22 @@ impl StructAttributes {
             repr,
             decode_attrs,
             test_attrs,
+            crate_attrs,
         })
     }
 }
 
 struct FieldAttributes {
+    skip: bool,
     skip_decode: bool,
     length: Option<Length>,
-    key: Option<Ident>,
+    key: Option<syn::Expr>,
     count: Option<Ident>,
 }
 
 impl FieldAttributes {
     fn extract(field: &Field) -> syn::Result<Self> {
+        let mut skip = false;
         let mut skip_decode = false;
         let mut length = None;
         let mut key = None;
@@ -408,7 +629,9 @@ impl FieldAttributes {
             }
 
             attr.parse_nested_meta(|meta| {
-                if meta.path.is_ident("skip_decode") {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                } else if meta.path.is_ident("skip_decode") {
                     skip_decode = true;
                 } else if meta.path.is_ident("length") {
                     let value = meta.value()?;
@@ -420,7 +643,7 @@ impl FieldAttributes {
                                 "checked" => length = Some(Length::Checked),
                                 _ => {
                                     return Err(meta.error(
-                                    "length must be \"unchecked\", \"checked\", or an identifier",
+                                    "length must be \"unchecked\", \"checked\", or an expression",
                                 ));
                                 }
                             }
@@ -428,12 +651,36 @@ impl FieldAttributes {
                     }
 
                     if length.is_none() {
-                        let ident: Ident = value.parse()?;
-                        length = Some(Length::Ident(ident));
+                        let expr: syn::Expr = value.parse()?;
+
+                        // Keep the precedence of `length_expr as usize` for expressions like `value_length - 2`
+                        let expr = match expr {
+                            syn::Expr::Path(_) | syn::Expr::Paren(_) | syn::Expr::Lit(_) => expr,
+                            expr => syn::parse_quote! { (#expr) },
+                        };
+
+                        length = Some(Length::Expr(expr));
                     }
                 } else if meta.path.is_ident("key") {
-                    let ident: Ident = meta.value()?.parse()?;
-                    key = Some(ident);
+                    let expr: syn::Expr = meta.value()?.parse()?;
+
+                    let is_ident = |expr: &syn::Expr| {
+                        matches!(expr, syn::Expr::Path(path) if path.path.get_ident().is_some())
+                    };
+
+                    // `key = tag` or a composite key `key = (tag, subtype)`
+                    let valid = match &expr {
+                        syn::Expr::Tuple(tuple) => tuple.elems.iter().all(is_ident),
+                        expr => is_ident(expr),
+                    };
+
+                    if !valid {
+                        return Err(meta.error(
+                            "key must be an identifier or a tuple of identifiers",
+                        ));
+                    }
+
+                    key = Some(expr);
                 } else if meta.path.is_ident("count") {
                     let ident: Ident = meta.value()?.parse()?;
                     count = Some(ident);
@@ -446,6 +693,7 @@ impl FieldAttributes {
         }
 
         Ok(Self {
+            skip,
             skip_decode,
             length,
             key,
@@ -455,12 +703,24 @@ impl FieldAttributes {
 
     fn validated(self) -> syn::Result<ValidFieldAttributes> {
         let Self {
+            skip,
             skip_decode,
             length,
             key,
             count,
         } = self;
 
+        if skip {
+            if skip_decode || length.is_some() || key.is_some() || count.is_some() {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    "skip cannot be combined with skip_decode, length, key, or count",
+                ));
+            }
+
+            return Ok(ValidFieldAttributes::Skip);
+        }
+
         if skip_decode {
             if length.is_some() || key.is_some() || count.is_some() {
                 return Err(syn::Error::new(
@@ -475,16 +735,16 @@ impl FieldAttributes {
         match (length, key, count) {
             (Some(Length::Unchecked), None, None) => Ok(ValidFieldAttributes::LengthUnchecked),
             (Some(Length::Checked), None, None) => Ok(ValidFieldAttributes::LengthChecked),
-            (Some(Length::Ident(length)), None, None) => Ok(ValidFieldAttributes::LengthIdent {
-                length_ident: length,
+            (Some(Length::Expr(length)), None, None) => Ok(ValidFieldAttributes::LengthExpr {
+                length_expr: length,
             }),
             (Some(Length::Unchecked), Some(key), None) => {
-                Ok(ValidFieldAttributes::KeyLengthUnchecked { key_ident: key })
+                Ok(ValidFieldAttributes::KeyLengthUnchecked { key_expr: key })
             }
-            (Some(Length::Ident(length)), Some(key), None) => {
-                Ok(ValidFieldAttributes::KeyLengthIdent {
-                    key_ident: key,
-                    length_ident: length,
+            (Some(Length::Expr(length)), Some(key), None) => {
+                Ok(ValidFieldAttributes::KeyLengthExpr {
+                    key_expr: key,
+                    length_expr: length,
                 })
             }
             (None, None, Some(count)) => Ok(ValidFieldAttributes::Count { count_ident: count }),
@@ -500,29 +760,32 @@ impl FieldAttributes {
 enum Length {
     Unchecked,
     Checked,
-    Ident(Ident),
+    /// A sibling field or an expression of sibling fields, e.g. `value_length - 2`.
+    Expr(syn::Expr),
 }
 
 enum ValidFieldAttributes {
     None,
+    /// `#[rusmpp(skip)]`
+    Skip,
     /// `#[rusmpp(skip_decode)]`
     SkipDecode,
     /// `#[rusmpp(length = "unchecked")]`
     LengthUnchecked,
     /// `#[rusmpp(length = "checked")]`
     LengthChecked,
-    /// `#[rusmpp(length = ident)]`
-    LengthIdent {
-        length_ident: Ident,
+    /// `#[rusmpp(length = expr)]`
+    LengthExpr {
+        length_expr: syn::Expr,
     },
-    /// `#[rusmpp(key = ident, length = "unchecked")]`
+    /// `#[rusmpp(key = ident|(ident, ..), length = "unchecked")]`
     KeyLengthUnchecked {
-        key_ident: Ident,
+        key_expr: syn::Expr,
     },
-    /// `#[rusmpp(key = ident, length = ident)]`
-    KeyLengthIdent {
-        key_ident: Ident,
-        length_ident: Ident,
+    /// `#[rusmpp(key = ident|(ident, ..), length = expr)]`
+    KeyLengthExpr {
+        key_expr: syn::Expr,
+        length_expr: syn::Expr,
     },
     /// `#[rusmpp(count = ident)]`
     Count {
@@ -536,13 +799,13 @@ impl ValidFieldAttributes {
             self,
             Self::LengthUnchecked
                 | Self::LengthChecked
-                | Self::LengthIdent { .. }
+                | Self::LengthExpr { .. }
                 | Self::KeyLengthUnchecked { .. }
                 | Self::Count { .. }
         )
     }
 
-    const fn skip(&self) -> bool {
+    const fn skip_decode(&self) -> bool {
         matches!(self, Self::SkipDecode)
     }
 }
@@ -553,126 +816,183 @@ struct ValidField<'a> {
 }
 
 impl ValidField<'_> {
-    fn quote_borrowed_decode(&self) -> TokenStream {
+    fn quote_borrowed_decode(&self, crate_attrs: &CrateAttributes) -> TokenStream {
+        let krate = crate_attrs.path();
         let name = self
             .field
             .ident
             .as_ref()
             .expect("Named fields must have idents");
+        let field = crate_attrs.field(name);
 
         match &self.attrs {
             ValidFieldAttributes::None => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(
-                    crate::decode::borrowed::DecodeExt::decode_move(src, size),
-                    crate::fields::SmppField::#name,
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(
+                    #krate::decode::borrowed::DecodeExt::decode_move(src, size),
+                    #field,
                 )?;
             },
+            ValidFieldAttributes::Skip => quote! {
+                let #name = ::core::default::Default::default();
+            },
             ValidFieldAttributes::SkipDecode => quote! {},
             ValidFieldAttributes::LengthUnchecked => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(crate::decode::borrowed::DecodeWithLengthExt::decode_move(
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::borrowed::DecodeWithLengthExt::decode_move(
                     src, length.saturating_sub(size), size
-                ),crate::fields::SmppField::#name)?;
+                ),#field)?;
             },
             ValidFieldAttributes::LengthChecked => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(crate::decode::borrowed::DecodeExt::length_checked_decode_move(
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::borrowed::DecodeExt::length_checked_decode_move(
                     src, length.saturating_sub(size), size
-                ),crate::fields::SmppField::#name)?
+                ),#field)?
                 .map(|(this, size)| (Some(this), size))
                 .unwrap_or((None, size));
             },
-            ValidFieldAttributes::LengthIdent { length_ident } => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(crate::decode::borrowed::DecodeWithLengthExt::decode_move(
-                    src, #length_ident as usize, size
-                ),crate::fields::SmppField::#name)?;
+            ValidFieldAttributes::LengthExpr { length_expr } => quote! {
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::borrowed::DecodeWithLengthExt::decode_move(
+                    src, #length_expr as usize, size
+                ),#field)?;
             },
-            ValidFieldAttributes::KeyLengthUnchecked { key_ident } => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(crate::decode::borrowed::DecodeWithKeyOptionalExt::decode_move(
-                    #key_ident, src, length.saturating_sub(size), size
-                ),crate::fields::SmppField::#name)?
+            ValidFieldAttributes::KeyLengthUnchecked { key_expr } => quote! {
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::borrowed::DecodeWithKeyOptionalExt::decode_move(
+                    #key_expr, src, length.saturating_sub(size), size
+                ),#field)?
                 .map(|(this, size)| (Some(this), size))
                 .unwrap_or((None, size));
             },
-            ValidFieldAttributes::KeyLengthIdent {
-                key_ident,
-                length_ident,
+            ValidFieldAttributes::KeyLengthExpr {
+                key_expr,
+                length_expr,
             } => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(crate::decode::borrowed::DecodeWithKeyExt::optional_length_checked_decode_move(
-                    #key_ident, src, #length_ident as usize, size
-                ),crate::fields::SmppField::#name)?
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::borrowed::DecodeWithKeyExt::optional_length_checked_decode_move(
+                    #key_expr, src, #length_expr as usize, size
+                ),#field)?
                 .map(|(this, size)| (Some(this), size))
                 .unwrap_or((None, size));
             },
             ValidFieldAttributes::Count { count_ident } => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(crate::decode::borrowed::DecodeExt::counted_move(
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::borrowed::DecodeExt::counted_move(
                     src, #count_ident as usize, size
-                ),crate::fields::SmppField::#name)?;
+                ),#field)?;
             },
         }
     }
 
-    fn quote_owned_decode(&self) -> TokenStream {
+    fn quote_owned_decode(&self, crate_attrs: &CrateAttributes) -> TokenStream {
+        let krate = crate_attrs.path();
         let name = self
             .field
             .ident
             .as_ref()
             .expect("Named fields must have idents");
+        let field = crate_attrs.field(name);
 
         match &self.attrs {
             ValidFieldAttributes::None => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(
-                    crate::decode::owned::DecodeExt::decode_move(src, size),
-                    crate::fields::SmppField::#name,
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(
+                    #krate::decode::owned::DecodeExt::decode_move(src, size),
+                    #field,
                 )?;
             },
+            ValidFieldAttributes::Skip => quote! {
+                let #name = ::core::default::Default::default();
+            },
             ValidFieldAttributes::SkipDecode => quote! {},
             ValidFieldAttributes::LengthUnchecked => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(crate::decode::owned::DecodeWithLengthExt::decode_move(
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::owned::DecodeWithLengthExt::decode_move(
                     src, length.saturating_sub(size), size
-                ),crate::fields::SmppField::#name)?;
+                ),#field)?;
             },
             ValidFieldAttributes::LengthChecked => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(crate::decode::owned::DecodeExt::length_checked_decode_move(
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::owned::DecodeExt::length_checked_decode_move(
                     src, length.saturating_sub(size), size
-                ),crate::fields::SmppField::#name)?
+                ),#field)?
                 .map(|(this, size)| (Some(this), size))
                 .unwrap_or((None, size));
             },
-            ValidFieldAttributes::LengthIdent { length_ident } => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(crate::decode::owned::DecodeWithLengthExt::decode_move(
-                    src, #length_ident as usize, size
-                ),crate::fields::SmppField::#name)?;
+            ValidFieldAttributes::LengthExpr { length_expr } => quote! {
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::owned::DecodeWithLengthExt::decode_move(
+                    src, #length_expr as usize, size
+                ),#field)?;
             },
-            ValidFieldAttributes::KeyLengthUnchecked { key_ident } => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(crate::decode::owned::DecodeWithKeyOptionalExt::decode_move(
-                    #key_ident, src, length.saturating_sub(size), size
-                ),crate::fields::SmppField::#name)?
+            ValidFieldAttributes::KeyLengthUnchecked { key_expr } => quote! {
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::owned::DecodeWithKeyOptionalExt::decode_move(
+                    #key_expr, src, length.saturating_sub(size), size
+                ),#field)?
                 .map(|(this, size)| (Some(this), size))
                 .unwrap_or((None, size));
             },
-            ValidFieldAttributes::KeyLengthIdent {
-                key_ident,
-                length_ident,
+            ValidFieldAttributes::KeyLengthExpr {
+                key_expr,
+                length_expr,
             } => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(crate::decode::owned::DecodeWithKeyExt::optional_length_checked_decode_move(
-                    #key_ident, src, #length_ident as usize, size
-                ),crate::fields::SmppField::#name)?
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::owned::DecodeWithKeyExt::optional_length_checked_decode_move(
+                    #key_expr, src, #length_expr as usize, size
+                ),#field)?
                 .map(|(this, size)| (Some(this), size))
                 .unwrap_or((None, size));
             },
             ValidFieldAttributes::Count { count_ident } => quote! {
-                let (#name, size) = crate::decode::DecodeErrorExt::map_as_source(crate::decode::owned::DecodeExt::counted_move(
+                let (#name, size) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::owned::DecodeExt::counted_move(
                     src, #count_ident as usize, size
-                ),crate::fields::SmppField::#name)?;
+                ),#field)?;
             },
         }
     }
 }
 
+impl ValidField<'_> {
+    /// Fields holding `TLV`s are decoded and checked with the context, other fields as usual.
+    fn quote_owned_decode_with_context(
+        &self,
+        crate_attrs: &CrateAttributes,
+    ) -> syn::Result<TokenStream> {
+        if !is_tlv_field(self.field) {
+            return Ok(self.quote_owned_decode(crate_attrs));
+        }
+
+        let krate = crate_attrs.path();
+        let name = self
+            .field
+            .ident
+            .as_ref()
+            .expect("Named fields must have idents");
+        let ty = &self.field.ty;
+        let field = crate_attrs.field(name);
+
+        match &self.attrs {
+            ValidFieldAttributes::LengthUnchecked => Ok(quote! {
+                let (#name, size): (#ty, usize) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::owned::DecodeWithContextExt::decode_with_context_move(
+                    src, length.saturating_sub(size), size, context
+                ),#field)?;
+                context.check_tlvs(#name.as_slice())?;
+            }),
+            ValidFieldAttributes::LengthChecked => Ok(quote! {
+                let (#name, size): (#ty, usize) = #krate::decode::DecodeErrorExt::map_as_source(#krate::decode::owned::DecodeWithContextExt::length_checked_decode_with_context_move(
+                    src, length.saturating_sub(size), size, context
+                ),#field)?
+                .map(|(this, size)| (Some(this), size))
+                .unwrap_or((None, size));
+                context.check_tlvs(#name.as_slice())?;
+            }),
+            _ => Err(syn::Error::new_spanned(
+                self.field,
+                "fields holding TLVs must use length = \"unchecked\" or length = \"checked\"",
+            )),
+        }
+    }
+}
+
 struct ValidFields<'a> {
     fields: Vec<ValidField<'a>>,
 }
 
 impl ValidFields<'_> {
+    /// Returns true if any field holds `TLV`s, see [`is_tlv_field`].
+    fn has_tlvs(&self) -> bool {
+        self.fields.iter().any(|f| is_tlv_field(f.field))
+    }
+
     /// Depending on the attributes, determine which decode impl to generate.
     #[allow(clippy::obfuscated_if_else)]
     fn decode_type(&self) -> DecodeType {
@@ -695,21 +1015,33 @@ enum DecodeType {
     DecodeWithLength,
 }
 
-fn quote_test(input: &DeriveInput, test_attrs: &TestAttributes) -> TokenStream {
+fn quote_test(
+    input: &DeriveInput,
+    test_attrs: &TestAttributes,
+    crate_attrs: &CrateAttributes,
+) -> syn::Result<TokenStream> {
+    // `TestInstance` is private to `rusmpp-core`
+    if crate_attrs.is_external() {
+        return Ok(quote! {});
+    }
+
     match test_attrs {
-        TestAttributes::Skip => quote! {},
+        TestAttributes::Skip => Ok(quote! {}),
+        TestAttributes::RoundTrip { should_panic } => {
+            TestAttributes::quote_round_trip(input, should_panic.as_ref())
+        }
         TestAttributes::Implement => {
             let name = &input.ident;
             let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
 
-            quote! {
+            Ok(quote! {
                 #[cfg(test)]
                 impl #impl_generics crate::tests::TestInstance for #name #ty_generics #where_clause {
                     fn instances() -> alloc::vec::Vec<Self> {
                         alloc::vec![Self::default(),]
                     }
                 }
-            }
+            })
         }
     }
 }
@@ -95,6 +95,26 @@ impl crate::encode::Encode for CancelSm {
         size
     }
 }
+impl crate::encode::EncodeFields for CancelSm {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "service_type" => Some(&self.service_type),
+            "message_id" => Some(&self.message_id),
+            "other" => Some(&self.other),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["service_type", "message_id", "other"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.service_type, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.message_id, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.other, dst, size);
+        size
+    }
+}
 impl crate::decode::owned::Decode for CancelSm {
     fn decode(src: &[u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
         let size = 0;
@@ -217,6 +237,26 @@ impl<'a> crate::encode::Encode for CancelSm<'a> {
         size
     }
 }
+impl<'a> crate::encode::EncodeFields for CancelSm<'a> {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "service_type" => Some(&self.service_type),
+            "message_id" => Some(&self.message_id),
+            "other" => Some(&self.other),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["service_type", "message_id", "other"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.service_type, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.message_id, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.other, dst, size);
+        size
+    }
+}
 impl<'a> crate::decode::borrowed::Decode<'a> for CancelSm<'a> {
     fn decode(src: &'a [u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
         let size = 0;
@@ -103,6 +103,30 @@ impl crate::encode::Encode for SubmitMulti {
         size
     }
 }
+impl crate::encode::EncodeFields for SubmitMulti {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "other" => Some(&self.other),
+            "number_of_dests" => Some(&self.number_of_dests),
+            "dest_address" => Some(&self.dest_address),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["other", "number_of_dests", "dest_address"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.other, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(
+            &self.number_of_dests,
+            dst,
+            size,
+        );
+        let size = crate::encode::EncodeExt::encode_move(&self.dest_address, dst, size);
+        size
+    }
+}
 impl crate::decode::owned::DecodeWithLength for SubmitMulti {
     fn decode(
         src: &[u8],
@@ -240,6 +264,30 @@ impl<'a, const N: usize> crate::encode::Encode for SubmitMulti<'a, N> {
         size
     }
 }
+impl<'a, const N: usize> crate::encode::EncodeFields for SubmitMulti<'a, N> {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "other" => Some(&self.other),
+            "number_of_dests" => Some(&self.number_of_dests),
+            "dest_address" => Some(&self.dest_address),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["other", "number_of_dests", "dest_address"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.other, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(
+            &self.number_of_dests,
+            dst,
+            size,
+        );
+        let size = crate::encode::EncodeExt::encode_move(&self.dest_address, dst, size);
+        size
+    }
+}
 impl<'a, const N: usize> crate::decode::borrowed::DecodeWithLength<'a>
 for SubmitMulti<'a, N> {
     fn decode(
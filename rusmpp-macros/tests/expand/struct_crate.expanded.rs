@@ -0,0 +1,128 @@
+/// Docs
+///
+/// More docs
+#[rusmpp(crate = "::rusmpp_core", decode = owned)]
+pub struct QueryBalance {
+    /// Docs
+    ///
+    /// More docs
+    pub account: COctetString<1, 17>,
+    pub currency: u16,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for QueryBalance {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "QueryBalance",
+            "account",
+            &self.account,
+            "currency",
+            &&self.currency,
+        )
+    }
+}
+pub struct QueryBalanceParts {
+    pub account: COctetString<1, 17>,
+    pub currency: u16,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for QueryBalanceParts {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "QueryBalanceParts",
+            "account",
+            &self.account,
+            "currency",
+            &&self.currency,
+        )
+    }
+}
+impl QueryBalanceParts {
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(account: COctetString<1, 17>, currency: u16) -> Self {
+        Self { account, currency }
+    }
+    #[inline]
+    #[allow(unused_parens)]
+    pub fn raw(self) -> (COctetString<1, 17>, u16) {
+        (self.account, self.currency)
+    }
+}
+impl QueryBalance {
+    #[inline]
+    pub fn into_parts(self) -> QueryBalanceParts {
+        QueryBalanceParts {
+            account: self.account,
+            currency: self.currency,
+        }
+    }
+}
+impl ::rusmpp_core::encode::Length for QueryBalance {
+    fn length(&self) -> usize {
+        let mut length = 0;
+        length += ::rusmpp_core::encode::Length::length(&self.account);
+        length += ::rusmpp_core::encode::Length::length(&self.currency);
+        length
+    }
+}
+impl ::rusmpp_core::encode::Encode for QueryBalance {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = ::rusmpp_core::encode::EncodeExt::encode_move(
+            &self.account,
+            dst,
+            size,
+        );
+        let size = ::rusmpp_core::encode::EncodeExt::encode_move(
+            &self.currency,
+            dst,
+            size,
+        );
+        size
+    }
+}
+impl ::rusmpp_core::encode::EncodeFields for QueryBalance {
+    fn field(&self, name: &str) -> Option<&dyn ::rusmpp_core::encode::Encode> {
+        match name {
+            "account" => Some(&self.account),
+            "currency" => Some(&self.currency),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["account", "currency"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = ::rusmpp_core::encode::EncodeExt::encode_move(
+            &self.account,
+            dst,
+            size,
+        );
+        let size = ::rusmpp_core::encode::EncodeExt::encode_move(
+            &self.currency,
+            dst,
+            size,
+        );
+        size
+    }
+}
+impl ::rusmpp_core::decode::owned::Decode for QueryBalance {
+    fn decode(src: &[u8]) -> Result<(Self, usize), ::rusmpp_core::decode::DecodeError> {
+        let size = 0;
+        let (account, size) = ::rusmpp_core::decode::DecodeErrorExt::map_as_source(
+            ::rusmpp_core::decode::owned::DecodeExt::decode_move(src, size),
+            ::rusmpp_core::fields::SmppField::Other("account"),
+        )?;
+        let (currency, size) = ::rusmpp_core::decode::DecodeErrorExt::map_as_source(
+            ::rusmpp_core::decode::owned::DecodeExt::decode_move(src, size),
+            ::rusmpp_core::fields::SmppField::Other("currency"),
+        )?;
+        Ok((Self { account, currency }, size))
+    }
+}
@@ -0,0 +1,12 @@
+/// Docs
+///
+/// More docs
+#[derive(Debug, rusmpp_macros::Rusmpp)]
+#[rusmpp(crate = "::rusmpp_core", decode = owned)]
+pub struct QueryBalance {
+    /// Docs
+    ///
+    /// More docs
+    pub account: COctetString<1, 17>,
+    pub currency: u16,
+}
@@ -91,6 +91,26 @@ impl crate::encode::Encode for Tlv {
         size
     }
 }
+impl crate::encode::EncodeFields for Tlv {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "tag" => Some(&self.tag),
+            "value_length" => Some(&self.value_length),
+            "value" => Some(&self.value),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["tag", "value_length", "value"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.tag, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value_length, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value, dst, size);
+        size
+    }
+}
 impl crate::decode::owned::Decode for Tlv {
     fn decode(src: &[u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
         let size = 0;
@@ -213,6 +233,26 @@ impl<'a> crate::encode::Encode for Tlv<'a> {
         size
     }
 }
+impl<'a> crate::encode::EncodeFields for Tlv<'a> {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "tag" => Some(&self.tag),
+            "value_length" => Some(&self.value_length),
+            "value" => Some(&self.value),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["tag", "value_length", "value"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.tag, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value_length, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value, dst, size);
+        size
+    }
+}
 impl<'a> crate::decode::borrowed::Decode<'a> for Tlv<'a> {
     fn decode(src: &'a [u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
         let size = 0;
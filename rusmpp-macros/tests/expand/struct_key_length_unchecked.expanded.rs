@@ -118,6 +118,36 @@ impl crate::encode::Encode for Command {
         size
     }
 }
+impl crate::encode::EncodeFields for Command {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "id" => Some(&self.id),
+            "command_status" => Some(&self.command_status),
+            "sequence_number" => Some(&self.sequence_number),
+            "pdu" => Some(&self.pdu),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["id", "command_status", "sequence_number", "pdu"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.id, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(
+            &self.command_status,
+            dst,
+            size,
+        );
+        let size = crate::encode::EncodeExt::encode_move(
+            &self.sequence_number,
+            dst,
+            size,
+        );
+        let size = crate::encode::EncodeExt::encode_move(&self.pdu, dst, size);
+        size
+    }
+}
 impl crate::decode::owned::DecodeWithLength for Command {
     fn decode(
         src: &[u8],
@@ -278,6 +308,36 @@ impl<'a, const N: usize> crate::encode::Encode for Command<'a, N> {
         size
     }
 }
+impl<'a, const N: usize> crate::encode::EncodeFields for Command<'a, N> {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "id" => Some(&self.id),
+            "command_status" => Some(&self.command_status),
+            "sequence_number" => Some(&self.sequence_number),
+            "pdu" => Some(&self.pdu),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["id", "command_status", "sequence_number", "pdu"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.id, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(
+            &self.command_status,
+            dst,
+            size,
+        );
+        let size = crate::encode::EncodeExt::encode_move(
+            &self.sequence_number,
+            dst,
+            size,
+        );
+        let size = crate::encode::EncodeExt::encode_move(&self.pdu, dst, size);
+        size
+    }
+}
 impl<'a, const N: usize> crate::decode::borrowed::DecodeWithLength<'a>
 for Command<'a, N> {
     fn decode(
@@ -0,0 +1,168 @@
+/// Docs
+///
+/// More docs
+#[rusmpp(decode = owned, test = skip)]
+pub struct NestedTlv {
+    tag: u16,
+    subtype: u8,
+    value_length: u16,
+    /// Docs
+    ///
+    /// More docs
+    #[rusmpp(key = (tag, subtype), length = value_length)]
+    value: Option<NestedValue>,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for NestedTlv {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field4_finish(
+            f,
+            "NestedTlv",
+            "tag",
+            &self.tag,
+            "subtype",
+            &self.subtype,
+            "value_length",
+            &self.value_length,
+            "value",
+            &&self.value,
+        )
+    }
+}
+pub struct NestedTlvParts {
+    pub tag: u16,
+    pub subtype: u8,
+    pub value_length: u16,
+    pub value: Option<NestedValue>,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for NestedTlvParts {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field4_finish(
+            f,
+            "NestedTlvParts",
+            "tag",
+            &self.tag,
+            "subtype",
+            &self.subtype,
+            "value_length",
+            &self.value_length,
+            "value",
+            &&self.value,
+        )
+    }
+}
+impl NestedTlvParts {
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        tag: u16,
+        subtype: u8,
+        value_length: u16,
+        value: Option<NestedValue>,
+    ) -> Self {
+        Self {
+            tag,
+            subtype,
+            value_length,
+            value,
+        }
+    }
+    #[inline]
+    #[allow(unused_parens)]
+    pub fn raw(self) -> (u16, u8, u16, Option<NestedValue>) {
+        (self.tag, self.subtype, self.value_length, self.value)
+    }
+}
+impl NestedTlv {
+    #[inline]
+    pub fn into_parts(self) -> NestedTlvParts {
+        NestedTlvParts {
+            tag: self.tag,
+            subtype: self.subtype,
+            value_length: self.value_length,
+            value: self.value,
+        }
+    }
+}
+impl crate::encode::Length for NestedTlv {
+    fn length(&self) -> usize {
+        let mut length = 0;
+        length += crate::encode::Length::length(&self.tag);
+        length += crate::encode::Length::length(&self.subtype);
+        length += crate::encode::Length::length(&self.value_length);
+        length += crate::encode::Length::length(&self.value);
+        length
+    }
+}
+impl crate::encode::Encode for NestedTlv {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.tag, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.subtype, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value_length, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value, dst, size);
+        size
+    }
+}
+impl crate::encode::EncodeFields for NestedTlv {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "tag" => Some(&self.tag),
+            "subtype" => Some(&self.subtype),
+            "value_length" => Some(&self.value_length),
+            "value" => Some(&self.value),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["tag", "subtype", "value_length", "value"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.tag, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.subtype, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value_length, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value, dst, size);
+        size
+    }
+}
+impl crate::decode::owned::Decode for NestedTlv {
+    fn decode(src: &[u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
+        let size = 0;
+        let (tag, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::tag,
+        )?;
+        let (subtype, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::subtype,
+        )?;
+        let (value_length, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::value_length,
+        )?;
+        let (value, size) = crate::decode::DecodeErrorExt::map_as_source(
+                crate::decode::owned::DecodeWithKeyExt::optional_length_checked_decode_move(
+                    (tag, subtype),
+                    src,
+                    value_length as usize,
+                    size,
+                ),
+                crate::fields::SmppField::value,
+            )?
+            .map(|(this, size)| (Some(this), size))
+            .unwrap_or((None, size));
+        Ok((
+            Self {
+                tag,
+                subtype,
+                value_length,
+                value,
+            },
+            size,
+        ))
+    }
+}
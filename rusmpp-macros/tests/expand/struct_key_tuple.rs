@@ -0,0 +1,15 @@
+/// Docs
+///
+/// More docs
+#[derive(Debug, rusmpp_macros::Rusmpp)]
+#[rusmpp(decode = owned, test = skip)]
+pub struct NestedTlv {
+    tag: u16,
+    subtype: u8,
+    value_length: u16,
+    /// Docs
+    ///
+    /// More docs
+    #[rusmpp(key = (tag, subtype), length = value_length)]
+    value: Option<NestedValue>,
+}
@@ -95,6 +95,32 @@ impl crate::encode::Encode for MsValidity {
         size
     }
 }
+impl crate::encode::EncodeFields for MsValidity {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "validity_behavior" => Some(&self.validity_behavior),
+            "validity_information" => Some(&self.validity_information),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["validity_behavior", "validity_information"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(
+            &self.validity_behavior,
+            dst,
+            size,
+        );
+        let size = crate::encode::EncodeExt::encode_move(
+            &self.validity_information,
+            dst,
+            size,
+        );
+        size
+    }
+}
 impl<'a> crate::decode::borrowed::DecodeWithLength<'a> for MsValidity {
     fn decode(
         src: &'a [u8],
@@ -0,0 +1,168 @@
+/// Docs
+///
+/// More docs
+#[rusmpp(decode = owned, test = skip)]
+pub struct ChecksummedTlv {
+    tag: u16,
+    value_length: u16,
+    checksum: u16,
+    /// Docs
+    ///
+    /// More docs
+    #[rusmpp(length = u16::saturating_sub(value_length, 2))]
+    value: AnyOctetString,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for ChecksummedTlv {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field4_finish(
+            f,
+            "ChecksummedTlv",
+            "tag",
+            &self.tag,
+            "value_length",
+            &self.value_length,
+            "checksum",
+            &self.checksum,
+            "value",
+            &&self.value,
+        )
+    }
+}
+pub struct ChecksummedTlvParts {
+    pub tag: u16,
+    pub value_length: u16,
+    pub checksum: u16,
+    pub value: AnyOctetString,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for ChecksummedTlvParts {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field4_finish(
+            f,
+            "ChecksummedTlvParts",
+            "tag",
+            &self.tag,
+            "value_length",
+            &self.value_length,
+            "checksum",
+            &self.checksum,
+            "value",
+            &&self.value,
+        )
+    }
+}
+impl ChecksummedTlvParts {
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        tag: u16,
+        value_length: u16,
+        checksum: u16,
+        value: AnyOctetString,
+    ) -> Self {
+        Self {
+            tag,
+            value_length,
+            checksum,
+            value,
+        }
+    }
+    #[inline]
+    #[allow(unused_parens)]
+    pub fn raw(self) -> (u16, u16, u16, AnyOctetString) {
+        (self.tag, self.value_length, self.checksum, self.value)
+    }
+}
+impl ChecksummedTlv {
+    #[inline]
+    pub fn into_parts(self) -> ChecksummedTlvParts {
+        ChecksummedTlvParts {
+            tag: self.tag,
+            value_length: self.value_length,
+            checksum: self.checksum,
+            value: self.value,
+        }
+    }
+}
+impl crate::encode::Length for ChecksummedTlv {
+    fn length(&self) -> usize {
+        let mut length = 0;
+        length += crate::encode::Length::length(&self.tag);
+        length += crate::encode::Length::length(&self.value_length);
+        length += crate::encode::Length::length(&self.checksum);
+        length += crate::encode::Length::length(&self.value);
+        length
+    }
+}
+impl crate::encode::Encode for ChecksummedTlv {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.tag, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value_length, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.checksum, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value, dst, size);
+        size
+    }
+}
+impl crate::encode::EncodeFields for ChecksummedTlv {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "tag" => Some(&self.tag),
+            "value_length" => Some(&self.value_length),
+            "checksum" => Some(&self.checksum),
+            "value" => Some(&self.value),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["tag", "value_length", "checksum", "value"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.tag, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value_length, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.checksum, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value, dst, size);
+        size
+    }
+}
+impl crate::decode::owned::DecodeWithLength for ChecksummedTlv {
+    fn decode(
+        src: &[u8],
+        length: usize,
+    ) -> Result<(Self, usize), crate::decode::DecodeError> {
+        let size = 0;
+        let (tag, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::tag,
+        )?;
+        let (value_length, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::value_length,
+        )?;
+        let (checksum, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::checksum,
+        )?;
+        let (value, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeWithLengthExt::decode_move(
+                src,
+                (u16::saturating_sub(value_length, 2)) as usize,
+                size,
+            ),
+            crate::fields::SmppField::value,
+        )?;
+        Ok((
+            Self {
+                tag,
+                value_length,
+                checksum,
+                value,
+            },
+            size,
+        ))
+    }
+}
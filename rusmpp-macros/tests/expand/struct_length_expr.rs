@@ -0,0 +1,15 @@
+/// Docs
+///
+/// More docs
+#[derive(Debug, rusmpp_macros::Rusmpp)]
+#[rusmpp(decode = owned, test = skip)]
+pub struct ChecksummedTlv {
+    tag: u16,
+    value_length: u16,
+    checksum: u16,
+    /// Docs
+    ///
+    /// More docs
+    #[rusmpp(length = u16::saturating_sub(value_length, 2))]
+    value: AnyOctetString,
+}
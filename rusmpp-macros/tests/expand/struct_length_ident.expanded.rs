@@ -99,6 +99,26 @@ impl crate::encode::Encode for SubmitSm {
         size
     }
 }
+impl crate::encode::EncodeFields for SubmitSm {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "other" => Some(&self.other),
+            "sm_length" => Some(&self.sm_length),
+            "short_message" => Some(&self.short_message),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["other", "sm_length", "short_message"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.other, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.sm_length, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.short_message, dst, size);
+        size
+    }
+}
 impl crate::decode::owned::DecodeWithLength for SubmitSm {
     fn decode(
         src: &[u8],
@@ -232,6 +252,26 @@ impl<'a, const N: usize> crate::encode::Encode for SubmitSm<'a, N> {
         size
     }
 }
+impl<'a, const N: usize> crate::encode::EncodeFields for SubmitSm<'a, N> {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "other" => Some(&self.other),
+            "sm_length" => Some(&self.sm_length),
+            "short_message" => Some(&self.short_message),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["other", "sm_length", "short_message"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.other, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.sm_length, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.short_message, dst, size);
+        size
+    }
+}
 impl<'a, const N: usize> crate::decode::borrowed::DecodeWithLength<'a>
 for SubmitSm<'a, N> {
     fn decode(
@@ -82,6 +82,24 @@ impl crate::encode::Encode for BroadcastAreaIdentifier {
         size
     }
 }
+impl crate::encode::EncodeFields for BroadcastAreaIdentifier {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "format" => Some(&self.format),
+            "area" => Some(&self.area),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["format", "area"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.format, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.area, dst, size);
+        size
+    }
+}
 impl crate::decode::owned::DecodeWithLength for BroadcastAreaIdentifier {
     fn decode(
         src: &[u8],
@@ -187,6 +205,24 @@ impl<'a> crate::encode::Encode for BroadcastAreaIdentifier<'a> {
         size
     }
 }
+impl<'a> crate::encode::EncodeFields for BroadcastAreaIdentifier<'a> {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "format" => Some(&self.format),
+            "area" => Some(&self.area),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["format", "area"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.format, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.area, dst, size);
+        size
+    }
+}
 impl<'a> crate::decode::borrowed::DecodeWithLength<'a> for BroadcastAreaIdentifier<'a> {
     fn decode(
         src: &'a [u8],
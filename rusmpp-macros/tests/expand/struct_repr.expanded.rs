@@ -64,21 +64,23 @@ impl CallbackNumPresInd {
 }
 impl crate::encode::Length for CallbackNumPresInd {
     fn length(&self) -> usize {
-        u8::from(*self).length()
+        crate::encode::Length::length(&u8::from(*self))
     }
 }
 impl crate::encode::Encode for CallbackNumPresInd {
     fn encode(&self, dst: &mut [u8]) -> usize {
-        u8::from(*self).encode(dst)
+        crate::encode::Encode::encode(&u8::from(*self), dst)
     }
 }
 impl crate::decode::owned::Decode for CallbackNumPresInd {
     fn decode(src: &[u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
-        u8::decode(src).map(|(this, size)| (Self::from(this), size))
+        <u8 as crate::decode::owned::Decode>::decode(src)
+            .map(|(this, size)| (Self::from(this), size))
     }
 }
 impl<'a> crate::decode::borrowed::Decode<'a> for CallbackNumPresInd {
     fn decode(src: &'a [u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
-        u8::decode(src).map(|(this, size)| (Self::from(this), size))
+        <u8 as crate::decode::borrowed::Decode<'a>>::decode(src)
+            .map(|(this, size)| (Self::from(this), size))
     }
 }
@@ -0,0 +1,129 @@
+/// Docs
+///
+/// More docs
+#[rusmpp(decode = owned, test = roundtrip)]
+pub struct QuerySm {
+    /// Docs
+    ///
+    /// More docs
+    message_id: COctetString<1, 65>,
+    source_addr_ton: Ton,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for QuerySm {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "QuerySm",
+            "message_id",
+            &self.message_id,
+            "source_addr_ton",
+            &&self.source_addr_ton,
+        )
+    }
+}
+pub struct QuerySmParts {
+    pub message_id: COctetString<1, 65>,
+    pub source_addr_ton: Ton,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for QuerySmParts {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field2_finish(
+            f,
+            "QuerySmParts",
+            "message_id",
+            &self.message_id,
+            "source_addr_ton",
+            &&self.source_addr_ton,
+        )
+    }
+}
+impl QuerySmParts {
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(message_id: COctetString<1, 65>, source_addr_ton: Ton) -> Self {
+        Self {
+            message_id,
+            source_addr_ton,
+        }
+    }
+    #[inline]
+    #[allow(unused_parens)]
+    pub fn raw(self) -> (COctetString<1, 65>, Ton) {
+        (self.message_id, self.source_addr_ton)
+    }
+}
+impl QuerySm {
+    #[inline]
+    pub fn into_parts(self) -> QuerySmParts {
+        QuerySmParts {
+            message_id: self.message_id,
+            source_addr_ton: self.source_addr_ton,
+        }
+    }
+}
+impl crate::encode::Length for QuerySm {
+    fn length(&self) -> usize {
+        let mut length = 0;
+        length += crate::encode::Length::length(&self.message_id);
+        length += crate::encode::Length::length(&self.source_addr_ton);
+        length
+    }
+}
+impl crate::encode::Encode for QuerySm {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.message_id, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(
+            &self.source_addr_ton,
+            dst,
+            size,
+        );
+        size
+    }
+}
+impl crate::encode::EncodeFields for QuerySm {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "message_id" => Some(&self.message_id),
+            "source_addr_ton" => Some(&self.source_addr_ton),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["message_id", "source_addr_ton"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.message_id, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(
+            &self.source_addr_ton,
+            dst,
+            size,
+        );
+        size
+    }
+}
+impl crate::decode::owned::Decode for QuerySm {
+    fn decode(src: &[u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
+        let size = 0;
+        let (message_id, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::message_id,
+        )?;
+        let (source_addr_ton, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::source_addr_ton,
+        )?;
+        Ok((
+            Self {
+                message_id,
+                source_addr_ton,
+            },
+            size,
+        ))
+    }
+}
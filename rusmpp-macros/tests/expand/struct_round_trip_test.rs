@@ -0,0 +1,12 @@
+/// Docs
+///
+/// More docs
+#[derive(Debug, rusmpp_macros::Rusmpp)]
+#[rusmpp(decode = owned, test = roundtrip)]
+pub struct QuerySm {
+    /// Docs
+    ///
+    /// More docs
+    message_id: COctetString<1, 65>,
+    source_addr_ton: Ton,
+}
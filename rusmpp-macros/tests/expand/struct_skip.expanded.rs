@@ -69,3 +69,19 @@ impl crate::encode::Encode for DistributionListName {
         size
     }
 }
+impl crate::encode::EncodeFields for DistributionListName {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "dest_flag" => Some(&self.dest_flag),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["dest_flag"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.dest_flag, dst, size);
+        size
+    }
+}
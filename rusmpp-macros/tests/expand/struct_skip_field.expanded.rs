@@ -79,6 +79,24 @@ impl crate::encode::Encode for DistributionListName {
         size
     }
 }
+impl crate::encode::EncodeFields for DistributionListName {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "dest_flag" => Some(&self.dest_flag),
+            "dl_name" => Some(&self.dl_name),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["dest_flag", "dl_name"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.dest_flag, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.dl_name, dst, size);
+        size
+    }
+}
 impl crate::decode::owned::Decode for DistributionListName {
     fn decode(src: &[u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
         let size = 0;
@@ -170,6 +188,24 @@ impl<'a> crate::encode::Encode for DistributionListName<'a> {
         size
     }
 }
+impl<'a> crate::encode::EncodeFields for DistributionListName<'a> {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "dest_flag" => Some(&self.dest_flag),
+            "dl_name" => Some(&self.dl_name),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["dest_flag", "dl_name"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.dest_flag, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.dl_name, dst, size);
+        size
+    }
+}
 impl<'a> crate::decode::borrowed::Decode<'a> for DistributionListName<'a> {
     fn decode(src: &'a [u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
         let size = 0;
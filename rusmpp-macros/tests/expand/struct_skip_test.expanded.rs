@@ -69,6 +69,22 @@ impl crate::encode::Encode for SubmitSmResp {
         size
     }
 }
+impl crate::encode::EncodeFields for SubmitSmResp {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "message_id" => Some(&self.message_id),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["message_id"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.message_id, dst, size);
+        size
+    }
+}
 impl crate::decode::owned::Decode for SubmitSmResp {
     fn decode(src: &[u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
         let size = 0;
@@ -150,6 +166,22 @@ impl<'a> crate::encode::Encode for SubmitSmResp<'a> {
         size
     }
 }
+impl<'a> crate::encode::EncodeFields for SubmitSmResp<'a> {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "message_id" => Some(&self.message_id),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["message_id"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.message_id, dst, size);
+        size
+    }
+}
 impl<'a> crate::decode::borrowed::Decode<'a> for SubmitSmResp<'a> {
     fn decode(src: &'a [u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
         let size = 0;
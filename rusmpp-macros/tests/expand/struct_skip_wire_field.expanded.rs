@@ -0,0 +1,162 @@
+/// Docs
+///
+/// More docs
+#[rusmpp(decode = owned, test = skip)]
+pub struct CappedTlv {
+    tag: u16,
+    value_length: u16,
+    #[rusmpp(length = value_length)]
+    value: AnyOctetString,
+    /// Docs
+    ///
+    /// More docs
+    #[rusmpp(skip)]
+    uncapped_value_length: Option<u16>,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for CappedTlv {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field4_finish(
+            f,
+            "CappedTlv",
+            "tag",
+            &self.tag,
+            "value_length",
+            &self.value_length,
+            "value",
+            &self.value,
+            "uncapped_value_length",
+            &&self.uncapped_value_length,
+        )
+    }
+}
+pub struct CappedTlvParts {
+    pub tag: u16,
+    pub value_length: u16,
+    pub value: AnyOctetString,
+    pub uncapped_value_length: Option<u16>,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for CappedTlvParts {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field4_finish(
+            f,
+            "CappedTlvParts",
+            "tag",
+            &self.tag,
+            "value_length",
+            &self.value_length,
+            "value",
+            &self.value,
+            "uncapped_value_length",
+            &&self.uncapped_value_length,
+        )
+    }
+}
+impl CappedTlvParts {
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        tag: u16,
+        value_length: u16,
+        value: AnyOctetString,
+        uncapped_value_length: Option<u16>,
+    ) -> Self {
+        Self {
+            tag,
+            value_length,
+            value,
+            uncapped_value_length,
+        }
+    }
+    #[inline]
+    #[allow(unused_parens)]
+    pub fn raw(self) -> (u16, u16, AnyOctetString, Option<u16>) {
+        (self.tag, self.value_length, self.value, self.uncapped_value_length)
+    }
+}
+impl CappedTlv {
+    #[inline]
+    pub fn into_parts(self) -> CappedTlvParts {
+        CappedTlvParts {
+            tag: self.tag,
+            value_length: self.value_length,
+            value: self.value,
+            uncapped_value_length: self.uncapped_value_length,
+        }
+    }
+}
+impl crate::encode::Length for CappedTlv {
+    fn length(&self) -> usize {
+        let mut length = 0;
+        length += crate::encode::Length::length(&self.tag);
+        length += crate::encode::Length::length(&self.value_length);
+        length += crate::encode::Length::length(&self.value);
+        length
+    }
+}
+impl crate::encode::Encode for CappedTlv {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.tag, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value_length, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value, dst, size);
+        size
+    }
+}
+impl crate::encode::EncodeFields for CappedTlv {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "tag" => Some(&self.tag),
+            "value_length" => Some(&self.value_length),
+            "value" => Some(&self.value),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["tag", "value_length", "value"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.tag, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value_length, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(&self.value, dst, size);
+        size
+    }
+}
+impl crate::decode::owned::DecodeWithLength for CappedTlv {
+    fn decode(
+        src: &[u8],
+        length: usize,
+    ) -> Result<(Self, usize), crate::decode::DecodeError> {
+        let size = 0;
+        let (tag, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::tag,
+        )?;
+        let (value_length, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::value_length,
+        )?;
+        let (value, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeWithLengthExt::decode_move(
+                src,
+                value_length as usize,
+                size,
+            ),
+            crate::fields::SmppField::value,
+        )?;
+        let uncapped_value_length = ::core::default::Default::default();
+        Ok((
+            Self {
+                tag,
+                value_length,
+                value,
+                uncapped_value_length,
+            },
+            size,
+        ))
+    }
+}
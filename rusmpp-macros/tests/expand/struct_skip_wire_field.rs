@@ -0,0 +1,16 @@
+/// Docs
+///
+/// More docs
+#[derive(Debug, rusmpp_macros::Rusmpp)]
+#[rusmpp(decode = owned, test = skip)]
+pub struct CappedTlv {
+    tag: u16,
+    value_length: u16,
+    #[rusmpp(length = value_length)]
+    value: AnyOctetString,
+    /// Docs
+    ///
+    /// More docs
+    #[rusmpp(skip)]
+    uncapped_value_length: Option<u16>,
+}
@@ -0,0 +1,182 @@
+/// Docs
+///
+/// More docs
+#[rusmpp(decode = owned, test = skip)]
+pub struct BroadcastSmResp {
+    /// Docs
+    ///
+    /// More docs
+    message_id: COctetString<1, 65>,
+    /// Docs
+    ///
+    /// More docs
+    #[rusmpp(length = "checked")]
+    user_message_reference: Option<Tlv>,
+    /// Docs
+    ///
+    /// More docs
+    #[rusmpp(length = "unchecked")]
+    tlvs: alloc::vec::Vec<Tlv>,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for BroadcastSmResp {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field3_finish(
+            f,
+            "BroadcastSmResp",
+            "message_id",
+            &self.message_id,
+            "user_message_reference",
+            &self.user_message_reference,
+            "tlvs",
+            &&self.tlvs,
+        )
+    }
+}
+pub struct BroadcastSmRespParts {
+    pub message_id: COctetString<1, 65>,
+    pub user_message_reference: Option<Tlv>,
+    pub tlvs: alloc::vec::Vec<Tlv>,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for BroadcastSmRespParts {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field3_finish(
+            f,
+            "BroadcastSmRespParts",
+            "message_id",
+            &self.message_id,
+            "user_message_reference",
+            &self.user_message_reference,
+            "tlvs",
+            &&self.tlvs,
+        )
+    }
+}
+impl BroadcastSmRespParts {
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        message_id: COctetString<1, 65>,
+        user_message_reference: Option<Tlv>,
+        tlvs: alloc::vec::Vec<Tlv>,
+    ) -> Self {
+        Self {
+            message_id,
+            user_message_reference,
+            tlvs,
+        }
+    }
+    #[inline]
+    #[allow(unused_parens)]
+    pub fn raw(self) -> (COctetString<1, 65>, Option<Tlv>, alloc::vec::Vec<Tlv>) {
+        (self.message_id, self.user_message_reference, self.tlvs)
+    }
+}
+impl BroadcastSmResp {
+    #[inline]
+    pub fn into_parts(self) -> BroadcastSmRespParts {
+        BroadcastSmRespParts {
+            message_id: self.message_id,
+            user_message_reference: self.user_message_reference,
+            tlvs: self.tlvs,
+        }
+    }
+}
+impl crate::encode::Length for BroadcastSmResp {
+    fn length(&self) -> usize {
+        let mut length = 0;
+        length += crate::encode::Length::length(&self.message_id);
+        length += crate::encode::Length::length(&self.user_message_reference);
+        length += crate::encode::Length::length(&self.tlvs);
+        length
+    }
+}
+impl crate::encode::Encode for BroadcastSmResp {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.message_id, dst, size);
+        let size = crate::encode::EncodeExt::encode_move(
+            &self.user_message_reference,
+            dst,
+            size,
+        );
+        let size = crate::encode::EncodeExt::encode_move(&self.tlvs, dst, size);
+        size
+    }
+}
+impl crate::encode::EncodeFields for BroadcastSmResp {
+    fn field(&self, name: &str) -> Option<&dyn crate::encode::Encode> {
+        match name {
+            "message_id" => Some(&self.message_id),
+            "user_message_reference" => Some(&self.user_message_reference),
+            "tlvs" => Some(&self.tlvs),
+            _ => None,
+        }
+    }
+    fn mandatory_fields(&self) -> &'static [&'static str] {
+        &["message_id"]
+    }
+    fn encode_mandatory_fields(&self, dst: &mut [u8]) -> usize {
+        let size = 0;
+        let size = crate::encode::EncodeExt::encode_move(&self.message_id, dst, size);
+        size
+    }
+}
+impl crate::decode::owned::DecodeWithLength for BroadcastSmResp {
+    fn decode(
+        src: &[u8],
+        length: usize,
+    ) -> Result<(Self, usize), crate::decode::DecodeError> {
+        crate::decode::owned::DecodeWithContext::decode_with_context(
+            src,
+            length,
+            &mut crate::decode::DecodeContext::default(),
+        )
+    }
+}
+impl crate::decode::owned::DecodeWithContext for BroadcastSmResp {
+    fn decode_with_context(
+        src: &[u8],
+        length: usize,
+        context: &mut crate::decode::DecodeContext,
+    ) -> Result<(Self, usize), crate::decode::DecodeError> {
+        let size = 0;
+        let (message_id, size) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::message_id,
+        )?;
+        let (user_message_reference, size): (Option<Tlv>, usize) = crate::decode::DecodeErrorExt::map_as_source(
+                crate::decode::owned::DecodeWithContextExt::length_checked_decode_with_context_move(
+                    src,
+                    length.saturating_sub(size),
+                    size,
+                    context,
+                ),
+                crate::fields::SmppField::user_message_reference,
+            )?
+            .map(|(this, size)| (Some(this), size))
+            .unwrap_or((None, size));
+        context.check_tlvs(user_message_reference.as_slice())?;
+        let (tlvs, size): (alloc::vec::Vec<Tlv>, usize) = crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeWithContextExt::decode_with_context_move(
+                src,
+                length.saturating_sub(size),
+                size,
+                context,
+            ),
+            crate::fields::SmppField::tlvs,
+        )?;
+        context.check_tlvs(tlvs.as_slice())?;
+        Ok((
+            Self {
+                message_id,
+                user_message_reference,
+                tlvs,
+            },
+            size,
+        ))
+    }
+}
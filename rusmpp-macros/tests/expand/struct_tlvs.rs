@@ -0,0 +1,21 @@
+/// Docs
+///
+/// More docs
+#[derive(Debug, rusmpp_macros::Rusmpp)]
+#[rusmpp(decode = owned, test = skip)]
+pub struct BroadcastSmResp {
+    /// Docs
+    ///
+    /// More docs
+    message_id: COctetString<1, 65>,
+    /// Docs
+    ///
+    /// More docs
+    #[rusmpp(length = "checked")]
+    user_message_reference: Option<Tlv>,
+    /// Docs
+    ///
+    /// More docs
+    #[rusmpp(length = "unchecked")]
+    tlvs: alloc::vec::Vec<Tlv>,
+}
@@ -191,6 +191,31 @@ impl ::core::fmt::Debug for BroadcastRequestTlvValue {
     }
 }
 impl BroadcastRequestTlvValue {
+    /// Tags of the known `TLV`s of this group.
+    pub const TAGS: &'static [TlvTag] = &[
+        TlvTag::AlertOnMessageDelivery,
+        TlvTag::BroadcastChannelIndicator,
+        TlvTag::BroadcastContentTypeInfo,
+        TlvTag::BroadcastMessageClass,
+        TlvTag::BroadcastServiceGroup,
+        TlvTag::CallbackNum,
+        TlvTag::CallbackNumAtag,
+        TlvTag::CallbackNumPresInd,
+        TlvTag::DestAddrSubunit,
+        TlvTag::DestSubaddress,
+        TlvTag::DestPort,
+        TlvTag::DisplayTime,
+        TlvTag::LanguageIndicator,
+        TlvTag::MessagePayload,
+        TlvTag::MsValidity,
+        TlvTag::PayloadType,
+        TlvTag::PrivacyIndicator,
+        TlvTag::SmsSignal,
+        TlvTag::SourceAddrSubunit,
+        TlvTag::SourcePort,
+        TlvTag::SourceSubaddress,
+        TlvTag::UserMessageReference,
+    ];
     pub const fn tag(&self) -> TlvTag {
         match self {
             BroadcastRequestTlvValue::AlertOnMessageDelivery(_) => {
@@ -515,6 +540,35 @@ impl<'a> ::core::fmt::Debug for BroadcastRequestTlvValue<'a> {
     }
 }
 impl<'a> BroadcastRequestTlvValue<'a> {
+    /// Tags of the known `TLV`s of this group.
+    pub const TAGS: &'static [TlvTag] = &[
+        TlvTag::BroadcastAreaIdentifier,
+        TlvTag::BroadcastContentType,
+        TlvTag::BroadcastFrequencyInterval,
+        TlvTag::BroadcastRepNum,
+        TlvTag::AlertOnMessageDelivery,
+        TlvTag::BroadcastChannelIndicator,
+        TlvTag::BroadcastContentTypeInfo,
+        TlvTag::BroadcastMessageClass,
+        TlvTag::BroadcastServiceGroup,
+        TlvTag::CallbackNum,
+        TlvTag::CallbackNumAtag,
+        TlvTag::CallbackNumPresInd,
+        TlvTag::DestAddrSubunit,
+        TlvTag::DestSubaddress,
+        TlvTag::DestPort,
+        TlvTag::DisplayTime,
+        TlvTag::LanguageIndicator,
+        TlvTag::MessagePayload,
+        TlvTag::MsValidity,
+        TlvTag::PayloadType,
+        TlvTag::PrivacyIndicator,
+        TlvTag::SmsSignal,
+        TlvTag::SourceAddrSubunit,
+        TlvTag::SourcePort,
+        TlvTag::SourceSubaddress,
+        TlvTag::UserMessageReference,
+    ];
     pub const fn tag(&self) -> TlvTag {
         match self {
             BroadcastRequestTlvValue::BroadcastAreaIdentifier(_) => {
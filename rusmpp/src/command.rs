@@ -1,5 +1,8 @@
 //! `SMPP` command.
 
 pub use rusmpp_core::command::owned::{
-    CommandParts, CommandStatusBuilder, PduBuilder, SequenceNumberBuilder,
+    CommandParts, CommandStatusBuilder, PduBuilder, SequenceNumberBuilder, VectoredCommand,
+    decode_many, encode_many,
 };
+
+pub use rusmpp_core::command::{CommandHeader, SequenceNumbers, peek_header};
@@ -1,7 +1,8 @@
 //! Traits for decoding `SMPP` values.
 
 pub use rusmpp_core::decode::{
-    COctetStringDecodeError, DecodeError, DecodeErrorKind, OctetStringDecodeError, owned::*,
+    COctetStringDecodeError, DecodeContext, DecodeError, DecodeErrorKind, DecodeErrorTlv,
+    DecodeOptions, Decoder, OctetStringDecodeError, owned::*,
 };
 
 #[cfg(feature = "verbose")]
@@ -0,0 +1,3 @@
+//! `SMPP` delivery receipt.
+
+pub use rusmpp_core::delivery_receipt::*;
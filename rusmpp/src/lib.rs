@@ -85,10 +85,13 @@
 //! ## Features
 //!
 //! - `tokio-codec`: Implements [`Encoder`](https://docs.rs/tokio-util/latest/tokio_util/codec/trait.Encoder.html) and [`Decoder`](https://docs.rs/tokio-util/latest/tokio_util/codec/trait.Decoder.html) traits.
+//! - `std`: Enables encoding commands into [`Write`](https://doc.rust-lang.org/std/io/trait.Write.html)rs.
 //! - `verbose`: Enables verbose error reports.
 //! - `serde`: Implements [`Serialize`](https://docs.rs/serde/latest/serde/trait.Serialize.html) trait for all SMPP types.
 //! - `serde-deserialize-unchecked`: Implements [`Deserialize`](https://docs.rs/serde/latest/serde/trait.Deserialize.html) trait for all SMPP types, but does not check the validity of the data. Use with caution.
 //! - `tracing`: Enables logging using [`tracing`](https://docs.rs/tracing/latest/tracing/).
+//! - `bytes`: Enables encoding commands into [`Bytes`](https://docs.rs/bytes/latest/bytes/struct.Bytes.html).
+//! - `test-server`: Enables the in-memory `MockSmsc` for testing ESMEs.
 //! - `pretty-hex-fmt`: Logs byte slices like `[0x00, 0x00, 0x00, 0x6F]` instead of `[00, 00, 00, 6F]`, if `tracing` feature is enabled.
 //! - `char-fmt`: Logs byte slices as characters, if `tracing` feature is enabled.
 //!
@@ -108,7 +111,10 @@ pub mod types;
 pub mod decode;
 pub mod encode;
 
-pub use rusmpp_core::{CommandId, CommandStatus, command::owned::Command, pdus::owned::Pdu};
+pub use rusmpp_core::{
+    CommandId, CommandStatus, command::owned::Command, command_name, is_response,
+    matching_request_id, matching_response_id, pdus::owned::Pdu,
+};
 
 pub mod command;
 
@@ -121,3 +127,25 @@ pub mod pdus;
 pub mod fields;
 
 pub mod session;
+
+pub mod validate;
+
+pub mod delivery_receipt;
+
+pub mod message_id;
+
+pub mod reassembly;
+
+pub mod stats;
+
+pub mod storage;
+
+pub mod text;
+
+#[cfg(feature = "test-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-server")))]
+pub mod mock;
+
+#[cfg(feature = "pcap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pcap")))]
+pub mod pcap;
@@ -0,0 +1,3 @@
+//! `SMPP` message ID normalization.
+
+pub use rusmpp_core::message_id::*;
@@ -0,0 +1,3 @@
+//! In-memory `MC` for testing `ESME`s.
+
+pub use rusmpp_core::mock::*;
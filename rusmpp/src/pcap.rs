@@ -0,0 +1,3 @@
+//! Decoding `SMPP` commands from captured traffic.
+
+pub use rusmpp_core::pcap::*;
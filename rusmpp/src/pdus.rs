@@ -15,3 +15,7 @@ pub use rusmpp_core::pdus::owned::{
     QueryBroadcastSmResp, QuerySm, QuerySmResp, ReplaceSm, SubmitMulti, SubmitMultiResp, SubmitSm,
     SubmitSmResp,
 };
+
+pub use rusmpp_core::pdus::owned::PduBody;
+
+pub use rusmpp_core::pdus::owned::{ConcatInfo, TlvContainer, Udh};
@@ -0,0 +1,3 @@
+//! Reassembly of concatenated messages.
+
+pub use rusmpp_core::reassembly::*;
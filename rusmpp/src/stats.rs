@@ -0,0 +1,3 @@
+//! Traffic statistics over decoded commands.
+
+pub use rusmpp_core::stats::*;
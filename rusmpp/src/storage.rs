@@ -0,0 +1,3 @@
+//! Integrity-checked framing for persisted commands.
+
+pub use rusmpp_core::storage::*;
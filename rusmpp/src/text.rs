@@ -0,0 +1,3 @@
+//! Conversion between message text and the bytes of a `short_message` or `message_payload`.
+
+pub use rusmpp_core::text::*;
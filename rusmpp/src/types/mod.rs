@@ -1,7 +1,8 @@
 //! Core `SMPP` types.
 
 pub use rusmpp_core::types::{
-    COctetStringError, EmptyOrFullCOctetStringError, OctetStringError, owned::*,
+    AnyOctetStringError, COctetStringError, EmptyOrFullCOctetStringError, OctetStringError,
+    owned::*,
 };
 
 pub mod u16;
@@ -0,0 +1,3 @@
+//! Validation of `SMPP` values.
+
+pub use rusmpp_core::validate::*;
@@ -550,6 +550,8 @@ impl<S: AsyncRead + AsyncWrite> Future for Connection<S> {
                                 }
                             }
 
+                            // Responses are correlated by sequence number only.
+                            // A generic_nack can answer any request, so its command_id never matches the request's.
                             if id.is_response() {
                                 match self.as_mut().remove_response(sequence_number) {
                                     Some(response) => {
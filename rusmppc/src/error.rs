@@ -85,6 +85,7 @@ pub enum Error {
     /// The `SMPP` operation failed with an error response from the server.
     ///
     /// Error responses are responses with the status code other than [`EsmeRok`](rusmpp::CommandStatus::EsmeRok).
+    /// A [`GenericNack`](rusmpp::Pdu::GenericNack) with the sequence number of the request is returned as well.
     ///
     /// This error is returned by methods that send commands and waits for a response, such as [`bind_transceiver`](crate::client::Client::bind_transceiver) and [`submit_sm`](crate::client::Client::submit_sm).
     #[error("Unexpected response from the server: response: {response:?}")]
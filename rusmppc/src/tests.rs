@@ -700,6 +700,71 @@ async fn server_ddos_client_should_still_send_requests_and_connection_should_sti
     let _ = events.count().await;
 }
 
+#[tokio::test]
+async fn generic_nack_should_resolve_the_pending_request_with_the_same_sequence_number() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        let Some(Ok(first)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        let Some(Ok(second)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        // The second request is rejected before the first one is answered
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRinvcmdid)
+                    .sequence_number(second.sequence_number())
+                    .pdu(Pdu::GenericNack),
+            )
+            .await
+            .expect("Failed to send GenericNack");
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(first.sequence_number())
+                    .pdu(SubmitSmResp::default()),
+            )
+            .await
+            .expect("Failed to send SubmitSmResp");
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    });
+
+    let (client, _events) = ConnectionBuilder::new()
+        .response_timeout(Duration::from_millis(500))
+        .connected(client);
+
+    let (first, second) = tokio::join!(
+        client.submit_sm(SubmitSm::default()),
+        // Make sure the second request is sent after the first one
+        async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+
+            client.submit_sm(SubmitSm::default()).await
+        }
+    );
+
+    first.expect("Expected SubmitSmResp");
+
+    let Err(Error::UnexpectedResponse { response }) = second else {
+        panic!("Expected UnexpectedResponse error");
+    };
+
+    assert_eq!(response.id(), CommandId::GenericNack);
+    assert_eq!(response.status(), CommandStatus::EsmeRinvcmdid);
+}
+
 /// This test relies on [`enquire_link_timeout_idle_should_close_connection`] to be correct.
 ///
 /// This function uses the same setup as the previous test, but disables the enquire link interval.